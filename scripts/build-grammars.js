@@ -0,0 +1,112 @@
+import {execFileSync} from 'node:child_process';
+import crypto from 'node:crypto';
+import fs from 'node:fs/promises';
+import os from 'node:os';
+import path from 'node:path';
+import process from 'node:process';
+
+// Grammars whose tree-sitter-wasms build targets an ABI web-tree-sitter 0.24
+// cannot load (it supports 13–14). Each is regenerated at ABI 14 and written
+// to grammars/, which the chunker loads (in a source checkout) before
+// tree-sitter-wasms. The build is a local developer step; the output is not
+// committed or published.
+//
+// The first build records the source commit and the sha256 of the built
+// .wasm in grammars/grammars.lock.json; later builds fetch that commit and
+// verify the checksum. `-- --update` moves to the latest `ref` instead.
+const GRAMMARS = [
+	{
+		name: 'dart',
+		repo: 'https://github.com/UserNobody14/tree-sitter-dart.git',
+		ref: process.env.VIBERAG_DART_GRAMMAR_REF ?? 'master',
+	},
+];
+
+const ABI_VERSION = '14';
+const LOCK_FILE = 'grammars.lock.json';
+
+function run(command, args, cwd) {
+	execFileSync(command, args, {cwd, stdio: 'inherit'});
+}
+
+function capture(command, args, cwd) {
+	return execFileSync(command, args, {cwd, encoding: 'utf-8'}).trim();
+}
+
+async function sha256(filePath) {
+	const data = await fs.readFile(filePath);
+	return crypto.createHash('sha256').update(data).digest('hex');
+}
+
+async function readLock(outDir) {
+	try {
+		return JSON.parse(await fs.readFile(path.join(outDir, LOCK_FILE), 'utf-8'));
+	} catch {
+		return {};
+	}
+}
+
+async function buildGrammars(outDir, update) {
+	// Requires git, the tree-sitter CLI plus emscripten (or docker/podman)
+	// for `tree-sitter build --wasm`.
+	const treeSitter = process.env.TREE_SITTER_CLI ?? 'tree-sitter';
+	const lock = await readLock(outDir);
+
+	await fs.mkdir(outDir, {recursive: true});
+	const workDir = await fs.mkdtemp(path.join(os.tmpdir(), 'viberag-grammars-'));
+
+	try {
+		for (const grammar of GRAMMARS) {
+			const pinned = update ? undefined : lock[grammar.name];
+			const srcDir = path.join(workDir, grammar.name);
+			await fs.mkdir(srcDir);
+			run('git', ['init', '--quiet'], srcDir);
+			run(
+				'git',
+				[
+					'fetch',
+					'--depth',
+					'1',
+					grammar.repo,
+					pinned?.commit ?? grammar.ref,
+				],
+				srcDir,
+			);
+			run('git', ['checkout', '--quiet', 'FETCH_HEAD'], srcDir);
+			const commit = capture('git', ['rev-parse', 'HEAD'], srcDir);
+
+			run(treeSitter, ['generate', '--abi', ABI_VERSION], srcDir);
+			const wasmFile = `tree-sitter-${grammar.name}.wasm`;
+			run(treeSitter, ['build', '--wasm', '--output', wasmFile], srcDir);
+			const checksum = await sha256(path.join(srcDir, wasmFile));
+
+			if (pinned?.sha256 && checksum !== pinned.sha256) {
+				throw new Error(
+					`${wasmFile} built from ${commit} has sha256 ${checksum}, expected ${pinned.sha256}`,
+				);
+			}
+			await fs.copyFile(
+				path.join(srcDir, wasmFile),
+				path.join(outDir, wasmFile),
+			);
+			lock[grammar.name] = {repo: grammar.repo, commit, sha256: checksum};
+			console.log(
+				`Built grammars/${wasmFile} (ABI ${ABI_VERSION}, ${commit.slice(0, 12)})`,
+			);
+		}
+	} finally {
+		await fs.rm(workDir, {recursive: true, force: true});
+	}
+
+	await fs.writeFile(
+		path.join(outDir, LOCK_FILE),
+		`${JSON.stringify(lock, null, '\t')}\n`,
+	);
+}
+
+async function main() {
+	const outDir = path.resolve(process.cwd(), 'grammars');
+	await buildGrammars(outDir, process.argv.slice(2).includes('--update'));
+}
+
+await main();
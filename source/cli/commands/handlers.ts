@@ -7,6 +7,7 @@ import chalk from 'chalk';
 import {computeStringHash} from '../../daemon/lib/merkle/hash.js';
 import {
 	configExists,
+	loadConfig,
 	saveConfig,
 	DEFAULT_CONFIG,
 	PROVIDER_CONFIGS,
@@ -24,7 +25,11 @@ import {
 	checkV2IndexCompatibility,
 	v2ManifestExists,
 } from '../../daemon/services/v2/manifest.js';
-import {getGrammarSupportSummary} from '../../daemon/lib/chunker/grammars.js';
+import {
+	getGrammarSupportSummary,
+	loadGrammarOptions,
+	type GrammarOptions,
+} from '../../daemon/lib/chunker/grammars.js';
 import {checkNpmForUpdate} from '../../daemon/lib/update-check.js';
 import type {
 	DaemonStatusResponse,
@@ -451,6 +456,9 @@ export async function getStatus(projectRoot: string): Promise<string> {
 
 	let daemonStatus: DaemonStatusResponse | null = null;
 	let daemonError: string | null = null;
+	const grammarOptions = await loadConfig(projectRoot)
+		.then(config => loadGrammarOptions(projectRoot, config))
+		.catch((): Partial<GrammarOptions> => ({}));
 
 	try {
 		if (await client.isRunning()) {
@@ -469,7 +477,7 @@ export async function getStatus(projectRoot: string): Promise<string> {
 			compatibilityPromise,
 		]);
 		return formatStatusWithStartupChecks(
-			formatDaemonStatus(daemonStatus, projectRoot),
+			formatDaemonStatus(daemonStatus, projectRoot, grammarOptions),
 			{
 				update,
 				compatibility,
@@ -477,7 +485,10 @@ export async function getStatus(projectRoot: string): Promise<string> {
 		);
 	}
 
-	const manifestStatus = await formatManifestStatus(projectRoot);
+	const manifestStatus = await formatManifestStatus(
+		projectRoot,
+		grammarOptions,
+	);
 	const [update, compatibility] = await Promise.all([
 		updatePromise,
 		compatibilityPromise,
@@ -491,7 +502,10 @@ export async function getStatus(projectRoot: string): Promise<string> {
 	return formatStatusWithStartupChecks(manifestStatus, {update, compatibility});
 }
 
-async function formatManifestStatus(projectRoot: string): Promise<string> {
+async function formatManifestStatus(
+	projectRoot: string,
+	grammarOptions: Partial<GrammarOptions>,
+): Promise<string> {
 	if (!(await v2ManifestExists(projectRoot))) {
 		return 'No index found. Run /index to create one.';
 	}
@@ -511,12 +525,25 @@ async function formatManifestStatus(projectRoot: string): Promise<string> {
 		`  Total refs: ${manifest.stats.totalRefs}`,
 	];
 
-	appendParsingSupport(lines);
+	const crates = Object.entries(manifest.stats.crates ?? {});
+	if (crates.length > 0) {
+		lines.push('  Crates:');
+		for (const [name, counts] of crates) {
+			lines.push(
+				`    ${name}: ${counts.totalFiles} files · ${counts.totalSymbols} symbols · ${counts.totalChunks} chunks`,
+			);
+		}
+	}
+
+	appendParsingSupport(lines, grammarOptions);
 	return lines.join('\n');
 }
 
-function appendParsingSupport(lines: string[]): void {
-	const summary = getGrammarSupportSummary();
+function appendParsingSupport(
+	lines: string[],
+	grammarOptions: Partial<GrammarOptions>,
+): void {
+	const summary = getGrammarSupportSummary(grammarOptions);
 	const enabledNames = summary.enabled.map(g => g.display_name).join(', ');
 	const disabledNames = summary.disabled.map(g => g.display_name).join(', ');
 
@@ -567,6 +594,7 @@ function formatStatusWithStartupChecks(
 function formatDaemonStatus(
 	status: DaemonStatusResponse,
 	projectRoot: string,
+	grammarOptions: Partial<GrammarOptions>,
 ): string {
 	const lines: string[] = ['Daemon status:'];
 
@@ -586,7 +614,7 @@ function formatDaemonStatus(
 		lines.push('  Index: not indexed');
 	}
 
-	appendParsingSupport(lines);
+	appendParsingSupport(lines, grammarOptions);
 
 	const warmupElapsed =
 		status.warmupElapsedMs !== undefined
@@ -800,6 +828,7 @@ This registers VibeRAG as an MCP server. After adding:
    - get_surrounding_code      Get neighboring code around a hit
    - read_file_lines           Read exact source lines from disk
    - find_references           Find all references to a symbol
+   - find_implementations      Find types implementing a trait/interface
    - build_index               Build/update the search index
    - get_status                Get index + daemon status
    - cancel_operation          Cancel indexing or warmup
@@ -20,6 +20,8 @@ import type {
 	DaemonClientOptions,
 	ClientSearchOptions,
 	ClientFindUsagesOptions,
+	ClientFindImplementationsOptions,
+	ClientCallGraphOptions,
 	ClientEvalOptions,
 	ClientIndexOptions,
 	IndexStartResponse,
@@ -27,6 +29,8 @@ import type {
 	PingResponse,
 	SearchResults,
 	FindUsagesResults,
+	FindImplementationsResults,
+	CallGraphResults,
 	EvalReport,
 	IndexStats,
 	WatcherStatus,
@@ -201,6 +205,30 @@ export class DaemonClient {
 		) as Promise<FindUsagesResults>;
 	}
 
+	/**
+	 * Find types implementing a trait/interface by name or symbol_id.
+	 */
+	async findImplementations(
+		options: ClientFindImplementationsOptions,
+	): Promise<FindImplementationsResults> {
+		return this.request(
+			'findImplementations',
+			options as unknown as Record<string, unknown>,
+		) as Promise<FindImplementationsResults>;
+	}
+
+	/**
+	 * Callers and callees of a symbol, up to a number of hops.
+	 */
+	async getCallGraph(
+		options: ClientCallGraphOptions,
+	): Promise<CallGraphResults> {
+		return this.request(
+			'getCallGraph',
+			options as unknown as Record<string, unknown>,
+		) as Promise<CallGraphResults>;
+	}
+
 	/**
 	 * Run the v2 eval harness (quality + latency).
 	 */
@@ -6,6 +6,10 @@
  */
 
 import type {
+	V2AccessFilter,
+	V2CallGraphDirection,
+	V2CallGraphResponse,
+	V2FindImplementationsResponse,
 	V2FindUsagesResponse,
 	V2SearchIntent,
 	V2SearchScope,
@@ -16,6 +20,10 @@ import type {
 	V2EvalReport,
 } from '../daemon/services/v2/eval/eval.js';
 import type {V2IndexStats} from '../daemon/services/v2/indexing.js';
+import type {
+	V2CrateCounts,
+	V2RefKind,
+} from '../daemon/services/v2/storage/types.js';
 import type {WatcherStatus} from '../daemon/services/watcher.js';
 import type {IndexingPhase, IndexingUnit} from '../daemon/services/types.js';
 
@@ -83,6 +91,29 @@ export interface ClientFindUsagesOptions {
 	symbol_id?: string;
 	symbol_name?: string;
 	scope?: V2SearchScope;
+	ref_kinds?: V2RefKind[];
+	access?: V2AccessFilter;
+	k?: number;
+}
+
+/**
+ * Find-implementations options for client.
+ */
+export interface ClientFindImplementationsOptions {
+	symbol_id?: string;
+	symbol_name?: string;
+	scope?: V2SearchScope;
+	k?: number;
+}
+
+/**
+ * Call graph options for client.
+ */
+export interface ClientCallGraphOptions {
+	symbol_id: string;
+	direction?: V2CallGraphDirection;
+	depth?: number;
+	scope?: V2SearchScope;
 	k?: number;
 }
 
@@ -120,6 +151,8 @@ export interface DaemonStatusResponse {
 	totalSymbols?: number;
 	totalChunks?: number;
 	totalRefs?: number;
+	/** Per workspace member counts (Cargo crate / npm package / Go module) */
+	crates?: Record<string, V2CrateCounts>;
 	embeddingProvider?: string;
 	embeddingModel?: string;
 	warmupStatus: string;
@@ -191,6 +224,8 @@ export interface CancelResponse {
 export type {
 	V2SearchResponse as SearchResults,
 	V2FindUsagesResponse as FindUsagesResults,
+	V2FindImplementationsResponse as FindImplementationsResults,
+	V2CallGraphResponse as CallGraphResults,
 	V2IndexStats as IndexStats,
 	WatcherStatus,
 	V2EvalReport as EvalReport,
@@ -48,6 +48,12 @@ export type SearchResultsData = {
 		path_contains?: string[];
 		path_not_contains?: string[];
 		extension?: string[];
+		crate?: string[];
+		tests?: 'include' | 'exclude' | 'only';
+		visibility?: Array<
+			'public' | 'crate' | 'restricted' | 'protected' | 'private'
+		>;
+		kinds?: string[];
 	};
 	groups: {
 		definitions: SearchHit[];
@@ -0,0 +1,198 @@
+/**
+ * Language plugin tests: manifest loading/validation, the grammar support
+ * matrix, index fingerprints, and chunking through a plugin grammar.
+ *
+ * The plugin under test maps Solidity (shipped in tree-sitter-wasms but not
+ * built into viberag) through a manifest, as a user would for a niche
+ * language.
+ */
+
+import {describe, it, expect, beforeAll, afterAll} from 'vitest';
+import fs from 'node:fs/promises';
+import {createRequire} from 'node:module';
+import os from 'node:os';
+import path from 'node:path';
+import {Chunker} from '../lib/chunker/index.js';
+import {
+	changedGrammarExtensions,
+	computeGrammarFingerprints,
+	getGrammarSupport,
+} from '../lib/chunker/grammars.js';
+import {
+	loadLanguagePlugins,
+	type LanguagePluginLoadResult,
+} from '../lib/chunker/plugins.js';
+
+const require = createRequire(import.meta.url);
+const solidityWasm = path.join(
+	path.dirname(require.resolve('tree-sitter-wasms/package.json')),
+	'out',
+	'tree-sitter-solidity.wasm',
+);
+
+const SOLIDITY_MANIFEST = {
+	name: 'solidity',
+	displayName: 'Solidity',
+	grammar: solidityWasm,
+	extensions: ['.sol'],
+	classNodeTypes: ['contract_declaration'],
+	functionNodeTypes: ['function_definition'],
+	methodNodeTypes: ['function_definition'],
+	importNodeTypes: ['import_directive'],
+	callNodeTypes: ['call_expression'],
+	docCommentPrefixes: ['///'],
+};
+
+const VAULT_SOURCE = `import "./Token.sol";
+
+contract Vault {
+    /// Deposits tokens into the vault.
+    function deposit(uint256 amount) public {
+        record(amount);
+    }
+
+    // not documentation
+    function record(uint256 amount) internal {}
+}
+`;
+
+describe('language plugins', () => {
+	let tempDir: string;
+	let projectRoot: string;
+	let homeDir: string;
+	let loaded: LanguagePluginLoadResult;
+	const originalHome = process.env['VIBERAG_HOME'];
+
+	beforeAll(async () => {
+		tempDir = await fs.mkdtemp(path.join(os.tmpdir(), 'viberag-plugins-'));
+		projectRoot = path.join(tempDir, 'project');
+		homeDir = path.join(tempDir, 'home');
+		await fs.mkdir(path.join(projectRoot, 'tools'), {recursive: true});
+		await fs.mkdir(path.join(homeDir, 'languages'), {recursive: true});
+		process.env['VIBERAG_HOME'] = homeDir;
+
+		await fs.writeFile(
+			path.join(projectRoot, 'tools', 'solidity.json'),
+			JSON.stringify(SOLIDITY_MANIFEST),
+		);
+		// Per-machine manifests: one shadowed by the project, one invalid
+		await fs.writeFile(
+			path.join(homeDir, 'languages', 'solidity.json'),
+			JSON.stringify({...SOLIDITY_MANIFEST, displayName: 'Shadowed'}),
+		);
+		await fs.writeFile(
+			path.join(homeDir, 'languages', 'pyish.json'),
+			JSON.stringify({
+				name: 'pyish',
+				grammar: 'pyish.wasm',
+				extensions: ['.py'],
+			}),
+		);
+
+		loaded = await loadLanguagePlugins(projectRoot, ['tools/solidity.json']);
+	});
+
+	afterAll(async () => {
+		if (originalHome === undefined) delete process.env['VIBERAG_HOME'];
+		else process.env['VIBERAG_HOME'] = originalHome;
+		await fs.rm(tempDir, {recursive: true, force: true});
+	});
+
+	it('loads project manifests before per-machine ones', () => {
+		expect(loaded.plugins.map(p => p.name)).toEqual(['solidity']);
+		const plugin = loaded.plugins[0]!;
+		expect(plugin.displayName).toBe('Solidity');
+		expect(plugin.grammarPath).toBe(solidityWasm);
+		expect(plugin.extensions).toEqual(['.sol']);
+		expect(plugin.nameField).toBe('name');
+		expect(plugin.commentNodeTypes).toEqual(['comment']);
+	});
+
+	it('reports manifests that claim built-in extensions', () => {
+		expect(loaded.errors).toHaveLength(1);
+		expect(loaded.errors[0]!.manifestPath).toContain('pyish.json');
+		expect(loaded.errors[0]!.error).toContain('python');
+	});
+
+	it('lists plugins in the grammar support matrix', () => {
+		const support = getGrammarSupport({plugins: loaded});
+		const solidity = support.find(g => g.language === 'solidity');
+		expect(solidity).toMatchObject({
+			display_name: 'Solidity',
+			wasm_file: 'tree-sitter-solidity.wasm',
+			source: 'plugin',
+			enabled: true,
+			reason: null,
+		});
+		const invalid = support.find(g => g.language === 'pyish');
+		expect(invalid?.enabled).toBe(false);
+		expect(invalid?.reason).toContain('Invalid language plugin');
+	});
+
+	it('fingerprints plugin and grammar override extensions', async () => {
+		const before = await computeGrammarFingerprints({
+			grammarPaths: {},
+			plugins: loaded,
+		});
+		expect(Object.keys(before)).toEqual(['.sol']);
+
+		const edited = {
+			...loaded,
+			plugins: loaded.plugins.map(p => ({
+				...p,
+				callNodeTypes: [...p.callNodeTypes, 'emit_statement'],
+			})),
+		};
+		const after = await computeGrammarFingerprints({
+			grammarPaths: {go: solidityWasm},
+			plugins: edited,
+		});
+		expect(changedGrammarExtensions(before, after)).toEqual(['.go', '.sol']);
+		expect(changedGrammarExtensions(after, after)).toEqual([]);
+	});
+
+	it('chunks plugin files through the manifest mapping', async () => {
+		const chunker = new Chunker({plugins: loaded.plugins});
+		await chunker.initialize();
+		try {
+			const analysis = chunker.analyzeFile(
+				'contracts/Vault.sol',
+				VAULT_SOURCE,
+				{
+					chunkMaxSize: 2000,
+					refs: {identifier_mode: 'none'},
+				},
+			);
+			expect(analysis.language).toBe('solidity');
+			expect(analysis.parse_status).toBe('parsed');
+
+			const defs = analysis.definition_chunks.map(c => [c.type, c.name]);
+			expect(defs).toEqual([
+				['class', 'Vault'],
+				['method', 'deposit'],
+				['method', 'record'],
+			]);
+
+			const deposit = analysis.definition_chunks.find(
+				c => c.name === 'deposit',
+			)!;
+			expect(deposit.contextHeader).toContain('Class: Vault');
+			expect(deposit.signature).toBe('function deposit(uint256 amount) public');
+			expect(deposit.docstring).toBe('Deposits tokens into the vault.');
+			expect(deposit.calledNames).toContain('record');
+
+			const record = analysis.definition_chunks.find(c => c.name === 'record')!;
+			expect(record.docstring).toBeNull();
+
+			const imports = analysis.refs.filter(r => r.ref_kind === 'import');
+			expect(imports).toHaveLength(1);
+			expect(imports[0]!.module_name).toBe('./Token.sol');
+			expect(imports[0]!.token_texts).toEqual(['Token']);
+
+			const calls = analysis.refs.filter(r => r.ref_kind === 'call');
+			expect(calls.map(r => r.token_texts[0])).toContain('record');
+		} finally {
+			chunker.close();
+		}
+	});
+});
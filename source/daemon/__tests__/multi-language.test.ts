@@ -3,21 +3,38 @@
  *
  * V2 must be able to index and retrieve across multiple languages. Some
  * languages may fall back to module-level chunking when the grammar is not
- * available (e.g., Dart currently).
+ * available (e.g., Dart before `npm run build:grammars`).
  */
 
 import {describe, it, expect, beforeAll, afterAll} from 'vitest';
+import fs from 'node:fs/promises';
+import path from 'node:path';
 import {IndexingServiceV2} from '../services/v2/indexing.js';
 import {SearchEngineV2} from '../services/v2/search/engine.js';
 import type {V2SearchScope} from '../services/v2/search/types.js';
+import type {V2RefKind} from '../services/v2/storage/types.js';
 import {copyFixtureToTemp, type TestContext} from './helpers.js';
 
-async function getSymbolFromDefinitionSearch(args: {
+type DefinitionSearch = {
 	search: SearchEngineV2;
 	query: string;
-	file_path: string;
+	file_path?: string;
+	/** Exact title the hit must have (default: a title containing `query`) */
+	title?: string;
 	scope?: V2SearchScope;
-}): Promise<Record<string, unknown>> {
+	/** `false` returns the hit id without loading the symbol */
+	getSymbol?: boolean;
+};
+
+async function getSymbolFromDefinitionSearch(
+	args: DefinitionSearch & {getSymbol: false},
+): Promise<string>;
+async function getSymbolFromDefinitionSearch(
+	args: DefinitionSearch & {getSymbol?: true},
+): Promise<Record<string, unknown>>;
+async function getSymbolFromDefinitionSearch(
+	args: DefinitionSearch,
+): Promise<string | Record<string, unknown>> {
 	const results = await args.search.search(args.query, {
 		intent: 'definition',
 		k: 50,
@@ -25,14 +42,17 @@ async function getSymbolFromDefinitionSearch(args: {
 		scope: args.scope ?? {},
 	});
 
+	const definitions = results.groups.definitions.filter(
+		r => args.file_path === undefined || r.file_path === args.file_path,
+	);
 	const needle = args.query.toLowerCase();
 	const hit =
-		results.groups.definitions.find(
-			r =>
-				r.file_path === args.file_path &&
-				r.title.toLowerCase().includes(needle),
-		) ?? results.groups.definitions.find(r => r.file_path === args.file_path);
+		args.title !== undefined
+			? definitions.find(r => r.title === args.title)
+			: (definitions.find(r => r.title.toLowerCase().includes(needle)) ??
+				definitions[0]);
 	expect(hit).toBeDefined();
+	if (args.getSymbol === false) return hit!.id;
 
 	const symbol = await args.search.getSymbol(hit!.id);
 	expect(symbol).not.toBeNull();
@@ -92,6 +112,1307 @@ describe('Multi-Language Support (v2)', () => {
 		expect(unexported['is_exported']).toBe(false);
 	});
 
+	it('Rust: impl-aware qualnames and trait impl links', async () => {
+		const inherent = await search.search('Greeter::new', {
+			intent: 'definition',
+			k: 10,
+			explain: false,
+			scope: {extension: ['.rs']},
+		});
+		expect(inherent.groups.definitions[0]?.title).toBe('Greeter::new');
+
+		const traitMethod = await search.search('<Greeter as Display>::fmt', {
+			intent: 'definition',
+			k: 10,
+			explain: false,
+			scope: {extension: ['.rs']},
+		});
+		const fmtHit = traitMethod.groups.definitions[0];
+		expect(fmtHit?.title).toBe('<Greeter as Display>::fmt');
+
+		const fmt = await search.getSymbol(fmtHit!.id);
+		expect(fmt?.['parent_symbol_id']).toBeTruthy();
+		const implBlock = await search.getSymbol(String(fmt!['parent_symbol_id']));
+		expect(implBlock?.['symbol_name']).toBe('impl Display for Greeter');
+		expect(implBlock?.['impl_type_name']).toBe('Greeter');
+		expect(implBlock?.['impl_trait_name']).toBe('Display');
+
+		const targets = implBlock?.['impl_targets'] as {
+			type: Array<Record<string, unknown>>;
+		};
+		expect(targets.type).toHaveLength(1);
+		expect(targets.type[0]?.['symbol_name']).toBe('Greeter');
+		expect(targets.type[0]?.['file_path']).toBe('sample.rs');
+		expect(implBlock?.['impl_type_symbol_id']).toBe(
+			targets.type[0]?.['symbol_id'],
+		);
+		expect(fmt?.['impl_type_symbol_id']).toBe(targets.type[0]?.['symbol_id']);
+	});
+
+	it('Rust: indexes macro_rules! definitions and invocations', async () => {
+		const macro = await getSymbolFromDefinitionSearch({
+			search,
+			query: 'greet_all',
+			file_path: 'sample.rs',
+			scope: {extension: ['.rs']},
+		});
+		expect(macro['symbol_kind']).toBe('macro');
+		expect(macro['is_exported']).toBe(true);
+
+		const usages = await search.findUsages({
+			symbol_id: String(macro['symbol_id']),
+		});
+		const refs = usages.by_file.flatMap(g => g.refs);
+		expect(
+			refs.some(r => r.file_path === 'sample.rs' && r.ref_kind === 'call'),
+		).toBe(true);
+	});
+
+	it('Rust: indexes consts, statics, type aliases, variants and fields', async () => {
+		const constant = await getSymbolFromDefinitionSearch({
+			search,
+			query: 'MAX_RETRIES',
+			file_path: 'sample.rs',
+			scope: {extension: ['.rs']},
+		});
+		expect(constant['symbol_kind']).toBe('constant');
+		expect(constant['is_exported']).toBe(true);
+
+		const staticItem = await getSymbolFromDefinitionSearch({
+			search,
+			query: 'DEFAULT_NAME',
+			file_path: 'sample.rs',
+			scope: {extension: ['.rs']},
+		});
+		expect(staticItem['symbol_kind']).toBe('constant');
+		expect(staticItem['is_exported']).toBe(false);
+
+		const alias = await getSymbolFromDefinitionSearch({
+			search,
+			query: 'GreetResult',
+			file_path: 'sample.rs',
+			scope: {extension: ['.rs']},
+		});
+		expect(alias['symbol_kind']).toBe('type_alias');
+
+		// Exact titles: `impl Greeter` blocks also match the query.
+		const definition = (name: string) =>
+			getSymbolFromDefinitionSearch({
+				search,
+				query: name,
+				title: name,
+				file_path: 'sample.rs',
+				scope: {extension: ['.rs']},
+			});
+
+		const mood = await definition('Mood');
+		const variants = mood['children'] as Array<Record<string, unknown>>;
+		expect(variants.map(v => v['symbol_name'])).toEqual([
+			'Happy',
+			'Formal',
+			'Custom',
+		]);
+		expect(variants.every(v => v['symbol_kind'] === 'variant')).toBe(true);
+		expect(variants[0]?.['qualname']).toBe('Mood::Happy');
+
+		const greeter = await definition('Greeter');
+		const members = greeter['children'] as Array<Record<string, unknown>>;
+		const field = members.find(m => m['symbol_name'] === 'mood');
+		expect(field?.['symbol_kind']).toBe('field');
+
+		const fieldSymbol = await search.getSymbol(String(field!['symbol_id']));
+		expect(fieldSymbol?.['parent_symbol_id']).toBe(greeter['symbol_id']);
+		expect(fieldSymbol?.['is_exported']).toBe(true);
+	});
+
+	it('Rust: distinguishes pub, pub(crate) and pub inside a private mod', async () => {
+		const cases: Array<{
+			query: string;
+			visibility: string;
+			exported: boolean;
+		}> = [
+			{query: 'add', visibility: 'public', exported: true},
+			{query: 'crate_helper', visibility: 'crate', exported: false},
+			{
+				query: 'reachable_from_parent',
+				visibility: 'restricted',
+				exported: false,
+			},
+			{query: 'private_function', visibility: 'private', exported: false},
+		];
+
+		for (const c of cases) {
+			const symbol = await getSymbolFromDefinitionSearch({
+				search,
+				query: c.query,
+				file_path: 'sample.rs',
+				scope: {extension: ['.rs']},
+			});
+			expect(symbol['visibility']).toBe(c.visibility);
+			expect(symbol['is_exported']).toBe(c.exported);
+		}
+	});
+
+	it('Java/C#/Kotlin: maps access modifiers to visibility', async () => {
+		const cases: Array<{
+			ext: string;
+			file: string;
+			query: string;
+			visibility: string;
+		}> = [
+			{
+				ext: '.java',
+				file: 'Sample.java',
+				query: 'internalMethod',
+				visibility: 'private',
+			},
+			{
+				ext: '.cs',
+				file: 'Sample.cs',
+				query: 'PrivateHelper',
+				visibility: 'crate',
+			},
+			{
+				ext: '.kt',
+				file: 'Sample.kt',
+				query: 'PrivateHelper',
+				visibility: 'crate',
+			},
+		];
+
+		for (const c of cases) {
+			const symbol = await getSymbolFromDefinitionSearch({
+				search,
+				query: c.query,
+				file_path: c.file,
+				scope: {extension: [c.ext]},
+			});
+			expect(symbol['visibility']).toBe(c.visibility);
+		}
+	});
+
+	it('Rust: resolves use paths and renamed imports to the target symbol', async () => {
+		const client = await getSymbolFromDefinitionSearch({
+			search,
+			query: 'Client',
+			file_path: 'rust_crate/src/net/client.rs',
+			scope: {extension: ['.rs']},
+		});
+		const symbolId = String(client['symbol_id']);
+
+		const usages = await search.findUsages({symbol_id: symbolId});
+		const imports = usages.by_file
+			.flatMap(g => g.refs)
+			.filter(r => r.ref_kind === 'import');
+
+		// `use crate::net::client::Client as NetClient;`
+		const renamed = imports.find(
+			r =>
+				r.file_path === 'rust_crate/src/app.rs' &&
+				r.token_text === 'NetClient',
+		);
+		expect(renamed?.target_symbol_id).toBe(symbolId);
+
+		// `pub use client::Client;` in net/mod.rs
+		expect(
+			imports.some(
+				r =>
+					r.file_path === 'rust_crate/src/net/mod.rs' &&
+					r.target_symbol_id === symbolId,
+			),
+		).toBe(true);
+
+		// `use super::legacy::Client;` binds a different struct.
+		expect(
+			imports.some(
+				r =>
+					r.file_path === 'rust_crate/src/app.rs' &&
+					r.module_name === 'super::legacy',
+			),
+		).toBe(false);
+	});
+
+	it('Doc links and doc-test examples show up as references', async () => {
+		const maxRetries = await getSymbolFromDefinitionSearch({
+			search,
+			query: 'MAX_RETRIES',
+			file_path: 'sample.rs',
+			scope: {extension: ['.rs']},
+		});
+		const rustRefs = (
+			await search.findUsages({symbol_id: String(maxRetries['symbol_id'])})
+		).by_file.flatMap(g => g.refs);
+		expect(
+			rustRefs.some(
+				r => r.file_path === 'sample.rs' && r.ref_kind === 'doc_link',
+			),
+		).toBe(true);
+
+		const cases: Array<{symbol_name: string; file_path: string}> = [
+			{symbol_name: 'capitalize', file_path: 'src/utils/helpers.ts'},
+			{symbol_name: 'add_two_numbers', file_path: 'math.py'},
+		];
+		for (const c of cases) {
+			const usages = await search.findUsages({symbol_name: c.symbol_name});
+			expect(
+				usages.by_file
+					.flatMap(g => g.refs)
+					.some(r => r.file_path === c.file_path && r.ref_kind === 'doc_link'),
+			).toBe(true);
+		}
+
+		// `>>> multiply(2, 3)` in the docstring is a call site.
+		const multiply = await search.findUsages({symbol_name: 'multiply'});
+		expect(
+			multiply.by_file
+				.flatMap(g => g.refs)
+				.some(r => r.file_path === 'math.py' && r.ref_kind === 'call'),
+		).toBe(true);
+
+		// The Rust doc test is a block owned by `Greeter::greet`.
+		const blocks = await search.search('Hello, Ada', {
+			intent: 'exact_text',
+			k: 10,
+			explain: false,
+			scope: {extension: ['.rs']},
+		});
+		const docTest = blocks.groups.blocks.find(b => b.title === 'doc_test');
+		expect(docTest?.file_path).toBe('sample.rs');
+		const greeterRefs = (
+			await search.findUsages({symbol_name: 'greet'})
+		).by_file.flatMap(g => g.refs);
+		expect(
+			greeterRefs.some(
+				r =>
+					r.file_path === 'sample.rs' &&
+					r.ref_kind === 'call' &&
+					r.start_line >= docTest!.start_line &&
+					r.start_line <= docTest!.end_line,
+			),
+		).toBe(true);
+	});
+
+	it('find_implementations covers impls, derives, supertypes and Go interfaces', async () => {
+		const debug = await search.findImplementations({symbol_name: 'Debug'});
+		expect(
+			debug.implementations
+				.filter(i => i.file_path === 'sample.rs')
+				.map(i => [i.type_name, i.via]),
+		).toEqual(
+			expect.arrayContaining([
+				['Mood', 'derive'],
+				['Greeter', 'derive'],
+			]),
+		);
+
+		const display = await search.findImplementations({
+			symbol_name: 'fmt::Display',
+		});
+		const displayImpl = display.implementations.find(
+			i => i.file_path === 'sample.rs',
+		);
+		expect(displayImpl?.via).toBe('impl');
+		expect(displayImpl?.type_name).toBe('Greeter');
+		expect(displayImpl?.qualname).toBe('impl Display for Greeter');
+
+		const javaGreeter = await search.findImplementations({
+			symbol_name: 'Greeter',
+			scope: {extension: ['.java']},
+		});
+		expect(
+			javaGreeter.implementations.map(i => [i.type_name, i.via]),
+		).toEqual([['HelloService', 'supertype']]);
+		const javaSample = await search.findImplementations({
+			symbol_name: 'Sample',
+			scope: {extension: ['.java']},
+		});
+		expect(javaSample.implementations.map(i => i.type_name)).toEqual([
+			'HelloService',
+		]);
+
+		const speaker = await search.findImplementations({
+			symbol_name: 'Speaker',
+		});
+		const goGreeter = speaker.implementations.find(
+			i => i.file_path === 'sample.go',
+		);
+		expect(goGreeter?.type_name).toBe('Greeter');
+		expect(goGreeter?.via).toBe('structural');
+		expect(goGreeter?.matched_methods).toEqual(['Greet']);
+	});
+
+	it('get_symbol_details links trait/interface methods and their implementations', async () => {
+		const method = (title: string, ext: string) =>
+			getSymbolFromDefinitionSearch({
+				search,
+				query: title,
+				title,
+				scope: {extension: [ext]},
+			});
+		const qualnames = (value: unknown) =>
+			(value as Array<Record<string, unknown>> | undefined)?.map(
+				m => m['qualname'],
+			);
+
+		const traitMethod = await method('Introduce::introduce', '.rs');
+		expect(qualnames(traitMethod['implementations'])).toEqual([
+			'<Greeter as Introduce>::introduce',
+		]);
+		const implMethod = await method('<Greeter as Introduce>::introduce', '.rs');
+		expect(qualnames(implMethod['declarations'])).toEqual([
+			'Introduce::introduce',
+		]);
+
+		const javaInterfaceMethod = await method('Greeter.greet', '.java');
+		expect(qualnames(javaInterfaceMethod['implementations'])).toEqual([
+			'HelloService.greet',
+		]);
+		const javaImpl = await method('HelloService.greet', '.java');
+		expect(qualnames(javaImpl['declarations'])).toEqual(['Greeter.greet']);
+
+		const goInterfaceMethod = await method('Speaker.Greet', '.go');
+		expect(qualnames(goInterfaceMethod['implementations'])).toEqual([
+			'Greeter.Greet',
+		]);
+		const goMethod = await method('Greeter.Greet', '.go');
+		expect(qualnames(goMethod['declarations'])).toEqual(['Speaker.Greet']);
+
+		const tsInterfaceMethod = await method('Renderable.render', '.ts');
+		expect(qualnames(tsInterfaceMethod['implementations'])).toEqual([
+			'Gauge.render',
+		]);
+		const tsMethod = await method('Gauge.render', '.ts');
+		expect(qualnames(tsMethod['declarations'])).toEqual(['Renderable.render']);
+	});
+
+	it('C/C++: namespaces, out-of-line methods, includes and header/implementation links', async () => {
+		const definition = (title: string, file_path: string) =>
+			getSymbolFromDefinitionSearch({
+				search,
+				query: title,
+				title,
+				file_path,
+				scope: {path_prefix: ['native/']},
+			});
+		const field = (value: unknown, key: string) =>
+			(value as Array<Record<string, unknown>> | undefined)?.map(m => m[key]);
+
+		// `double Circle::area() const` inside `namespace geo` in shape.cpp
+		const circle = await definition('geo::Circle', 'native/geo/shape.h');
+		expect(circle['docstring']).toBe('A circle centered at the origin.');
+		const areaImpl = await definition(
+			'geo::Circle::area',
+			'native/geo/shape.cpp',
+		);
+		expect(areaImpl['symbol_kind']).toBe('method');
+		expect(areaImpl['parent_symbol_id']).toBe(circle['symbol_id']);
+		expect(field(areaImpl['prototypes'], 'file_path')).toEqual([
+			'native/geo/shape.h',
+		]);
+
+		const areaDecl = await definition('geo::Shape::area', 'native/geo/shape.h');
+		expect(areaDecl['docstring']).toBe('Area of the shape in square units.');
+		expect(field(areaDecl['definitions'], 'file_path')).toEqual([
+			'native/geo/shape.cpp',
+		]);
+		// Virtual dispatch through the `: public Shape` base clause reaches
+		// the override and its out-of-line definition, linked at index time
+		expect(field(areaDecl['implementations'], 'qualname')).toEqual([
+			'geo::Circle::area',
+			'geo::Circle::area',
+		]);
+		expect(field(areaDecl['implementations'], 'file_path')).toEqual([
+			'native/geo/shape.cpp',
+			'native/geo/shape.h',
+		]);
+
+		// C prototype in the header, definition in the .c file
+		const distance = await definition('grid_distance', 'native/mathlib.h');
+		expect(distance['docstring']).toContain('Manhattan distance');
+		expect(field(distance['definitions'], 'file_path')).toEqual([
+			'native/mathlib.c',
+		]);
+
+		// `static` functions and anonymous namespaces are file-local
+		const gridAbs = await definition('grid_abs', 'native/mathlib.c');
+		expect(gridAbs['visibility']).toBe('private');
+		const square = await definition('geo::square', 'native/geo/shape.cpp');
+		expect(square['visibility']).toBe('private');
+
+		const includes = (
+			await search.findUsages({symbol_name: 'mathlib'})
+		).by_file.flatMap(g => g.refs);
+		expect(
+			includes.some(
+				r =>
+					r.file_path === 'native/mathlib.c' &&
+					r.ref_kind === 'import' &&
+					r.module_name === 'mathlib.h',
+			),
+		).toBe(true);
+	});
+
+	it('Ruby/Scala/Elixir/Lua: modules, visibility, docs and imports', async () => {
+		const definition = (title: string, file_path: string) =>
+			getSymbolFromDefinitionSearch({
+				search,
+				query: title,
+				title,
+				file_path,
+				scope: {path_prefix: ['scripting/']},
+			});
+		const imports = async (symbol_name: string, file_path: string) =>
+			(await search.findUsages({symbol_name})).by_file
+				.flatMap(g => g.refs)
+				.filter(r => r.file_path === file_path && r.ref_kind === 'import')
+				.map(r => r.module_name);
+
+		// Ruby: classes nest in modules; bare `private` applies to later defs
+		const invoice = await definition(
+			'Billing::Invoice',
+			'scripting/billing/invoice.rb',
+		);
+		expect(invoice['docstring']).toBe('An invoice with line items.');
+		expect(invoice['supertypes']).toEqual(['Record']);
+		const total = await definition(
+			'Billing::Invoice::total',
+			'scripting/billing/invoice.rb',
+		);
+		expect(total['symbol_kind']).toBe('method');
+		expect(total['parent_symbol_id']).toBe(invoice['symbol_id']);
+		expect(total['docstring']).toBe('Total of all line items in cents.');
+		const rounded = await definition(
+			'Billing::Invoice::rounded',
+			'scripting/billing/invoice.rb',
+		);
+		expect(rounded['visibility']).toBe('private');
+		expect(
+			await imports('record', 'scripting/billing/invoice.rb'),
+		).toContain('./record');
+
+		// Scala: traits, case classes and `private def`
+		const flatPlan = await definition(
+			'FlatPlan',
+			'scripting/plans/Plans.scala',
+		);
+		expect(flatPlan['docstring']).toBe('A plan with a fixed monthly price.');
+		expect(flatPlan['supertypes']).toEqual(['Plan', 'Serializable']);
+		const monthly = await definition(
+			'FlatPlan.monthly',
+			'scripting/plans/Plans.scala',
+		);
+		expect(monthly['signature']).toBe('def monthly: BigDecimal');
+		const discounted = await definition(
+			'FlatPlan.discounted',
+			'scripting/plans/Plans.scala',
+		);
+		expect(discounted['visibility']).toBe('private');
+		expect(
+			await imports('MutableMap', 'scripting/plans/Plans.scala'),
+		).toContain('scala.collection.mutable.Map');
+
+		// Elixir: `def`/`defp` inside `defmodule`, `@doc`, `defimpl`
+		const balance = await definition(
+			'Scripting.Ledger.balance',
+			'scripting/lib/ledger.ex',
+		);
+		expect(balance['symbol_kind']).toBe('method');
+		expect(balance['docstring']).toBe('Balance after applying every entry.');
+		expect(balance['signature']).toBe(
+			'def balance(entries) when is_list(entries)',
+		);
+		const applyEntry = await definition(
+			'Scripting.Ledger.apply_entry',
+			'scripting/lib/ledger.ex',
+		);
+		expect(applyEntry['visibility']).toBe('private');
+		const describable = await search.findImplementations({
+			symbol_name: 'Describable',
+			scope: {path_prefix: ['scripting/']},
+		});
+		expect(describable.implementations.map(i => i.qualname)).toEqual([
+			'Scripting.Describable.Scripting.Ledger',
+		]);
+		expect(await imports('Entry', 'scripting/lib/ledger.ex')).toContain(
+			'Scripting.Ledger.Entry',
+		);
+
+		// Lua: `T.f` functions, `T:m` methods and `local function`
+		const create = await definition('Meter.new', 'scripting/meter.lua');
+		expect(create['symbol_kind']).toBe('function');
+		expect(create['docstring']).toBe('Create a meter starting at zero.');
+		const record = await definition('Meter.record', 'scripting/meter.lua');
+		expect(record['symbol_kind']).toBe('method');
+		const clamp = await definition('clamp', 'scripting/meter.lua');
+		expect(clamp['visibility']).toBe('private');
+		expect(await imports('util', 'scripting/meter.lua')).toContain(
+			'scripting.util',
+		);
+	});
+
+	it('Vue/Svelte/Astro: script symbols, component names and usages', async () => {
+		const definition = (title: string, file_path: string) =>
+			getSymbolFromDefinitionSearch({
+				search,
+				query: title,
+				title,
+				file_path,
+				scope: {path_prefix: ['sfc/']},
+			});
+		const usageFiles = async (symbol_name: string, ref_kind: string) =>
+			(await search.findUsages({symbol_name})).by_file
+				.flatMap(g => g.refs)
+				.filter(r => r.ref_kind === ref_kind)
+				.map(r => r.file_path);
+
+		// Components are named after their files
+		const button = await definition('CounterButton', 'sfc/CounterButton.vue');
+		expect(button['symbol_kind']).toBe('class');
+		expect(button['docstring']).toBe(
+			'Button that increments a shared counter.',
+		);
+		const badge = await definition('CounterBadge', 'sfc/counter-badge.svelte');
+		expect(badge['symbol_kind']).toBe('class');
+
+		// Script sections parse as TypeScript with original line numbers
+		const onClick = await definition('onClick', 'sfc/CounterButton.vue');
+		expect(onClick['symbol_kind']).toBe('function');
+		expect(onClick['start_line']).toBe(16);
+		expect(onClick['docstring']).toBe('Increment and remember the new total.');
+		const formatBadge = await definition(
+			'formatBadge',
+			'sfc/counter-badge.svelte',
+		);
+		expect(formatBadge['signature']).toContain('formatBadge(n: number)');
+		expect(formatBadge['start_line']).toBe(7);
+
+		// A composable's usages include every component that calls it
+		const callers = await usageFiles('useCounter', 'call');
+		expect(callers).toEqual(
+			expect.arrayContaining([
+				'sfc/CounterButton.vue',
+				'sfc/counter-badge.svelte',
+				'sfc/CounterPage.astro',
+			]),
+		);
+
+		// Template tags reference the components they render
+		expect(await usageFiles('CounterButton', 'identifier')).toContain(
+			'sfc/CounterPage.astro',
+		);
+		expect(await usageFiles('CounterBadge', 'identifier')).toContain(
+			'sfc/CounterButton.vue',
+		);
+	});
+
+	it('Jupyter: code cell symbols at notebook lines, cells as chunks', async () => {
+		const file_path = 'notebooks/sales_analysis.ipynb';
+		const loadSales = await getSymbolFromDefinitionSearch({
+			search,
+			query: 'load_sales',
+			file_path,
+		});
+		expect(loadSales['symbol_kind']).toBe('function');
+		expect(loadSales['docstring']).toBe(
+			'Read the sales export into a DataFrame.',
+		);
+		// Lines address the .ipynb file itself
+		const notebookLines = (
+			await fs.readFile(path.join(ctx.projectRoot, file_path), 'utf-8')
+		).split('\n');
+		expect(loadSales['start_line']).toBe(33);
+		expect(notebookLines[32]).toContain('def load_sales(path):');
+
+		// Cells parse together: later cells call earlier definitions
+		const usages = await search.findUsages({symbol_name: 'load_sales'});
+		const callers = usages.by_file
+			.flatMap(g => g.refs)
+			.filter(r => r.ref_kind === 'call');
+		expect(callers.map(r => [r.file_path, r.start_line])).toContainEqual([
+			file_path,
+			65,
+		]);
+
+		// Output text that repeats a definition is not mistaken for source
+		const summarise = await getSymbolFromDefinitionSearch({
+			search,
+			query: 'summarise',
+			file_path,
+		});
+		expect(summarise['start_line']).toBe(62);
+
+		// Long markdown cells split at headings, like markdown files
+		const method = notebookLines.findIndex(l => l.includes('"## Method')) + 1;
+		const findings =
+			notebookLines.findIndex(l => l.includes('"## Findings')) + 1;
+		const attributed = await search.search('billing region of each invoice', {
+			intent: 'exact_text',
+			k: 20,
+			explain: false,
+			scope: {path_prefix: [file_path]},
+		});
+		const section = attributed.groups.blocks.find(h => h.table === 'chunks');
+		expect(section).toMatchObject({
+			file_path,
+			start_line: method,
+			end_line: findings - 1,
+		});
+	});
+
+	it('Schemas: protobuf, GraphQL, OpenAPI and SQL definitions', async () => {
+		const definition = (
+			query: string,
+			file_path: string,
+			title: string = query,
+		) =>
+			getSymbolFromDefinitionSearch({
+				search,
+				query,
+				title,
+				file_path,
+				scope: {path_prefix: ['contracts/']},
+			});
+
+		// Protobuf: messages, services and RPCs qualified by the package
+		const proto = 'contracts/users.proto';
+		const request = await definition('acme.users.v1.GetUserRequest', proto);
+		expect(request['symbol_kind']).toBe('message');
+		expect(request['docstring']).toBe('Request for UserService.GetUser.');
+		const getUser = await definition(
+			'acme.users.v1.UserService.GetUser',
+			proto,
+		);
+		expect(getUser['symbol_kind']).toBe('rpc');
+		expect(getUser['signature']).toBe(
+			'rpc GetUser(GetUserRequest) returns (GetUserResponse)',
+		);
+		const role = await definition('acme.users.v1.User.Role', proto);
+		expect(role['symbol_kind']).toBe('enum');
+		// Owner-qualified queries match below the package
+		const userId = await definition(
+			'GetUserRequest.user_id',
+			proto,
+			'acme.users.v1.GetUserRequest.user_id',
+		);
+		expect(userId['symbol_kind']).toBe('field');
+		expect(userId['parent_symbol_id']).toBe(request['symbol_id']);
+
+		// RPCs reference their message types
+		const requestRefs = (
+			await search.findUsages({symbol_name: 'GetUserRequest'})
+		).by_file.flatMap(g => g.refs);
+		expect(requestRefs.map(r => [r.file_path, r.start_line])).toContainEqual([
+			proto,
+			10,
+		]);
+
+		// GraphQL: types, enums, root fields as RPCs, descriptions as docs
+		const graphql = 'contracts/schema.graphql';
+		const userRole = await definition('UserRole', graphql);
+		expect(userRole['symbol_kind']).toBe('enum');
+		const userQuery = await definition('Query.user', graphql);
+		expect(userQuery['symbol_kind']).toBe('rpc');
+		expect(userQuery['docstring']).toBe('Look up a user by id.');
+		const email = await definition('User.email', graphql);
+		expect(email['symbol_kind']).toBe('field');
+		expect(email['docstring']).toBe('Primary contact address.');
+
+		// OpenAPI: operations named by operationId
+		const operation = await definition('getUser', 'contracts/openapi.yaml');
+		expect(operation['symbol_kind']).toBe('operation');
+		expect(operation['signature']).toBe('GET /users/{id}');
+		expect(operation['docstring']).toBe('Fetch a user by id');
+
+		// SQL DDL: tables and their columns, including ALTER TABLE additions
+		const migration = 'contracts/migrations/001_create_users.sql';
+		const column = await definition('users.email', migration);
+		expect(column['symbol_kind']).toBe('column');
+		expect(column['docstring']).toBe('primary contact address');
+		const added = await definition('users.last_login_at', migration);
+		expect(added['symbol_kind']).toBe('column');
+		const users = await definition('users', migration);
+		expect(users['symbol_kind']).toBe('table');
+		expect(column['parent_symbol_id']).toBe(users['symbol_id']);
+	});
+
+	it('Infrastructure: Terraform, Dockerfile and Kubernetes symbols and refs', async () => {
+		const definition = (title: string, file_path: string) =>
+			getSymbolFromDefinitionSearch({
+				search,
+				query: title,
+				title,
+				file_path,
+				scope: {path_prefix: ['infra/']},
+			});
+		const usageLines = async (symbol_name: string) =>
+			(await search.findUsages({symbol_name})).by_file
+				.flatMap(g => g.refs)
+				.map(r => [r.file_path, r.start_line]);
+
+		// Terraform: blocks are named by their address
+		const main = 'infra/terraform/main.tf';
+		const redis = await definition('aws_elasticache_cluster.redis', main);
+		expect(redis['symbol_kind']).toBe('resource');
+		expect(redis['symbol_name']).toBe('redis');
+		expect(redis['docstring']).toBe('Session store for the web tier.');
+		expect(redis['context_header']).toContain(
+			'resource aws_elasticache_cluster.redis',
+		);
+		const region = await definition(
+			'var.region',
+			'infra/terraform/variables.tf',
+		);
+		expect(region['symbol_kind']).toBe('variable');
+		expect(region['docstring']).toBe('AWS region for all resources');
+		const network = await definition('module.network', main);
+		expect(network['symbol_kind']).toBe('module_call');
+
+		// var.x, module.x and resource references, across files
+		const regionRefs = await usageLines('region');
+		expect(regionRefs).toContainEqual([main, 2]);
+		expect(regionRefs).toContainEqual(['infra/terraform/prod.tfvars', 1]);
+		expect(await usageLines('network')).toContainEqual([main, 28]);
+		// Heredoc text is not scanned for references
+		const redisRefs = await usageLines('redis');
+		expect(redisRefs).toContainEqual([main, 43]);
+		expect(redisRefs).not.toContainEqual([main, 37]);
+
+		// Dockerfile: named stages, referenced by FROM and --from
+		const builder = await definition('builder', 'infra/Dockerfile');
+		expect(builder['symbol_kind']).toBe('stage');
+		expect(builder['signature']).toBe('FROM golang:1.22-alpine AS builder');
+		expect(builder['docstring']).toBe('Compile the API server.');
+		const builderRefs = await usageLines('builder');
+		expect(builderRefs).toContainEqual(['infra/Dockerfile', 9]);
+		expect(builderRefs).toContainEqual(['infra/Dockerfile', 14]);
+
+		// Kubernetes: objects are named by metadata.name under their kind
+		const k8s = 'infra/k8s/redis.yaml';
+		const deployment = await definition('Deployment.redis', k8s);
+		expect(deployment['symbol_kind']).toBe('resource');
+		expect(deployment['signature']).toBe('Deployment platform/redis');
+		const service = await definition('Service.redis', k8s);
+		expect(service['start_line']).toBe(22);
+		expect(await usageLines('redis-config')).toContainEqual([k8s, 16]);
+	});
+
+	it('Config: JSON, YAML and TOML key paths linked to string literals', async () => {
+		const definition = (title: string, file_path: string) =>
+			getSymbolFromDefinitionSearch({
+				search,
+				query: title,
+				title,
+				file_path,
+				scope: {path_prefix: ['config/']},
+			});
+
+		const yaml = 'config/production.yaml';
+		const maxSize = await definition('database.pool.max_size', yaml);
+		expect(maxSize['symbol_kind']).toBe('key');
+		expect(maxSize['symbol_name']).toBe('max_size');
+		expect(maxSize['signature']).toBe('database.pool.max_size = 20');
+		expect(maxSize['docstring']).toBe(
+			'Upper bound on open connections per instance',
+		);
+		const pool = await definition('database.pool', yaml);
+		expect(maxSize['parent_symbol_id']).toBe(pool['symbol_id']);
+
+		// TOML tables and multi-line arrays
+		const toml = 'config/settings.toml';
+		const tomlMaxSize = await definition('database.pool.max_size', toml);
+		expect(tomlMaxSize['signature']).toBe('database.pool.max_size = 5');
+		const hosts = await definition('server.allowed_hosts', toml);
+		expect(hosts['end_line']).toBe(6);
+
+		const rollout = await definition(
+			'features.checkout.rollout',
+			'config/features.json',
+		);
+		expect(rollout['signature']).toBe('features.checkout.rollout = 0.25');
+
+		// Code reading the key by its path
+		const usages = await search.findUsages({
+			symbol_id: maxSize['symbol_id'] as string,
+		});
+		expect(usages.resolved.symbol_name).toBe('database.pool.max_size');
+		expect(usages.by_file.flatMap(g => g.refs)).toContainEqual(
+			expect.objectContaining({
+				file_path: 'config/pool.ts',
+				start_line: 5,
+				ref_kind: 'string_literal',
+			}),
+		);
+	});
+
+	it('Build scripts: shell functions, Make targets and just recipes', async () => {
+		const definition = (title: string, file_path: string) =>
+			getSymbolFromDefinitionSearch({
+				search,
+				query: title,
+				title,
+				file_path,
+				scope: {path_prefix: ['build/']},
+			});
+		const usages = async (symbol_name: string) =>
+			(await search.findUsages({symbol_name})).by_file
+				.flatMap(g => g.refs)
+				.map(r => [r.file_path, r.start_line, r.ref_kind]);
+
+		// Shell: functions, calls across sourced files
+		const deploySh = 'build/scripts/deploy.sh';
+		const log = await definition('log', 'build/scripts/common.sh');
+		expect(log['symbol_kind']).toBe('function');
+		expect(log['docstring']).toBe('Print a timestamped message to stderr.');
+		const deploy = await definition('deploy', deploySh);
+		expect(deploy['signature']).toBe('function deploy');
+		expect(deploy['end_line']).toBe(14);
+		expect(await usages('require_env')).toContainEqual([
+			deploySh,
+			8,
+			'call',
+		]);
+		expect(await usages('common')).toContainEqual([deploySh, 3, 'import']);
+		// Heredoc text is not code
+		expect(await usages('not_a_function')).toEqual([]);
+
+		// Make: targets with prerequisites and $(MAKE) as calls
+		const build = await definition('build', 'build/Makefile');
+		expect(build['symbol_kind']).toBe('target');
+		expect(build['docstring']).toBe('Compile the API server.');
+		expect(build['context_header']).toContain('target build');
+		const test = await definition('test', 'build/Makefile');
+		expect(test['docstring']).toBe('Run the unit tests');
+		const buildRefs = await usages('build');
+		expect(buildRefs).toContainEqual(['build/Makefile', 9, 'call']);
+		expect(buildRefs).not.toContainEqual(['build/Makefile', 3, 'call']);
+		expect(await usages('publish')).toContainEqual([
+			'build/Makefile',
+			14,
+			'call',
+		]);
+
+		// just: recipes, dependencies and `just recipe` in bodies
+		const release = await definition('release', 'build/justfile');
+		expect(release['symbol_kind']).toBe('target');
+		expect(release['signature']).toBe(
+			'release env="staging": build (tag version)',
+		);
+		expect(release['docstring']).toBe('Ship a release to an environment');
+		expect(await usages('tag')).toContainEqual(['build/justfile', 13, 'call']);
+		expect(await usages('notify')).toContainEqual([
+			'build/justfile',
+			15,
+			'call',
+		]);
+	});
+
+	it('Type kinds: interfaces, enums, structs, type aliases and constants', async () => {
+		const definition = (title: string, file_path: string) =>
+			getSymbolFromDefinitionSearch({
+				search,
+				query: title,
+				title,
+				file_path,
+				scope: {path_prefix: [file_path]},
+			});
+		const kindOf = async (title: string, file_path: string) =>
+			(await definition(title, file_path))['symbol_kind'];
+
+		// TypeScript
+		const roles = 'kinds/roles.ts';
+		const userRole = await definition('UserRole', roles);
+		expect(userRole['symbol_kind']).toBe('enum');
+		const admin = await definition('UserRole.Admin', roles);
+		expect(admin['symbol_kind']).toBe('variant');
+		expect(admin['parent_symbol_id']).toBe(userRole['symbol_id']);
+		expect(await kindOf('RoleHolder', roles)).toBe('interface');
+		expect(await kindOf('RoleHolder.role', roles)).toBe('field');
+		expect(await kindOf('RoleHolder.grant', roles)).toBe('method');
+		expect(await kindOf('RoleMap', roles)).toBe('type_alias');
+		const defaultRole = await definition('DEFAULT_ROLE', roles);
+		expect(defaultRole['symbol_kind']).toBe('constant');
+		expect(defaultRole['docstring']).toBe('Role given to new members.');
+		expect(defaultRole['is_exported']).toBe(true);
+		expect(await kindOf('sessionRole', roles)).toBe('variable');
+		expect(await kindOf('userRole', roles)).toBe('function');
+		expect(await kindOf('RoleRegistry', roles)).toBe('class');
+		expect(await kindOf('RoleRegistry.roles', roles)).toBe('field');
+
+		// Python: Enum/Protocol bases, class attributes, ALL_CAPS constants
+		const permissions = 'kinds/permissions.py';
+		expect(await kindOf('Permission', permissions)).toBe('enum');
+		expect(await kindOf('Permission.READ', permissions)).toBe('variant');
+		expect(await kindOf('Grantable', permissions)).toBe('interface');
+		expect(await kindOf('Grant.scope', permissions)).toBe('field');
+		expect(await kindOf('MAX_GRANTS', permissions)).toBe('constant');
+		const locals = await search.search('default_scope', {
+			intent: 'definition',
+			k: 20,
+			explain: false,
+			scope: {path_prefix: [permissions]},
+		});
+		expect(locals.groups.definitions.map(h => h.title)).not.toContain(
+			'default_scope',
+		);
+
+		// Go, Rust and Java type definitions
+		expect(await kindOf('Greeter', 'sample.go')).toBe('struct');
+		expect(await kindOf('Speaker', 'sample.go')).toBe('interface');
+		expect(await kindOf('Mood', 'sample.rs')).toBe('enum');
+		expect(await kindOf('Greeter', 'sample.rs')).toBe('struct');
+		expect(await kindOf('Introduce', 'sample.rs')).toBe('trait');
+		expect(await kindOf('Greeter', 'Sample.java')).toBe('interface');
+
+		// The kinds filter keeps only definitions of those kinds
+		const enums = await search.search('UserRole', {
+			intent: 'definition',
+			k: 20,
+			explain: false,
+			scope: {path_prefix: ['kinds/'], kinds: ['enum']},
+		});
+		const titles = enums.groups.definitions.map(h => h.title);
+		expect(titles).toContain('UserRole');
+		expect(titles).not.toContain('userRole');
+		expect(titles).not.toContain('UserRole.Admin');
+	});
+
+	it('Call graph: resolved callers and callees across files', async () => {
+		const definition = (title: string, file_path: string) =>
+			getSymbolFromDefinitionSearch({
+				search,
+				query: title,
+				title,
+				file_path,
+				scope: {path_prefix: [file_path]},
+				getSymbol: false,
+			});
+		const orders = 'callgraph/orders.ts';
+		const payments = 'callgraph/payments.ts';
+		const placeOrder = await definition('placeOrder', orders);
+		const validateTotal = await definition('validateTotal', orders);
+		const reorder = await definition('reorder', orders);
+		const chargeCard = await definition('chargeCard', payments);
+		const formatReceipt = await definition('formatReceipt', payments);
+
+		const graph = await search.getCallGraph({
+			symbol_id: placeOrder,
+			direction: 'callees',
+			depth: 2,
+		});
+		expect(graph.root.qualname).toBe('placeOrder');
+		expect(graph.callers).toEqual([]);
+		const callee = (name: string) =>
+			graph.callees.find(e => e.callee_name === name);
+		expect(callee('validateTotal')).toMatchObject({
+			caller_id: placeOrder,
+			callee_id: validateTotal,
+			resolution: 'definition',
+			depth: 1,
+		});
+		// Imported from payments.ts
+		expect(callee('chargeCard')).toMatchObject({
+			callee_id: chargeCard,
+			resolution: 'definition',
+			file_path: orders,
+			line: 17,
+			depth: 1,
+		});
+		// Declared but never defined: name-only and not followed
+		expect(callee('notify')).toMatchObject({
+			callee_id: null,
+			resolution: 'name',
+			depth: 1,
+		});
+		expect(callee('formatReceipt')).toMatchObject({
+			caller_id: chargeCard,
+			callee_id: formatReceipt,
+			resolution: 'definition',
+			depth: 2,
+		});
+		expect(graph.nodes.map(n => n.symbol_id)).toEqual(
+			expect.arrayContaining([placeOrder, chargeCard, formatReceipt]),
+		);
+
+		const direct = await search.getCallGraph({
+			symbol_id: placeOrder,
+			direction: 'both',
+			depth: 1,
+		});
+		expect(direct.callees.every(e => e.depth === 1)).toBe(true);
+		expect(direct.callees.map(e => e.callee_name)).not.toContain(
+			'formatReceipt',
+		);
+		expect(direct.callers).toEqual([
+			expect.objectContaining({
+				caller_id: reorder,
+				callee_id: placeOrder,
+				resolution: 'definition',
+				depth: 1,
+			}),
+		]);
+
+		// Callers walk back through files
+		const receipts = await search.getCallGraph({
+			symbol_id: formatReceipt,
+			direction: 'callers',
+			depth: 3,
+		});
+		const callers = receipts.callers.map(e => [e.caller_id, e.depth]);
+		expect(callers).toEqual(
+			expect.arrayContaining([
+				[chargeCard, 1],
+				[placeOrder, 2],
+				[reorder, 3],
+			]),
+		);
+		expect(receipts.truncated).toBe(false);
+	});
+
+	it('Import resolution: binds imports and calls to definitions', async () => {
+		const definition = (title: string, file_path: string) =>
+			getSymbolFromDefinitionSearch({
+				search,
+				query: title,
+				title,
+				file_path,
+				scope: {path_prefix: [file_path]},
+				getSymbol: false,
+			});
+		const usages = async (symbol_id: string) => {
+			const result = await search.findUsages({symbol_id});
+			return {
+				exact: result.by_file.flatMap(g => g.refs),
+				sameName: result.same_name_by_file.flatMap(g => g.refs),
+			};
+		};
+		const ts = 'resolution/ts';
+
+		// Two unrelated `Config` classes keep their refs apart
+		const config = await definition('Config', `${ts}/src/config.ts`);
+		const legacy = await definition('Config', `${ts}/src/legacy/config.ts`);
+		const configRefs = await usages(config);
+		expect(configRefs.exact).toContainEqual(
+			expect.objectContaining({
+				file_path: `${ts}/src/app.ts`,
+				ref_kind: 'import',
+				target_symbol_id: config,
+			}),
+		);
+		expect(
+			[...configRefs.exact, ...configRefs.sameName].some(
+				r => r.file_path === `${ts}/src/server.ts`,
+			),
+		).toBe(false);
+		// `@app/legacy/config` through tsconfig paths
+		const legacyRefs = await usages(legacy);
+		expect(legacyRefs.exact).toContainEqual(
+			expect.objectContaining({
+				file_path: `${ts}/src/server.ts`,
+				ref_kind: 'import',
+				target_symbol_id: legacy,
+			}),
+		);
+		expect(
+			legacyRefs.exact.some(r => r.file_path === `${ts}/src/app.ts`),
+		).toBe(false);
+
+		// Calls through an import are exact; a same-named method is not
+		const loadConfig = await definition('loadConfig', `${ts}/src/config.ts`);
+		const loadRefs = await usages(loadConfig);
+		expect(loadRefs.exact).toContainEqual(
+			expect.objectContaining({
+				file_path: `${ts}/src/app.ts`,
+				ref_kind: 'call',
+				target_symbol_id: loadConfig,
+			}),
+		);
+		expect(loadRefs.sameName).toContainEqual(
+			expect.objectContaining({
+				file_path: `${ts}/src/server.ts`,
+				ref_kind: 'call',
+				target_symbol_id: null,
+			}),
+		);
+
+		// Bound call sites per language
+		const cases: Array<{title: string; file: string; caller: string}> = [
+			// package.json `exports` of a workspace package (dist → src)
+			{
+				title: 'slugify',
+				file: `${ts}/packages/shared/src/index.ts`,
+				caller: `${ts}/src/app.ts`,
+			},
+			// `from .pricing import total_price` and `pricing.total_price()`
+			{
+				title: 'total_price',
+				file: 'resolution/py/shop/pricing.py',
+				caller: 'resolution/py/shop/checkout.py',
+			},
+			// go.mod module path
+			{
+				title: 'Total',
+				file: 'resolution/go/pricing/pricing.go',
+				caller: 'resolution/go/cmd/main.go',
+			},
+			// Static method of an imported class
+			{
+				title: 'Money.format',
+				file: 'resolution/jvm/src/main/java/com/shop/util/Money.java',
+				caller: 'resolution/jvm/src/main/java/com/shop/App.java',
+			},
+			// Kotlin top-level function
+			{
+				title: 'slug',
+				file: 'resolution/jvm/src/main/kotlin/com/shop/text/Slug.kt',
+				caller: 'resolution/jvm/src/main/kotlin/com/shop/Main.kt',
+			},
+		];
+		for (const c of cases) {
+			const symbolId = await definition(c.title, c.file);
+			const {exact} = await usages(symbolId);
+			expect(exact).toContainEqual(
+				expect.objectContaining({
+					file_path: c.caller,
+					ref_kind: 'call',
+					target_symbol_id: symbolId,
+				}),
+			);
+		}
+
+		const checkoutCalls = (
+			await usages(
+				await definition('total_price', 'resolution/py/shop/pricing.py'),
+			)
+		).exact.filter(
+			r =>
+				r.file_path === 'resolution/py/shop/checkout.py' &&
+				r.ref_kind === 'call',
+		);
+		expect(checkoutCalls).toHaveLength(2);
+
+		// Refs the index cannot bind stay exact: a Go call from the same
+		// package (no import) and a Ruby superclass (no import resolution)
+		const total = await definition('Total', 'resolution/go/pricing/pricing.go');
+		expect((await usages(total)).exact).toContainEqual(
+			expect.objectContaining({
+				file_path: 'resolution/go/pricing/discount.go',
+				ref_kind: 'call',
+			}),
+		);
+		const record = await definition(
+			'Billing::Record',
+			'scripting/billing/record.rb',
+		);
+		expect(
+			(await usages(record)).exact.some(
+				r => r.file_path === 'scripting/billing/invoice.rb',
+			),
+		).toBe(true);
+	});
+
+	it('Ref kinds: types, inheritance and instantiations are told apart', async () => {
+		const refLines = async (
+			symbol_name: string,
+			ref_kind: V2RefKind,
+			file_path: string,
+		) => {
+			const result = await search.findUsages({
+				symbol_name,
+				ref_kinds: [ref_kind],
+				scope: {path_prefix: ['refkinds/']},
+			});
+			const refs = result.by_file.flatMap(g => g.refs);
+			expect(refs.every(r => r.ref_kind === ref_kind)).toBe(true);
+			const lines = refs
+				.filter(r => r.file_path === `refkinds/${file_path}`)
+				.map(r => r.start_line);
+			return [...new Set(lines)].sort((a, b) => a - b);
+		};
+
+		const cases: Array<[string, V2RefKind, string, number[]]> = [
+			// class Gauge extends BaseWidget implements Renderable
+			['BaseWidget', 'extends', 'widgets.ts', [12]],
+			['Renderable', 'implements', 'widgets.ts', [12]],
+			['Renderable', 'type_ref', 'widgets.ts', [20]],
+			['Gauge', 'type_ref', 'widgets.ts', [18]],
+			['Gauge', 'instantiation', 'widgets.ts', [19, 21]],
+			// Python bases, return annotations and `Ledger()`
+			['Ledger', 'extends', 'ledger.py', [8]],
+			['Ledger', 'type_ref', 'ledger.py', [12]],
+			['Ledger', 'instantiation', 'ledger.py', [15]],
+			['AuditLedger', 'instantiation', 'ledger.py', [14]],
+			['Dispatchable', 'implements', 'Courier.java', [9]],
+			['Courier', 'type_ref', 'Courier.java', [14, 15]],
+			['Courier', 'instantiation', 'Courier.java', [15]],
+			// impl Meter for Odometer; Odometer { km: 0 }
+			['Meter', 'implements', 'odometer.rs', [11]],
+			['Odometer', 'type_ref', 'odometer.rs', [11, 17, 21]],
+			['Odometer', 'instantiation', 'odometer.rs', [18]],
+			// &Tally{...} composite literal
+			['Tally', 'type_ref', 'tally.go', [9, 14]],
+			['Tally', 'instantiation', 'tally.go', [10]],
+		];
+		for (const [name, kind, file, lines] of cases) {
+			expect({name, kind, lines: await refLines(name, kind, file)}).toEqual({
+				name,
+				kind,
+				lines,
+			});
+		}
+	});
+
+	it('Access: identifier refs tell writes from reads', async () => {
+		const writes = async (symbol_name: string) =>
+			(
+				await search.findUsages({
+					symbol_name,
+					access: 'write',
+					scope: {path_prefix: ['access/']},
+				})
+			).by_file
+				.flatMap(g => g.refs)
+				.map(r => ({line: r.start_line, access: r.access}))
+				.sort((a, b) => a.line - b.line);
+
+		// Field initialiser `hits = 0`; `this.hits += 1` reads and writes;
+		// `this.hits = 0` only writes
+		expect(await writes('hits')).toEqual([
+			{line: 3, access: 'write'},
+			{line: 6, access: 'read_write'},
+			{line: 11, access: 'write'},
+		]);
+		// Java field initialiser and `tokens--`
+		expect(await writes('tokens')).toEqual([
+			{line: 3, access: 'write'},
+			{line: 9, access: 'read_write'},
+		]);
+		expect(await writes('retries')).toEqual([
+			{line: 5, access: 'write'},
+			{line: 10, access: 'read_write'},
+		]);
+		// Field initialiser and `&mut self.ticks`
+		expect(await writes('ticks')).toEqual([
+			{line: 12, access: 'write'},
+			{line: 16, access: 'read_write'},
+		]);
+		// Keyed composite literal and `s.misses++`
+		expect(await writes('misses')).toEqual([
+			{line: 10, access: 'write'},
+			{line: 15, access: 'read_write'},
+		]);
+
+		// `read_write` alone: compound assignments and increments
+		expect(
+			(
+				await search.findUsages({
+					symbol_name: 'hits',
+					access: 'read_write',
+					scope: {path_prefix: ['access/']},
+				})
+			).by_file.flatMap(g => g.refs.map(r => r.start_line)),
+		).toEqual([6]);
+	});
+
 	it('Java/C#/Kotlin/Swift/PHP: indexes public Greeter definitions', async () => {
 		const cases: Array<{ext: string; file: string}> = [
 			{ext: '.java', file: 'Sample.java'},
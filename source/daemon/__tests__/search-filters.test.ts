@@ -1,13 +1,14 @@
 /**
  * Tests for v2 search scope filters.
  *
- * V2 supports transparent, path-based filtering (plus extensions) with no
- * hidden heuristic exclusions.
+ * V2 supports transparent, path-based filtering (plus extensions and
+ * workspace members) with no hidden heuristic exclusions.
  */
 
 import {describe, it, expect, beforeAll, afterAll} from 'vitest';
 import {IndexingServiceV2} from '../services/v2/indexing.js';
 import {SearchEngineV2} from '../services/v2/search/engine.js';
+import {loadV2Manifest} from '../services/v2/manifest.js';
 import type {V2SearchResponse, V2HitBase} from '../services/v2/search/types.js';
 import {copyFixtureToTemp, type TestContext} from './helpers.js';
 
@@ -139,6 +140,147 @@ describe('V2 Search Scope Filters', () => {
 		});
 	});
 
+	describe('crate filters', () => {
+		it('filters by Cargo workspace member', async () => {
+			const results = await search.search('client', {
+				intent: 'concept',
+				k: 30,
+				explain: false,
+				scope: {crate: ['fixture-net']},
+			});
+
+			const hits = allHits(results);
+			expect(hits.length).toBeGreaterThan(0);
+			expect(
+				hits.every(h => h.file_path.startsWith('rust_crate/')),
+			).toBe(true);
+		});
+
+		it('filters by pnpm workspace member', async () => {
+			const results = await search.search('slugify', {
+				intent: 'definition',
+				k: 30,
+				explain: false,
+				scope: {crate: ['@fixture/shared']},
+			});
+
+			const hits = allHits(results);
+			expect(hits.length).toBeGreaterThan(0);
+			expect(
+				hits.every(h =>
+					h.file_path.startsWith('resolution/ts/packages/shared/'),
+				),
+			).toBe(true);
+		});
+
+		it('applies the crate scope to find_references', async () => {
+			const usages = await search.findUsages({
+				symbol_name: 'Client',
+				scope: {crate: ['fixture-net']},
+			});
+
+			expect(usages.total_refs).toBeGreaterThan(0);
+			expect(
+				usages.by_file.every(g => g.file_path.startsWith('rust_crate/')),
+			).toBe(true);
+		});
+
+		it('reports per-crate counts in the manifest', async () => {
+			const manifest = await loadV2Manifest(ctx.projectRoot, {
+				repoId: 'unknown',
+				revision: 'working',
+			});
+			const counts = manifest.stats.crates?.['fixture-net'];
+			expect(counts?.totalFiles).toBeGreaterThan(0);
+			expect(counts?.totalSymbols).toBeGreaterThan(0);
+		});
+	});
+
+	describe('tests filters', () => {
+		it('excludes test files and test items', async () => {
+			const results = await search.search('add', {
+				intent: 'definition',
+				k: 50,
+				explain: false,
+				scope: {tests: 'exclude'},
+			});
+
+			const hits = allHits(results);
+			expect(hits.length).toBeGreaterThan(0);
+			expect(hits.some(h => h.file_path.includes('__tests__'))).toBe(false);
+			expect(hits.some(h => h.title.startsWith('test_'))).toBe(false);
+		});
+
+		it('returns only test code', async () => {
+			const results = await search.search('test', {
+				intent: 'definition',
+				k: 50,
+				explain: false,
+				scope: {tests: 'only'},
+			});
+
+			const hits = allHits(results);
+			expect(hits.some(h => h.file_path.includes('__tests__'))).toBe(true);
+			expect(
+				hits.some(h => h.file_path === 'sample.rs' && h.title === 'test_add'),
+			).toBe(true);
+		});
+
+		it('marks #[test] functions under #[cfg(test)] as tests', async () => {
+			const results = await search.search('test_greeter', {
+				intent: 'definition',
+				k: 10,
+				explain: false,
+				scope: {extension: ['.rs']},
+			});
+			const hit = results.groups.definitions.find(
+				h => h.title === 'test_greeter',
+			);
+			expect(hit).toBeDefined();
+
+			const symbol = await search.getSymbol(hit!.id);
+			expect(symbol?.['is_test']).toBe(true);
+
+			const add = await search.search('add', {
+				intent: 'definition',
+				k: 10,
+				explain: false,
+				scope: {extension: ['.rs']},
+			});
+			const addHit = add.groups.definitions.find(h => h.title === 'add');
+			const addSymbol = await search.getSymbol(addHit!.id);
+			expect(addSymbol?.['is_test']).toBe(false);
+		});
+	});
+
+	describe('visibility filters', () => {
+		it('keeps only public definitions', async () => {
+			const results = await search.search('helper', {
+				intent: 'definition',
+				k: 50,
+				explain: false,
+				scope: {extension: ['.rs'], visibility: ['public']},
+			});
+
+			const titles = results.groups.definitions.map(h => h.title);
+			expect(titles).not.toContain('crate_helper');
+			expect(titles).not.toContain('PrivateHelper');
+		});
+
+		it('selects crate-visible definitions', async () => {
+			const results = await search.search('crate_helper', {
+				intent: 'definition',
+				k: 10,
+				explain: false,
+				scope: {extension: ['.rs'], visibility: ['crate']},
+			});
+
+			expect(results.groups.definitions.map(h => h.title)).toContain(
+				'crate_helper',
+			);
+		});
+	});
+
 	describe('filter combinations', () => {
 		it('combines path_prefix + extension', async () => {
 			const results = await search.search('user', {
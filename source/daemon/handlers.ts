@@ -20,6 +20,65 @@ import {captureException, flushSentry} from './lib/telemetry/sentry.js';
 // Parameter Schemas
 // ============================================================================
 
+const symbolKindSchema = z.enum([
+	'function',
+	'class',
+	'interface',
+	'struct',
+	'trait',
+	'enum',
+	'method',
+	'macro',
+	'constant',
+	'variable',
+	'type_alias',
+	'variant',
+	'field',
+	'module',
+	'message',
+	'service',
+	'rpc',
+	'table',
+	'column',
+	'endpoint',
+	'operation',
+	'resource',
+	'module_call',
+	'stage',
+	'key',
+	'target',
+]);
+
+const refKindSchema = z.enum([
+	'import',
+	'call',
+	'instantiation',
+	'identifier',
+	'type_ref',
+	'extends',
+	'implements',
+	'string_literal',
+	'doc_link',
+]);
+
+/**
+ * Scope filters shared by every search tool. Visibility and kinds filter
+ * definitions; tools that return refs apply the path/extension/crate/test
+ * filters only.
+ */
+const scopeSchema = z.object({
+	path_prefix: z.array(z.string()).optional(),
+	path_contains: z.array(z.string()).optional(),
+	path_not_contains: z.array(z.string()).optional(),
+	extension: z.array(z.string()).optional(),
+	crate: z.array(z.string()).optional(),
+	tests: z.enum(['include', 'exclude', 'only']).optional(),
+	visibility: z
+		.array(z.enum(['public', 'crate', 'restricted', 'protected', 'private']))
+		.optional(),
+	kinds: z.array(symbolKindSchema).optional(),
+});
+
 const searchParamsSchema = z.object({
 	query: z.string().min(1),
 	intent: z
@@ -32,14 +91,7 @@ const searchParamsSchema = z.object({
 			'similar_code',
 		])
 		.optional(),
-	scope: z
-		.object({
-			path_prefix: z.array(z.string()).optional(),
-			path_contains: z.array(z.string()).optional(),
-			path_not_contains: z.array(z.string()).optional(),
-			extension: z.array(z.string()).optional(),
-		})
-		.optional(),
+	scope: scopeSchema.optional(),
 	k: z.number().min(1).max(100).optional(),
 	explain: z.boolean().optional(),
 });
@@ -52,20 +104,34 @@ const findUsagesParamsSchema = z
 	.object({
 		symbol_id: z.string().min(1).optional(),
 		symbol_name: z.string().min(1).optional(),
-		scope: z
-			.object({
-				path_prefix: z.array(z.string()).optional(),
-				path_contains: z.array(z.string()).optional(),
-				path_not_contains: z.array(z.string()).optional(),
-				extension: z.array(z.string()).optional(),
-			})
-			.optional(),
+		scope: scopeSchema.optional(),
+		ref_kinds: z.array(refKindSchema).optional(),
+		access: z.enum(['write', 'read_write']).optional(),
 		k: z.number().min(1).max(2000).optional(),
 	})
 	.refine(v => v.symbol_id || v.symbol_name, {
 		message: 'symbol_id or symbol_name is required',
 	});
 
+const findImplementationsParamsSchema = z
+	.object({
+		symbol_id: z.string().min(1).optional(),
+		symbol_name: z.string().min(1).optional(),
+		scope: scopeSchema.optional(),
+		k: z.number().min(1).max(500).optional(),
+	})
+	.refine(v => v.symbol_id || v.symbol_name, {
+		message: 'symbol_id or symbol_name is required',
+	});
+
+const getCallGraphParamsSchema = z.object({
+	symbol_id: z.string().min(1),
+	direction: z.enum(['callers', 'callees', 'both']).optional(),
+	depth: z.number().int().min(1).max(5).optional(),
+	scope: scopeSchema.optional(),
+	k: z.number().min(1).max(500).optional(),
+});
+
 const expandContextParamsSchema = z.object({
 	table: z.enum(['symbols', 'chunks', 'files']),
 	id: z.string().min(1),
@@ -136,6 +202,24 @@ const findUsagesHandler: Handler = async (params, ctx) => {
 	return ctx.owner.findUsages(validated);
 };
 
+/**
+ * Find implementations handler.
+ */
+const findImplementationsHandler: Handler = async (params, ctx) => {
+	const validated = findImplementationsParamsSchema.parse(params ?? {});
+	await ctx.owner.ensureInitialized();
+	return ctx.owner.findImplementations(validated);
+};
+
+/**
+ * Call graph handler.
+ */
+const getCallGraphHandler: Handler = async (params, ctx) => {
+	const validated = getCallGraphParamsSchema.parse(params ?? {});
+	await ctx.owner.ensureInitialized();
+	return ctx.owner.getCallGraph(validated);
+};
+
 /**
  * Expand context handler.
  */
@@ -307,6 +391,8 @@ export function createHandlers(): HandlerRegistry {
 		search: searchHandler,
 		getSymbol: getSymbolHandler,
 		findUsages: findUsagesHandler,
+		findImplementations: findImplementationsHandler,
+		getCallGraph: getCallGraphHandler,
 		expandContext: expandContextHandler,
 		index: indexHandler,
 		indexAsync: indexAsyncHandler,
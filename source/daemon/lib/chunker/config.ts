@@ -0,0 +1,93 @@
+/**
+ * Config files - JSON, YAML and TOML. Every key path
+ * (`database.pool.max_size`) is outlined as a `key` symbol with a preview of
+ * its value, so a dotted key can be found exactly and linked to the string
+ * literals in code that read it.
+ */
+
+import path from 'node:path';
+import {readKeyLines, type KeyFormat} from './keys.js';
+import {type FileOutline, OutlineBuilder, qualify} from './outline.js';
+
+const CONFIG_FORMATS: Record<string, KeyFormat> = {
+	'.json': 'json',
+	'.yaml': 'yaml',
+	'.yml': 'yaml',
+	'.toml': 'toml',
+};
+
+/**
+ * Generated lockfiles: large, and nothing reads their keys by path.
+ */
+const LOCKFILE_NAMES = new Set([
+	'package-lock.json',
+	'npm-shrinkwrap.json',
+	'pnpm-lock.yaml',
+]);
+
+/**
+ * Files with more keys than this are data, not configuration.
+ */
+const MAX_CONFIG_KEYS = 2000;
+
+const MAX_VALUE_PREVIEW_CHARS = 80;
+
+/**
+ * A dotted key path, bare or as a Spring-style placeholder with an optional
+ * default (`${database.pool.max_size:10}`).
+ */
+const KEY_PATH_LITERAL_PATTERN =
+	/^\$?\{?([A-Za-z_][\w-]*(?:\.[A-Za-z_][\w-]*)+)(?::[^}]*)?\}?$/;
+
+/**
+ * Outline a config file, or null when the file is not one. Keys inside list
+ * items (`- name: x`, `[[servers]]`) have no stable path and are skipped.
+ */
+export function outlineConfigFile(
+	filepath: string,
+	content: string,
+): FileOutline | null {
+	const basename = path.basename(filepath);
+	const format = CONFIG_FORMATS[path.extname(basename).toLowerCase()];
+	if (!format || LOCKFILE_NAMES.has(basename)) return null;
+	// Top-level JSON arrays are data
+	if (format === 'json' && !/^\s*\{/.test(content)) return null;
+
+	const keys = readKeyLines(content, format);
+	if (keys.length === 0 || keys.length > MAX_CONFIG_KEYS) return null;
+
+	const outline = new OutlineBuilder(
+		content,
+		format === 'json' ? ['//'] : ['#'],
+	);
+	const paths: string[] = [];
+	for (const key of keys) {
+		const parent = key.parent === -1 ? null : paths[key.parent]!;
+		const keyPath = qualify(parent, key.key);
+		paths.push(keyPath);
+		if (key.inSequence) continue;
+		outline.defineLines(
+			'key',
+			key.key,
+			parent,
+			key.line + 1,
+			key.endLine + 1,
+			key.value === null ? keyPath : `${keyPath} = ${preview(key.value)}`,
+		);
+	}
+
+	return outline.build(format);
+}
+
+function preview(value: string): string {
+	return value.length > MAX_VALUE_PREVIEW_CHARS
+		? `${value.slice(0, MAX_VALUE_PREVIEW_CHARS)}…`
+		: value;
+}
+
+/**
+ * The key path a string literal names (`"database.pool.max_size"`), or null.
+ */
+export function keyPathFromLiteral(text: string): string | null {
+	return text.match(KEY_PATH_LITERAL_PATTERN)?.[1] ?? null;
+}
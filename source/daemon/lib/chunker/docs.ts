@@ -0,0 +1,477 @@
+/**
+ * Documentation parsing for the chunker.
+ *
+ * Pure text helpers (no tree-sitter) that find:
+ * - doc links: Rust intra-doc links, JSDoc/Javadoc `{@link}` and `@see`,
+ *   Python Sphinx roles, C# `<see cref>`, Doxygen `@ref`/`@see`
+ * - doc examples: Rust doc tests, JSDoc `@example` blocks, fenced code in
+ *   doc comments, Python `>>>` doctests, Doxygen `@code` blocks
+ */
+
+import type {SupportedLanguage} from './types.js';
+
+export type DocLink = {
+	/** Last path segment (`greet` for `Greeter::greet`) */
+	name: string;
+	/** Normalized target path (`Greeter::greet`, `pkg.Class.method`) */
+	path: string;
+	/** Offset of the target within the scanned text */
+	offset: number;
+	length: number;
+};
+
+export type DocLine = {
+	/** 1-indexed file line */
+	line: number;
+	/** Line text with comment markers stripped */
+	text: string;
+};
+
+export type DocExample = {
+	code: string;
+	/** File line of each code line (parallel to `code.split('\n')`) */
+	lineNumbers: number[];
+	startLine: number;
+	endLine: number;
+};
+
+/**
+ * Rust intra-doc link disambiguators (`struct@Foo`, `fn@foo`, ...).
+ */
+const RUST_DISAMBIGUATOR =
+	/^(?:struct|enum|trait|fn|mod|module|type|const|constant|static|macro|method|field|variant|value|tymethod|union|prim|primitive|derive|attr)@/;
+
+/**
+ * Rust code block attributes that still mark a block as a doc test.
+ */
+const RUST_DOCTEST_ATTRIBUTES = new Set([
+	'rust',
+	'ignore',
+	'no_run',
+	'should_panic',
+	'compile_fail',
+	'test_harness',
+	'allow_fail',
+	'edition2015',
+	'edition2018',
+	'edition2021',
+	'edition2024',
+]);
+
+/**
+ * Fence info strings accepted as code in each language's docs.
+ */
+const FENCE_LANGUAGES: Partial<Record<SupportedLanguage, string[]>> = {
+	javascript: ['js', 'javascript', 'jsx'],
+	typescript: ['ts', 'typescript', 'js', 'javascript'],
+	tsx: ['tsx', 'ts', 'typescript', 'jsx', 'js'],
+	python: ['py', 'python', 'pycon'],
+	java: ['java'],
+	kotlin: ['kotlin', 'kt'],
+	csharp: ['cs', 'csharp'],
+	swift: ['swift'],
+	dart: ['dart'],
+	php: ['php'],
+	c: ['c'],
+	cpp: ['cpp', 'c++', 'cc', 'c'],
+	ruby: ['ruby', 'rb'],
+	scala: ['scala'],
+	elixir: ['elixir', 'ex', 'iex'],
+	lua: ['lua'],
+};
+
+const JSDOC_LANGUAGES = new Set<SupportedLanguage>([
+	'javascript',
+	'typescript',
+	'tsx',
+	'java',
+	'kotlin',
+	'php',
+]);
+
+const TRIPLE_SLASH_LANGUAGES = new Set<SupportedLanguage>([
+	'rust',
+	'csharp',
+	'swift',
+	'dart',
+	'c',
+	'cpp',
+]);
+
+/**
+ * Languages documented with Doxygen: `///`, `//!`, `/** *\/` and `/*! *\/`
+ * comments, `@ref`/`@see` links and `@code` examples.
+ */
+const DOXYGEN_LANGUAGES = new Set<SupportedLanguage>(['c', 'cpp']);
+
+/**
+ * Whether a comment node's text is documentation (as opposed to a plain
+ * comment) in the given language.
+ */
+export function isDocComment(text: string, lang: SupportedLanguage): boolean {
+	if (lang === 'rust' || DOXYGEN_LANGUAGES.has(lang)) {
+		return /^(?:\/\/\/(?!\/)|\/\/!|\/\*\*(?![*/])|\/\*!)/.test(text);
+	}
+	if (TRIPLE_SLASH_LANGUAGES.has(lang) && /^\/\/\/(?!\/)/.test(text)) {
+		return true;
+	}
+	return /^\/\*\*(?![*/])/.test(text);
+}
+
+/**
+ * Find doc links in documentation text.
+ */
+export function extractDocLinks(
+	text: string,
+	lang: SupportedLanguage,
+): DocLink[] {
+	switch (lang) {
+		case 'rust':
+			return extractRustDocLinks(text);
+		case 'python':
+			return extractSphinxLinks(text);
+		case 'csharp':
+			return extractCrefLinks(text);
+		case 'c':
+		case 'cpp':
+			return extractDoxygenLinks(text);
+		default:
+			return JSDOC_LANGUAGES.has(lang) ? extractJsDocLinks(text) : [];
+	}
+}
+
+function extractRustDocLinks(text: string): DocLink[] {
+	const links: DocLink[] = [];
+	const add = (raw: string, offset: number, backticked: boolean) => {
+		const link = normalizeRustDocPath(raw, backticked);
+		if (link) links.push({...link, offset, length: raw.length});
+	};
+
+	// [label](target) and [label](`target`)
+	const inline = /\[[^\[\]\n]*\]\(\s*(`?)([^()`\s]+)\1\s*\)/g;
+	for (const m of text.matchAll(inline)) {
+		add(m[2]!, m.index! + m[0].lastIndexOf(m[2]!), true);
+	}
+	// [label][target] and [label][`target`]
+	const reference = /\[[^\[\]\n]*\]\[(`?)([^\[\]`\n]+)\1\]/g;
+	for (const m of text.matchAll(reference)) {
+		add(m[2]!, m.index! + m[0].lastIndexOf(m[2]!), m[1] === '`');
+	}
+	// [label]: target (reference definitions)
+	const definition =
+		/^(?:[ \t]*(?:\/\/[/!]|\*))?[ \t]*\[[^\[\]\n]+\]:[ \t]*(`?)(\S+?)\1[ \t]*$/gm;
+	for (const m of text.matchAll(definition)) {
+		add(m[2]!, m.index! + m[0].lastIndexOf(m[2]!), true);
+	}
+	// [`target`] and [target] shortcuts
+	const shortcut = /(?<![\]\\])\[(`?)([^\[\]`\n]+)\1\](?![(\[:])/g;
+	for (const m of text.matchAll(shortcut)) {
+		add(m[2]!, m.index! + 1 + m[1]!.length, m[1] === '`');
+	}
+	return links.sort((a, b) => a.offset - b.offset);
+}
+
+function normalizeRustDocPath(
+	raw: string,
+	backticked: boolean,
+): {name: string; path: string} | null {
+	let target = raw.trim();
+	if (target.includes('://') || target.startsWith('#')) return null;
+	const hasDisambiguator = RUST_DISAMBIGUATOR.test(target);
+	target = target.replace(RUST_DISAMBIGUATOR, '');
+	const hasSuffix = /(?:\(\)|!)$/.test(target);
+	target = target.replace(/(?:\(\)|!)$/, '');
+	if (!/^(?:[A-Za-z_]\w*)(?:::[A-Za-z_]\w*)*$/.test(target)) return null;
+	// Plain `[word]` is usually prose; only treat it as a link when it looks
+	// like a path or type name.
+	if (
+		!backticked &&
+		!hasDisambiguator &&
+		!hasSuffix &&
+		!target.includes('::') &&
+		!/^[A-Z]/.test(target)
+	) {
+		return null;
+	}
+	const segments = target.split('::');
+	return {name: segments[segments.length - 1]!, path: target};
+}
+
+function extractJsDocLinks(text: string): DocLink[] {
+	const links: DocLink[] = [];
+	const add = (raw: string, offset: number) => {
+		const link = normalizeDottedPath(raw);
+		if (link) links.push({...link, offset, length: raw.length});
+	};
+
+	for (const m of text.matchAll(
+		/\{@(?:link|linkcode|linkplain)\s+([^\s|}]+)[^}]*\}/g,
+	)) {
+		add(m[1]!, m.index! + m[0].indexOf(m[1]!));
+	}
+	for (const m of text.matchAll(/@see\s+([^\s{<"'`]+)/g)) {
+		add(m[1]!, m.index! + m[0].indexOf(m[1]!));
+	}
+	return links;
+}
+
+function extractSphinxLinks(text: string): DocLink[] {
+	const links: DocLink[] = [];
+	for (const m of text.matchAll(
+		/:(?:py:)?(?:func|meth|class|mod|attr|exc|data|const|obj):`([^`]+)`/g,
+	)) {
+		const raw = m[1]!;
+		const explicit = raw.match(/<([^>]+)>\s*$/);
+		const target = explicit ? explicit[1]! : raw;
+		const link = normalizeDottedPath(target.replace(/^[~!.]+/, ''));
+		if (!link) continue;
+		const offset = m.index! + m[0].indexOf(target);
+		links.push({...link, offset, length: target.length});
+	}
+	return links;
+}
+
+function extractCrefLinks(text: string): DocLink[] {
+	const links: DocLink[] = [];
+	const cref = /<see(?:also)?\s+cref="(?:[A-Z]:)?([^"]+)"/g;
+	for (const m of text.matchAll(cref)) {
+		const raw = m[1]!;
+		const link = normalizeDottedPath(raw.replace(/\{[^}]*\}/g, ''));
+		if (!link) continue;
+		const offset = m.index! + m[0].indexOf(raw);
+		links.push({...link, offset, length: raw.length});
+	}
+	return links;
+}
+
+function extractDoxygenLinks(text: string): DocLink[] {
+	const links: DocLink[] = [];
+	for (const m of text.matchAll(/[@\\](?:ref|see|sa|copydoc)\s+([^\s,;]+)/g)) {
+		const raw = m[1]!.replace(/\(.*$/, '').replace(/[.:]+$/, '');
+		const segments = raw.split(/::|\.|#/);
+		if (!segments.every(s => /^~?[A-Za-z_]\w*$/.test(s))) continue;
+		links.push({
+			name: segments[segments.length - 1]!,
+			path: raw,
+			offset: m.index! + m[0].indexOf(raw),
+			length: raw.length,
+		});
+	}
+	return links;
+}
+
+/**
+ * Normalize `module:foo.Bar#baz(x)` style targets to `foo.Bar.baz`.
+ */
+function normalizeDottedPath(
+	raw: string,
+): {name: string; path: string} | null {
+	if (raw.includes('://')) return null;
+	const target = raw
+		.replace(/^module:/, '')
+		.replace(/\(.*$/, '')
+		.replace(/[#~]/g, '.')
+		.replace(/\.+$/, '');
+	const segments = target.split('.');
+	if (
+		segments.length === 0 ||
+		!segments.every(s => /^[A-Za-z_$][\w$]*$/.test(s))
+	) {
+		return null;
+	}
+	return {name: segments[segments.length - 1]!, path: target};
+}
+
+/**
+ * Documentation lines for a definition spanning `startLine..endLine`
+ * (1-indexed): preceding `///` lines or `/** *\/` block, or the Python
+ * docstring inside the body.
+ */
+export function collectDocLines(
+	lines: string[],
+	startLine: number,
+	endLine: number,
+	lang: SupportedLanguage,
+): DocLine[] {
+	if (lang === 'python') {
+		return collectPythonDocstringLines(lines, startLine, endLine);
+	}
+
+	const out: DocLine[] = [];
+	let i = startLine - 2;
+	// Attributes / decorators / annotations / C++ template heads sit between
+	// docs and the item.
+	while (i >= 0 && /^\s*(?:#!?\[|@|template\s*<)/.test(lines[i] ?? '')) i--;
+
+	const isDoxygen = DOXYGEN_LANGUAGES.has(lang);
+	if (TRIPLE_SLASH_LANGUAGES.has(lang)) {
+		const lineDoc = isDoxygen ? /^\/\/[/!](?!\/)/ : /^\/\/\/(?!\/)/;
+		for (; i >= 0; i--) {
+			const text = (lines[i] ?? '').trim();
+			if (!lineDoc.test(text)) break;
+			out.unshift({line: i + 1, text: text.replace(/^\/\/[/!] ?/, '')});
+		}
+		// Doxygen also allows block comments
+		if (out.length > 0 || !isDoxygen) return out;
+	}
+
+	if (!JSDOC_LANGUAGES.has(lang) && !isDoxygen) return out;
+	const last = (lines[i] ?? '').trim();
+	if (!last.endsWith('*/')) return out;
+	for (; i >= 0; i--) {
+		const raw = lines[i] ?? '';
+		const text = raw
+			.trim()
+			.replace(/\*\/$/, '')
+			.replace(/^\/\*[*!]\s?/, '')
+			.replace(/^\* ?/, '');
+		out.unshift({line: i + 1, text});
+		if (raw.includes('/**') || (isDoxygen && raw.includes('/*!'))) {
+			return out;
+		}
+		if (raw.includes('/*')) return [];
+	}
+	return [];
+}
+
+function collectPythonDocstringLines(
+	lines: string[],
+	startLine: number,
+	endLine: number,
+): DocLine[] {
+	const last = Math.min(endLine, startLine + 10);
+	for (let i = startLine; i < last; i++) {
+		const open = (lines[i] ?? '').match(/^(\s*)[rRuU]?("""|''')/);
+		if (!open) continue;
+		const quote = open[2]!;
+		const out: DocLine[] = [];
+		const indent = open[1]!.length;
+		for (let j = i; j < endLine; j++) {
+			let text = lines[j] ?? '';
+			if (j === i) text = text.slice(open[0].length);
+			else text = text.slice(Math.min(indent, text.search(/\S|$/)));
+			const close = text.indexOf(quote);
+			if (close !== -1) {
+				out.push({line: j + 1, text: text.slice(0, close)});
+				return out;
+			}
+			out.push({line: j + 1, text});
+		}
+		return out;
+	}
+	return [];
+}
+
+/**
+ * Code examples in documentation lines.
+ */
+export function extractDocExamples(
+	docLines: DocLine[],
+	lang: SupportedLanguage,
+): DocExample[] {
+	const examples: DocExample[] = [];
+	let fence: {marker: string; code: DocLine[]; isCode: boolean} | null = null;
+	let example: DocLine[] | null = null;
+	let doctest: DocLine[] | null = null;
+	let doctestEnd = 0;
+
+	const flush = (code: DocLine[] | null, endLine?: number) => {
+		if (!code || code.length === 0) return;
+		if (!code.some(l => l.text.trim().length > 0)) return;
+		examples.push({
+			code: code.map(l => l.text).join('\n'),
+			lineNumbers: code.map(l => l.line),
+			startLine: code[0]!.line,
+			endLine: endLine ?? code[code.length - 1]!.line,
+		});
+	};
+
+	for (const docLine of docLines) {
+		const text = docLine.text;
+		const trimmed = text.trim();
+
+		if (fence) {
+			if (trimmed.startsWith(fence.marker)) {
+				if (fence.isCode) flush(fence.code);
+				fence = null;
+			} else {
+				fence.code.push({
+					line: docLine.line,
+					text: lang === 'rust' ? text.replace(/^#(?: |$)/, '') : text,
+				});
+			}
+			continue;
+		}
+
+		// Doxygen `@code` / `\code{.cpp}` blocks run until `@endcode`
+		const doxygenCode = trimmed.match(/^([@\\])code\b/);
+		if (doxygenCode && DOXYGEN_LANGUAGES.has(lang)) {
+			fence = {marker: `${doxygenCode[1]}endcode`, code: [], isCode: true};
+			continue;
+		}
+
+		const open = trimmed.match(/^(`{3,}|~{3,})\s*([\w,\s.+-]*)$/);
+		if (open) {
+			// A fenced block inside `@example` is the example itself
+			example = null;
+			fence = {
+				marker: open[1]!,
+				code: [],
+				isCode: isDocTestFence(open[2]!, lang),
+			};
+			continue;
+		}
+
+		// Python doctests: `>>>` statements with `...` continuations; output
+		// lines belong to the example until a blank line.
+		if (lang === 'python') {
+			const statement = trimmed.match(/^(?:>>>|\.\.\.)(?: |$)(.*)$/);
+			if (statement) {
+				if (!doctest) doctest = [];
+				doctest.push({line: docLine.line, text: statement[1]!});
+				doctestEnd = docLine.line;
+			} else if (doctest && trimmed) {
+				doctestEnd = docLine.line;
+			} else if (doctest) {
+				flush(doctest, doctestEnd);
+				doctest = null;
+			}
+			continue;
+		}
+
+		// JSDoc `@example` runs until the next tag
+		if (JSDOC_LANGUAGES.has(lang)) {
+			if (/^@example\b/.test(trimmed)) {
+				flush(example);
+				example = [];
+				const rest = trimmed
+					.replace(/^@example\b\s*/, '')
+					.replace(/<caption>.*?<\/caption>\s*/, '');
+				if (rest) example.push({line: docLine.line, text: rest});
+				continue;
+			}
+			if (example && trimmed.startsWith('@')) {
+				flush(example);
+				example = null;
+				continue;
+			}
+			if (example) example.push(docLine);
+		}
+	}
+
+	if (doctest) flush(doctest, doctestEnd);
+	flush(example);
+	return examples;
+}
+
+function isDocTestFence(info: string, lang: SupportedLanguage): boolean {
+	const tokens = info
+		.split(/[\s,]+/)
+		.map(t => t.trim().toLowerCase())
+		.filter(Boolean);
+	if (lang === 'rust') {
+		return tokens.every(t => RUST_DOCTEST_ATTRIBUTES.has(t));
+	}
+	if (tokens.length === 0) return true;
+	return (FENCE_LANGUAGES[lang] ?? []).includes(tokens[0]!);
+}
@@ -5,7 +5,19 @@
  * coverage without importing `web-tree-sitter` or initializing WASM grammars.
  */
 
-import type {SupportedLanguage} from './types.js';
+import fs from 'node:fs';
+import {createRequire} from 'node:module';
+import path from 'node:path';
+import {fileURLToPath} from 'node:url';
+import type {ViberagConfig} from '../config.js';
+import {computeFileHash, computeStringHash} from '../merkle/hash.js';
+import {
+	loadLanguagePlugins,
+	type LanguagePluginLoadResult,
+} from './plugins.js';
+import {EXTENSION_TO_LANGUAGE, type SupportedLanguage} from './types.js';
+
+const require = createRequire(import.meta.url);
 
 export const LANGUAGE_DISPLAY_NAMES: Record<SupportedLanguage, string> = {
 	javascript: 'JavaScript',
@@ -20,11 +32,18 @@ export const LANGUAGE_DISPLAY_NAMES: Record<SupportedLanguage, string> = {
 	swift: 'Swift',
 	dart: 'Dart',
 	php: 'PHP',
+	c: 'C',
+	cpp: 'C++',
+	ruby: 'Ruby',
+	scala: 'Scala',
+	elixir: 'Elixir',
+	lua: 'Lua',
 };
 
 /**
- * Mapping from our language names to tree-sitter-wasms filenames.
- * WASM files are in node_modules/tree-sitter-wasms/out/
+ * Mapping from our language names to grammar WASM filenames.
+ * WASM files are in node_modules/tree-sitter-wasms/out/, except for
+ * BUNDLED_GRAMMARS.
  */
 export const LANGUAGE_WASM_FILES: Record<SupportedLanguage, string | null> = {
 	javascript: 'tree-sitter-javascript.wasm',
@@ -37,43 +56,235 @@ export const LANGUAGE_WASM_FILES: Record<SupportedLanguage, string | null> = {
 	csharp: 'tree-sitter-c_sharp.wasm',
 	kotlin: 'tree-sitter-kotlin.wasm',
 	swift: 'tree-sitter-swift.wasm',
-	dart: null, // Disabled: wasm version mismatch (see DISABLED_LANGUAGE_REASONS.dart)
+	dart: 'tree-sitter-dart.wasm',
 	php: 'tree-sitter-php.wasm',
+	c: 'tree-sitter-c.wasm',
+	cpp: 'tree-sitter-cpp.wasm',
+	ruby: 'tree-sitter-ruby.wasm',
+	scala: 'tree-sitter-scala.wasm',
+	elixir: 'tree-sitter-elixir.wasm',
+	lua: 'tree-sitter-lua.wasm',
 };
 
-const DISABLED_LANGUAGE_REASONS: Partial<Record<SupportedLanguage, string>> = {
-	// tree-sitter-wasms Dart WASM is version 15, but web-tree-sitter 0.24.7 supports 13-14.
-	dart: 'Disabled: tree-sitter-wasms Dart WASM v15 is incompatible with web-tree-sitter 0.24.7 (supports 13–14).',
+/**
+ * Grammars loaded from viberag's own `grammars/` directory when built
+ * locally (`npm run build:grammars`, not published) because the
+ * tree-sitter-wasms build targets a newer ABI than web-tree-sitter 0.24
+ * loads (13–14).
+ */
+const BUNDLED_GRAMMARS = new Set<SupportedLanguage>(['dart']);
+
+/**
+ * `grammars/` at the package root (same depth from `source/` and `dist/`).
+ */
+const BUNDLED_GRAMMARS_DIR = fileURLToPath(
+	new URL('../../../../grammars/', import.meta.url),
+);
+
+const MISSING_GRAMMAR_REASONS: Partial<Record<SupportedLanguage, string>> = {
+	dart: 'Bundled Dart grammar not found: run `npm run build:grammars`, or set `grammars.dart` in config to a tree-sitter-dart.wasm built for ABI 13–14.',
+};
+
+/**
+ * Per-language grammar WASM paths from project config (`grammars`),
+ * overriding the built-in grammar.
+ */
+export type GrammarPaths = Partial<Record<SupportedLanguage, string>>;
+
+/**
+ * Where a language's grammar is loaded from.
+ */
+export type GrammarSource = 'tree-sitter-wasms' | 'bundled' | 'config';
+
+/**
+ * Validate the `grammars` config entry: known languages only, paths
+ * resolved against the project root.
+ */
+export function resolveGrammarPaths(
+	projectRoot: string,
+	grammars: Record<string, string> | undefined,
+): GrammarPaths {
+	const resolved: GrammarPaths = {};
+	for (const [language, wasmPath] of Object.entries(grammars ?? {})) {
+		if (!(language in LANGUAGE_WASM_FILES)) continue;
+		if (typeof wasmPath !== 'string' || !wasmPath.trim()) continue;
+		resolved[language as SupportedLanguage] = path.resolve(
+			projectRoot,
+			wasmPath,
+		);
+	}
+	return resolved;
+}
+
+/**
+ * Absolute path of a language's grammar WASM and where it comes from:
+ * config override, viberag's bundled grammars, or tree-sitter-wasms.
+ */
+export function resolveGrammarPath(
+	language: SupportedLanguage,
+	grammarPaths: GrammarPaths = {},
+): {path: string; source: GrammarSource} | null {
+	const override = grammarPaths[language];
+	if (override) return {path: override, source: 'config'};
+
+	const wasmFile = LANGUAGE_WASM_FILES[language];
+	if (!wasmFile) return null;
+	if (BUNDLED_GRAMMARS.has(language)) {
+		return {
+			path: path.join(BUNDLED_GRAMMARS_DIR, wasmFile),
+			source: 'bundled',
+		};
+	}
+	const wasmPackagePath = require.resolve('tree-sitter-wasms/package.json');
+	return {
+		path: path.join(path.dirname(wasmPackagePath), 'out', wasmFile),
+		source: 'tree-sitter-wasms',
+	};
+}
+
+/**
+ * Grammar configuration for a project: `grammars` overrides and language
+ * plugins from config.
+ */
+export type GrammarOptions = {
+	grammarPaths: GrammarPaths;
+	plugins: LanguagePluginLoadResult;
 };
 
+/**
+ * Resolve grammar overrides and load language plugins for a project config.
+ */
+export async function loadGrammarOptions(
+	projectRoot: string,
+	config: Pick<ViberagConfig, 'grammars' | 'languagePlugins'>,
+): Promise<GrammarOptions> {
+	return {
+		grammarPaths: resolveGrammarPaths(projectRoot, config.grammars),
+		plugins: await loadLanguagePlugins(projectRoot, config.languagePlugins),
+	};
+}
+
+/**
+ * Per-extension fingerprints of the grammar overrides and language plugins
+ * in effect (manifest, path and WASM content). Built-in grammars are
+ * covered by the index schema version.
+ */
+export async function computeGrammarFingerprints(
+	options: GrammarOptions,
+): Promise<Record<string, string>> {
+	const fingerprints: Record<string, string> = {};
+	for (const [language, wasmPath] of Object.entries(options.grammarPaths)) {
+		const digest = computeStringHash(
+			`${language}|${wasmPath}|${await hashWasm(wasmPath)}`,
+		);
+		for (const [ext, extLanguage] of Object.entries(EXTENSION_TO_LANGUAGE)) {
+			if (extLanguage === language) fingerprints[ext] = digest;
+		}
+	}
+	for (const plugin of options.plugins.plugins) {
+		const digest = computeStringHash(
+			`${JSON.stringify(plugin)}|${await hashWasm(plugin.grammarPath)}`,
+		);
+		for (const ext of plugin.extensions) fingerprints[ext] = digest;
+	}
+	return fingerprints;
+}
+
+/**
+ * Extensions whose grammar or plugin fingerprint differs between two
+ * indexing runs (added, removed or changed).
+ */
+export function changedGrammarExtensions(
+	a: Record<string, string>,
+	b: Record<string, string>,
+): string[] {
+	const extensions = new Set([...Object.keys(a), ...Object.keys(b)]);
+	return [...extensions].filter(ext => a[ext] !== b[ext]).sort();
+}
+
+async function hashWasm(wasmPath: string): Promise<string> {
+	try {
+		return await computeFileHash(wasmPath);
+	} catch {
+		return 'missing';
+	}
+}
+
 export type GrammarSupport = {
-	language: SupportedLanguage;
+	/** Built-in language or language plugin name */
+	language: string;
 	display_name: string;
 	wasm_file: string | null;
+	source: GrammarSource | 'plugin' | null;
 	enabled: boolean;
 	reason: string | null;
 };
 
-export function getGrammarSupport(): GrammarSupport[] {
+export function getGrammarSupport(
+	options: Partial<GrammarOptions> = {},
+): GrammarSupport[] {
+	const grammarPaths = options.grammarPaths ?? {};
 	const languages = Object.keys(LANGUAGE_WASM_FILES) as SupportedLanguage[];
-	return languages.sort().map(language => {
-		const wasm_file = LANGUAGE_WASM_FILES[language];
-		const enabled = Boolean(wasm_file);
+	const builtin = languages.sort().map((language): GrammarSupport => {
+		const resolved = resolveGrammarPath(language, grammarPaths);
+		// tree-sitter-wasms is a dependency; bundled and configured grammars
+		// may be missing on disk.
+		const enabled =
+			resolved !== null &&
+			(resolved.source === 'tree-sitter-wasms' || fs.existsSync(resolved.path));
+		const missingReason =
+			resolved?.source === 'config'
+				? `Configured grammar not found: ${resolved.path}`
+				: (MISSING_GRAMMAR_REASONS[language] ?? null);
 		return {
 			language,
 			display_name: LANGUAGE_DISPLAY_NAMES[language],
-			wasm_file,
+			wasm_file: resolved ? path.basename(resolved.path) : null,
+			source: resolved?.source ?? null,
 			enabled,
-			reason: enabled ? null : (DISABLED_LANGUAGE_REASONS[language] ?? null),
+			reason: enabled ? null : missingReason,
 		};
 	});
+
+	const plugins = options.plugins?.plugins ?? [];
+	const pluginSupport = plugins.map((plugin): GrammarSupport => {
+		const enabled = fs.existsSync(plugin.grammarPath);
+		return {
+			language: plugin.name,
+			display_name: plugin.displayName,
+			wasm_file: path.basename(plugin.grammarPath),
+			source: 'plugin',
+			enabled,
+			reason: enabled
+				? null
+				: `Plugin grammar not found: ${plugin.grammarPath}`,
+		};
+	});
+	// Manifests that failed validation are listed under their file name
+	const pluginErrors = (options.plugins?.errors ?? []).map(
+		(e): GrammarSupport => {
+			const name = path.basename(e.manifestPath, '.json');
+			return {
+				language: name,
+				display_name: name,
+				wasm_file: null,
+				source: 'plugin',
+				enabled: false,
+				reason: `Invalid language plugin ${e.manifestPath}: ${e.error}`,
+			};
+		},
+	);
+
+	return [...builtin, ...pluginSupport, ...pluginErrors];
 }
 
-export function getGrammarSupportSummary(): {
+export function getGrammarSupportSummary(
+	options: Partial<GrammarOptions> = {},
+): {
 	enabled: GrammarSupport[];
 	disabled: Array<Pick<GrammarSupport, 'language' | 'display_name' | 'reason'>>;
 } {
-	const all = getGrammarSupport();
+	const all = getGrammarSupport(options);
 	return {
 		enabled: all.filter(g => g.enabled),
 		disabled: all
@@ -5,8 +5,8 @@
  * (functions, classes, methods) for embedding.
  */
 
+import fs from 'node:fs';
 import path from 'node:path';
-import {createRequire} from 'node:module';
 import Parser from 'web-tree-sitter';
 import {computeStringHash} from '../merkle/hash.js';
 import {
@@ -15,13 +15,49 @@ import {
 	type Chunk,
 	type ChunkType,
 	type ExtractedRef,
+	isTestFilePath,
+	type RefAccess,
 	type RefExtractionOptions,
+	type RefKind,
 	type SupportedLanguage,
+	type Visibility,
 } from './types.js';
-import {LANGUAGE_WASM_FILES} from './grammars.js';
-
-// Use createRequire to resolve WASM file paths from tree-sitter-wasms
-const require = createRequire(import.meta.url);
+import {
+	type GrammarPaths,
+	LANGUAGE_WASM_FILES,
+	resolveGrammarPath,
+} from './grammars.js';
+import {keyPathFromLiteral, outlineConfigFile} from './config.js';
+import {
+	collectDocLines,
+	extractDocExamples,
+	extractDocLinks,
+	isDocComment,
+} from './docs.js';
+import {
+	notebookCodeView,
+	notebookSourceView,
+	parseNotebook,
+	type NotebookCell,
+} from './notebook.js';
+import {outlineInfraFile} from './infra.js';
+import {
+	type FileOutline,
+	LineIndex,
+	type OutlineDefinition,
+} from './outline.js';
+import type {LanguagePlugin} from './plugins.js';
+import {outlineSchemaFile} from './schemas.js';
+import {outlineScriptFile} from './scripts.js';
+import {
+	componentNameFromPath,
+	findComponentTags,
+	lineAt,
+	maskToScripts,
+	SFC_EXTENSIONS,
+	sfcScriptLanguage,
+	splitSfc,
+} from './sfc.js';
 
 type TokenFacts = {
 	identifiers: string[];
@@ -30,6 +66,20 @@ type TokenFacts = {
 	stringLiterals: string[];
 };
 
+/**
+ * A language plugin with its grammar loaded.
+ */
+type PluginLanguage = {
+	plugin: LanguagePlugin;
+	language: Parser.Language;
+};
+
+type DocTest = {
+	chunk: Chunk;
+	/** File line of each line of `chunk.text` */
+	lineNumbers: number[];
+};
+
 /**
  * Node types that represent functions in each language.
  */
@@ -56,6 +106,17 @@ const FUNCTION_NODE_TYPES: Record<SupportedLanguage, string[]> = {
 	kotlin: ['function_declaration'],
 	// PHP
 	php: ['function_definition', 'method_declaration'],
+	// C/C++ (definitions and prototypes)
+	c: ['function_definition', 'declaration'],
+	cpp: ['function_definition', 'declaration'],
+	// Ruby
+	ruby: ['method', 'singleton_method'],
+	// Scala (incl. abstract `def`s)
+	scala: ['function_definition', 'function_declaration'],
+	// Elixir (`def`/`defp`/`defmacro` calls)
+	elixir: ['call'],
+	// Lua (incl. `local function`)
+	lua: ['function_declaration', 'local_function'],
 };
 
 /**
@@ -64,8 +125,12 @@ const FUNCTION_NODE_TYPES: Record<SupportedLanguage, string[]> = {
 const CLASS_NODE_TYPES: Record<SupportedLanguage, string[]> = {
 	// JavaScript/TypeScript
 	javascript: ['class_declaration'],
-	typescript: ['class_declaration'],
-	tsx: ['class_declaration'],
+	typescript: [
+		'class_declaration',
+		'interface_declaration',
+		'enum_declaration',
+	],
+	tsx: ['class_declaration', 'interface_declaration', 'enum_declaration'],
 	// Python
 	python: ['class_definition'],
 	// Go (structs via type declarations)
@@ -88,37 +153,635 @@ const CLASS_NODE_TYPES: Record<SupportedLanguage, string[]> = {
 	// Kotlin
 	kotlin: ['class_declaration', 'object_declaration', 'interface_declaration'],
 	// PHP
-	php: ['class_declaration', 'interface_declaration', 'trait_declaration'],
+	php: [
+		'class_declaration',
+		'interface_declaration',
+		'trait_declaration',
+		'enum_declaration',
+	],
+	// C/C++ (only specifiers with a body define the type)
+	c: ['struct_specifier', 'union_specifier', 'enum_specifier'],
+	cpp: [
+		'class_specifier',
+		'struct_specifier',
+		'union_specifier',
+		'enum_specifier',
+	],
+	// Ruby
+	ruby: ['class', 'module'],
+	// Scala
+	scala: [
+		'class_definition',
+		'object_definition',
+		'trait_definition',
+		'enum_definition',
+	],
+	// Elixir (`defmodule`/`defprotocol`/`defimpl` calls)
+	elixir: ['call'],
+	// Lua (no classes; tables own `function T:m()` methods)
+	lua: [],
 };
 
 /**
  * Node types that represent methods in each language.
  */
 const METHOD_NODE_TYPES: Record<SupportedLanguage, string[]> = {
-	// JavaScript/TypeScript
+	// JavaScript/TypeScript (incl. interface method signatures)
 	javascript: ['method_definition'],
-	typescript: ['method_definition'],
-	tsx: ['method_definition'],
+	typescript: ['method_definition', 'method_signature'],
+	tsx: ['method_definition', 'method_signature'],
 	// Python (function_definition inside class)
 	python: ['function_definition'],
-	// Go
-	go: ['method_declaration'],
-	// Rust (function_item inside impl)
-	rust: ['function_item'],
+	// Go (receiver methods + interface method elements)
+	go: ['method_declaration', 'method_elem', 'method_spec'],
+	// Rust (function_item inside impl, bodiless trait methods)
+	rust: ['function_item', 'function_signature_item'],
 	// Java
 	java: ['method_declaration'],
 	// C#
 	csharp: ['method_declaration'],
 	// Dart
 	dart: ['method_signature'],
-	// Swift
-	swift: ['function_declaration'],
+	// Swift (incl. protocol requirements)
+	swift: ['function_declaration', 'protocol_function_declaration'],
 	// Kotlin
 	kotlin: ['function_declaration'],
 	// PHP
 	php: ['method_declaration'],
+	// C
+	c: [],
+	// C++ (inline definitions, in-class prototypes, out-of-line `Foo::bar`)
+	cpp: ['function_definition', 'declaration', 'field_declaration'],
+	// Ruby (instance and `def self.` methods)
+	ruby: ['method', 'singleton_method'],
+	// Scala
+	scala: ['function_definition', 'function_declaration'],
+	// Elixir (functions inside a module)
+	elixir: ['call'],
+	// Lua (`function Account:deposit()`)
+	lua: ['function_declaration'],
+};
+
+/**
+ * Node types that represent macro definitions (Rust `macro_rules!`).
+ */
+const MACRO_NODE_TYPES: Partial<Record<SupportedLanguage, string[]>> = {
+	rust: ['macro_definition'],
+	c: ['preproc_def', 'preproc_function_def'],
+	cpp: ['preproc_def', 'preproc_function_def'],
+};
+
+/**
+ * Node types for named declarations that are not functions or classes
+ * (constants, variables, type aliases, enum variants, fields). Inside a
+ * class-like node they become members of it. The mapped kind is refined by
+ * `declarationChunkType` (`let` vs `const`, `static final`, enum members).
+ */
+const DECLARATION_NODE_TYPES: Partial<
+	Record<SupportedLanguage, Record<string, ChunkType>>
+> = {
+	// Exported `const`/`let`/`var` bindings that are not functions or classes
+	javascript: {
+		lexical_declaration: 'constant',
+		variable_declaration: 'variable',
+		field_definition: 'field',
+	},
+	typescript: {
+		lexical_declaration: 'constant',
+		variable_declaration: 'variable',
+		type_alias_declaration: 'type_alias',
+		public_field_definition: 'field',
+		property_signature: 'field',
+		// Enum members (`Admin`, `Admin = 'admin'`)
+		property_identifier: 'variant',
+		enum_assignment: 'variant',
+	},
+	tsx: {
+		lexical_declaration: 'constant',
+		variable_declaration: 'variable',
+		type_alias_declaration: 'type_alias',
+		public_field_definition: 'field',
+		property_signature: 'field',
+		// Enum members (`Admin`, `Admin = 'admin'`)
+		property_identifier: 'variant',
+		enum_assignment: 'variant',
+	},
+	// Module-level ALL_CAPS assignments, class attributes
+	python: {
+		assignment: 'constant',
+		type_alias_statement: 'type_alias',
+	},
+	go: {
+		const_spec: 'constant',
+		var_spec: 'variable',
+		field_declaration: 'field',
+	},
+	rust: {
+		const_item: 'constant',
+		static_item: 'constant',
+		type_item: 'type_alias',
+		enum_variant: 'variant',
+		field_declaration: 'field',
+	},
+	java: {
+		field_declaration: 'field',
+		constant_declaration: 'constant',
+		enum_constant: 'variant',
+	},
+	csharp: {
+		field_declaration: 'field',
+		property_declaration: 'field',
+		enum_member_declaration: 'variant',
+	},
+	swift: {
+		property_declaration: 'constant',
+		enum_entry: 'variant',
+	},
+	kotlin: {
+		property_declaration: 'constant',
+		enum_entry: 'variant',
+	},
+	php: {
+		const_declaration: 'constant',
+		property_declaration: 'field',
+		enum_case: 'variant',
+	},
+	c: {
+		type_definition: 'type_alias',
+		enumerator: 'variant',
+		field_declaration: 'field',
+	},
+	cpp: {
+		type_definition: 'type_alias',
+		alias_declaration: 'type_alias',
+		enumerator: 'variant',
+		field_declaration: 'field',
+	},
+	// Constant assignments (`MAX_RETRIES = 3`)
+	ruby: {
+		assignment: 'constant',
+	},
+	scala: {
+		val_definition: 'constant',
+		var_definition: 'variable',
+		type_definition: 'type_alias',
+		simple_enum_case: 'variant',
+		full_enum_case: 'variant',
+	},
+};
+
+/**
+ * Class-like node types that declare something other than a class. Rust
+ * impl blocks and node types shared by several kinds (Kotlin/Swift
+ * `class_declaration`, Go `type_declaration`) are resolved by
+ * `typeDefinitionKind`.
+ */
+const TYPE_DEFINITION_KINDS: Record<string, ChunkType> = {
+	interface_declaration: 'interface',
+	protocol_declaration: 'interface',
+	enum_declaration: 'enum',
+	enum_item: 'enum',
+	enum_specifier: 'enum',
+	enum_definition: 'enum',
+	struct_item: 'struct',
+	struct_declaration: 'struct',
+	struct_specifier: 'struct',
+	union_specifier: 'struct',
+	trait_item: 'trait',
+	trait_declaration: 'trait',
+	trait_definition: 'trait',
+};
+
+/**
+ * Python base classes that make a class an enum or an interface.
+ */
+const PYTHON_ENUM_BASES = new Set([
+	'Enum',
+	'IntEnum',
+	'StrEnum',
+	'Flag',
+	'IntFlag',
+]);
+const PYTHON_INTERFACE_BASES = new Set(['Protocol']);
+
+/**
+ * JS/TS declarator values that are chunked as functions or classes instead.
+ */
+const JS_CALLABLE_VALUE_TYPES = new Set([
+	'arrow_function',
+	'function',
+	'function_expression',
+	'generator_function',
+	'class',
+]);
+
+/**
+ * Nodes between a declaration and the declarator that names it
+ * (`int count = 0;`, `val limit = 3`, `public $name;`).
+ */
+const DECLARATOR_NODE_TYPES = new Set([
+	'variable_declarator',
+	'variable_declaration',
+	'const_element',
+	'property_element',
+	'variable_name',
+]);
+
+/**
+ * Leaf nodes that name a declaration.
+ */
+const DECLARATION_NAME_NODE_TYPES = new Set([
+	'identifier',
+	'name',
+	'simple_identifier',
+	'property_identifier',
+	'type_identifier',
+	'field_identifier',
+	'constant',
+]);
+
+/**
+ * C/C++ declaration nodes that declare a function (prototype) only when
+ * their declarator is a function declarator.
+ */
+const C_PROTOTYPE_NODE_TYPES = new Set(['declaration', 'field_declaration']);
+
+/**
+ * Leaf nodes that name a C/C++ declarator.
+ */
+const C_DECLARATOR_NAME_NODE_TYPES = new Set([
+	'identifier',
+	'field_identifier',
+	'type_identifier',
+	'destructor_name',
+	'operator_name',
+]);
+
+/**
+ * Child node types of a class-like node that declare its supertypes
+ * (`extends`/`implements` clauses, base lists, protocol conformances).
+ * Rust supertraits and derives are read separately.
+ */
+const SUPERTYPE_CLAUSE_NODE_TYPES: Partial<
+	Record<SupportedLanguage, string[]>
+> = {
+	javascript: ['class_heritage'],
+	typescript: ['class_heritage', 'extends_type_clause'],
+	tsx: ['class_heritage', 'extends_type_clause'],
+	python: ['argument_list'],
+	java: ['superclass', 'super_interfaces', 'extends_interfaces'],
+	csharp: ['base_list'],
+	dart: ['superclass', 'interfaces', 'mixins'],
+	swift: ['inheritance_specifier'],
+	kotlin: ['delegation_specifiers', 'delegation_specifier'],
+	php: ['base_clause', 'class_interface_clause'],
+	cpp: ['base_class_clause'],
+	ruby: ['superclass'],
+	scala: ['extends_clause'],
+};
+
+/**
+ * Nodes inside a supertype clause that list several supertypes.
+ */
+const SUPERTYPE_LIST_NODE_TYPES = new Set([
+	'extends_clause',
+	'implements_clause',
+	'type_list',
+	'interface_type_list',
+	'delegation_specifier',
+	'inheritance_specifier',
+]);
+
+/**
+ * Nodes skipped when reading a supertype's base name (generic arguments,
+ * constructor arguments, Python `metaclass=` keywords).
+ */
+const SUPERTYPE_SKIP_NODE_TYPES = new Set([
+	'type_arguments',
+	'type_argument_list',
+	'template_argument_list',
+	'type_parameters',
+	'arguments',
+	'argument_list',
+	'value_arguments',
+	'keyword_argument',
+	'comment',
+]);
+
+/**
+ * Identifier node types that name a supertype.
+ */
+const SUPERTYPE_NAME_NODE_TYPES = new Set([
+	'identifier',
+	'constant',
+	'type_identifier',
+	'property_identifier',
+	'simple_identifier',
+	'name',
+]);
+
+/**
+ * Supertype clauses that list implemented interfaces rather than extended
+ * base classes.
+ */
+const IMPLEMENTS_CLAUSE_NODE_TYPES = new Set([
+	'implements_clause',
+	'super_interfaces',
+	'class_interface_clause',
+	'interfaces',
+]);
+
+/**
+ * Class-like nodes whose supertypes are always extended (interfaces,
+ * protocols and traits extend other interfaces).
+ */
+const INTERFACE_NODE_TYPES = new Set([
+	'interface_declaration',
+	'protocol_declaration',
+	'trait_declaration',
+	'trait_definition',
+	'trait_item',
+]);
+
+/**
+ * Nodes whose names sit in a type position: annotations, generic
+ * arguments, Kotlin/Swift user types, PHP named types, Python `type`s.
+ */
+const TYPE_CONTEXT_NODE_TYPES = new Set([
+	'type',
+	'type_annotation',
+	'type_arguments',
+	'type_argument_list',
+	'generic_type',
+	'generic_name',
+	'user_type',
+	'named_type',
+	'nullable_type',
+	'optional_type',
+	'array_type',
+	'union_type',
+	'intersection_type',
+	'qualified_type',
+	'nested_type_identifier',
+]);
+
+/**
+ * Fields that hold a type (parameter/variable types, return types).
+ */
+const TYPE_FIELD_NAMES = ['type', 'return_type', 'returns'];
+
+/**
+ * How many ancestors to inspect for a type or inheritance context.
+ */
+const MAX_REF_CONTEXT_DEPTH = 8;
+
+/**
+ * Languages that construct objects by calling the class (`Foo(...)`), so a
+ * call of a PascalCase name is an instantiation.
+ */
+const CALL_CONSTRUCTOR_LANGUAGES = new Set<SupportedLanguage>([
+	'python',
+	'kotlin',
+	'swift',
+	'scala',
+]);
+
+/**
+ * Class-style names (`Session`, `HttpClient`; not `MAX` or `run`).
+ */
+const PASCAL_CASE_RE = /^[A-Z]\w*[a-z]\w*$/;
+
+/**
+ * Go composite literal types that name a type (not slices/maps of one).
+ */
+const GO_NAMED_TYPE_NODE_TYPES = new Set([
+	'type_identifier',
+	'qualified_type',
+	'generic_type',
+]);
+
+/**
+ * Assignments and initialised declarations; their target (`left`,
+ * `pattern`, `name`, `property` or `target` field) is written.
+ */
+const ASSIGNMENT_NODE_TYPES = new Set([
+	'assignment',
+	'assignment_expression',
+	'assignment_statement',
+	'augmented_assignment',
+	'augmented_assignment_expression',
+	'compound_assignment_expr',
+	'operator_assignment',
+	'named_expression',
+	'short_var_declaration',
+	'variable_declarator',
+	'var_spec',
+	'let_declaration',
+	'public_field_definition',
+	'field_definition',
+]);
+
+const ASSIGNMENT_TARGET_FIELDS = [
+	'left',
+	'pattern',
+	'name',
+	'property',
+	'target',
+];
+
+/**
+ * Class field declarations (TS/JS class fields, Java fields); an
+ * initialiser (`hits = 0`) writes the field they define.
+ */
+const FIELD_DECLARATION_NODE_TYPES = new Set([
+	'public_field_definition',
+	'field_definition',
+	'field_declaration',
+]);
+
+const COMPARISON_OPERATORS = new Set(['==', '!=', '<=', '>=', '===', '!==']);
+
+/**
+ * Wrappers between an assignment and the names it assigns (destructuring
+ * patterns, `a, b = ...` lists, parentheses).
+ */
+const ASSIGNABLE_WRAPPER_NODE_TYPES = new Set([
+	'pattern_list',
+	'tuple_pattern',
+	'list_pattern',
+	'array_pattern',
+	'expression_list',
+	'variable_list',
+	'left_assignment_list',
+	'tuple',
+	'parenthesized_expression',
+	'navigation_suffix',
+	'literal_element',
+]);
+
+/**
+ * Element accesses; writing an element writes its container.
+ */
+const SUBSCRIPT_NODE_TYPES = new Set([
+	'subscript',
+	'subscript_expression',
+	'index_expression',
+	'element_access_expression',
+	'array_access',
+	'element_reference',
+]);
+
+/**
+ * `++`/`--` expressions (the operator is checked separately for the
+ * generic unary node types).
+ */
+const UPDATE_NODE_TYPES = new Set([
+	'update_expression',
+	'inc_statement',
+	'dec_statement',
+	'postfix_unary_expression',
+	'prefix_unary_expression',
+	'postfix_expression',
+	'prefix_expression',
+]);
+
+/**
+ * Node types that hold access modifiers (Java `modifiers`, C# `modifier`,
+ * Kotlin/Swift/PHP/Rust `visibility_modifier`, TS `accessibility_modifier`).
+ */
+const MODIFIER_NODE_TYPES = new Set([
+	'modifiers',
+	'modifier',
+	'visibility_modifier',
+	'accessibility_modifier',
+]);
+
+/**
+ * Ordering used to narrow visibility (most to least visible).
+ */
+const VISIBILITY_RANK: Record<Visibility, number> = {
+	public: 4,
+	crate: 3,
+	restricted: 2,
+	protected: 1,
+	private: 0,
 };
 
+/**
+ * Rust path segments that name a module relative to the current one rather
+ * than an item.
+ */
+const RUST_PATH_KEYWORDS = new Set(['self', 'super', 'crate']);
+
+/**
+ * Rust items that can carry `#[derive(...)]`.
+ */
+const RUST_DERIVE_NODE_TYPES = new Set(['struct_item', 'enum_item']);
+
+/**
+ * JS/TS test framework block functions (jest, vitest, mocha, node:test).
+ */
+const JS_TEST_BLOCKS = new Set([
+	'describe',
+	'suite',
+	'it',
+	'test',
+	'beforeAll',
+	'beforeEach',
+	'afterAll',
+	'afterEach',
+]);
+
+/**
+ * JUnit / TestNG / kotlin.test / xUnit / NUnit / MSTest test annotations.
+ */
+const TEST_ANNOTATIONS = new Set([
+	'Test',
+	'ParameterizedTest',
+	'RepeatedTest',
+	'TestFactory',
+	'TestTemplate',
+	'BeforeEach',
+	'AfterEach',
+	'BeforeAll',
+	'AfterAll',
+	'Fact',
+	'Theory',
+	'TestCase',
+	'TestMethod',
+	'TestFixture',
+	'TestClass',
+]);
+
+/**
+ * GoogleTest / Catch2 / doctest macros that define a test body.
+ */
+const C_TEST_MACROS = new Set([
+	'TEST',
+	'TEST_F',
+	'TEST_P',
+	'TYPED_TEST',
+	'TYPED_TEST_P',
+	'TEST_CASE',
+	'TEST_CASE_METHOD',
+	'SCENARIO',
+]);
+
+/**
+ * RSpec / minitest-spec / busted blocks that hold test code.
+ */
+const SPEC_TEST_BLOCKS = new Set([
+	'describe',
+	'context',
+	'it',
+	'specify',
+	'before',
+	'after',
+	'before_each',
+	'after_each',
+	'setup',
+	'teardown',
+]);
+
+/**
+ * Ruby calls that load another file.
+ */
+const RUBY_IMPORT_METHODS = new Set(['require', 'require_relative', 'load']);
+
+/**
+ * Ruby visibility keywords, used bare (`private`), as a wrapper
+ * (`private def foo`) or with names (`private :foo`).
+ */
+const RUBY_VISIBILITY_KEYWORDS = new Map<string, Visibility>([
+	['public', 'public'],
+	['protected', 'protected'],
+	['private', 'private'],
+]);
+
+/**
+ * Elixir macro calls that define a module-like container.
+ */
+const ELIXIR_MODULE_CALLS = new Set(['defmodule', 'defprotocol', 'defimpl']);
+
+/**
+ * Elixir macro calls that define a function, and the private variants.
+ */
+const ELIXIR_FUNCTION_CALLS = new Set([
+	'def',
+	'defp',
+	'defmacro',
+	'defmacrop',
+	'defguard',
+	'defguardp',
+	'defdelegate',
+]);
+const ELIXIR_PRIVATE_CALLS = new Set(['defp', 'defmacrop', 'defguardp']);
+
+/**
+ * Elixir directives that reference another module.
+ */
+const ELIXIR_IMPORT_CALLS = new Set(['alias', 'import', 'use', 'require']);
+
 /**
  * Node types that indicate export in JS/TS.
  */
@@ -160,6 +823,16 @@ const MARKDOWN_TARGET_LINES = 60;
  */
 const MARKDOWN_EXTENSIONS = new Set(['.md', '.mdx', '.markdown']);
 
+/**
+ * Refs kept from doc-test examples (code usages, not imports or literals).
+ */
+const DOC_TEST_REF_KINDS = new Set<RefKind>([
+	'call',
+	'instantiation',
+	'identifier',
+	'type_ref',
+]);
+
 /**
  * Chunker that uses web-tree-sitter (WASM) to extract semantic code chunks.
  * Provides 100% platform compatibility - no native compilation required.
@@ -168,10 +841,23 @@ export class Chunker {
 	private parser: Parser | null = null;
 	private languages: Map<SupportedLanguage, Parser.Language> = new Map();
 	private initialized = false;
-	private wasmBasePath: string | null = null;
+	private readonly grammarPaths: GrammarPaths;
+	private readonly plugins: LanguagePlugin[];
+	/** Loaded language plugins by (lowercase) extension */
+	private pluginLanguages: Map<string, PluginLanguage> = new Map();
 
-	constructor() {
+	/**
+	 * @param options.grammarPaths - Per-language grammar WASM overrides from
+	 *   config (see resolveGrammarPaths)
+	 * @param options.plugins - Language plugins from config (see
+	 *   loadLanguagePlugins)
+	 */
+	constructor(
+		options: {grammarPaths?: GrammarPaths; plugins?: LanguagePlugin[]} = {},
+	) {
 		// Parser instance created in initialize()
+		this.grammarPaths = options.grammarPaths ?? {};
+		this.plugins = options.plugins ?? [];
 	}
 
 	/**
@@ -188,27 +874,38 @@ export class Chunker {
 		this.parser = new Parser();
 
 		try {
-			// Resolve the path to tree-sitter-wasms/out/
-			const wasmPackagePath = require.resolve('tree-sitter-wasms/package.json');
-			this.wasmBasePath = path.join(path.dirname(wasmPackagePath), 'out');
-
-			// Load all language grammars sequentially (skip null entries like Dart)
+			// Load all language grammars sequentially
 			// IMPORTANT: Must be sequential - web-tree-sitter has global state that
 			// gets corrupted when loading multiple WASM modules in parallel.
-			for (const [lang, wasmFile] of Object.entries(LANGUAGE_WASM_FILES)) {
-				if (!wasmFile) {
-					// Language temporarily disabled (e.g., Dart due to version mismatch)
+			for (const lang of Object.keys(LANGUAGE_WASM_FILES)) {
+				const grammar = resolveGrammarPath(
+					lang as SupportedLanguage,
+					this.grammarPaths,
+				);
+				if (!grammar) continue;
+				// Bundled grammars are built separately (npm run build:grammars);
+				// a missing one is reported by get_status, not logged here.
+				if (grammar.source === 'bundled' && !fs.existsSync(grammar.path)) {
 					continue;
 				}
 				try {
-					const wasmPath = path.join(this.wasmBasePath!, wasmFile);
-					const language = await Parser.Language.load(wasmPath);
+					const language = await Parser.Language.load(grammar.path);
 					this.languages.set(lang as SupportedLanguage, language);
 				} catch (error) {
 					// Log but don't fail - we can still work with other languages
 					console.error(`Failed to load ${lang} grammar:`, error);
 				}
 			}
+			for (const plugin of this.plugins) {
+				try {
+					const language = await Parser.Language.load(plugin.grammarPath);
+					for (const ext of plugin.extensions) {
+						this.pluginLanguages.set(ext, {plugin, language});
+					}
+				} catch (error) {
+					console.error(`Failed to load ${plugin.name} plugin grammar:`, error);
+				}
+			}
 			this.initialized = true;
 		} catch (error) {
 			// Cleanup parser on failure to prevent resource leak
@@ -216,8 +913,8 @@ export class Chunker {
 				this.parser.delete();
 				this.parser = null;
 			}
-			this.wasmBasePath = null;
 			this.languages.clear();
+			this.pluginLanguages.clear();
 			throw error;
 		}
 	}
@@ -272,6 +969,44 @@ export class Chunker {
 			return this.chunkMarkdown(filepath, content, maxChunkSize);
 		}
 
+		const pluginLanguage = this.pluginLanguages.get(ext.toLowerCase());
+		if (pluginLanguage) {
+			return this.analyzePluginFile(filepath, content, pluginLanguage, {
+				chunkMaxSize: maxChunkSize,
+				refs: {identifier_mode: 'none'},
+			}).chunks;
+		}
+
+		if (ext.toLowerCase() === '.ipynb') {
+			const notebook = this.analyzeNotebookFile(filepath, content, {
+				chunkMaxSize: maxChunkSize,
+				definitionMaxChunkSize: maxChunkSize,
+				refs: {identifier_mode: 'none'},
+			});
+			if (notebook) return notebook.chunks;
+		}
+
+		if (SFC_EXTENSIONS.has(ext.toLowerCase())) {
+			const sfc = this.analyzeSfcFile(filepath, content, ext, {
+				chunkMaxSize: maxChunkSize,
+				definitionMaxChunkSize: maxChunkSize,
+				refs: {identifier_mode: 'none'},
+			});
+			if (sfc) return sfc.chunks;
+		}
+
+		const outline =
+			outlineSchemaFile(filepath, content) ??
+			outlineInfraFile(filepath, content) ??
+			outlineConfigFile(filepath, content) ??
+			outlineScriptFile(filepath, content);
+		if (outline) {
+			return this.analyzeOutline(filepath, content, outline, {
+				chunkMaxSize: maxChunkSize,
+				refs: {identifier_mode: 'none'},
+			}).chunks;
+		}
+
 		if (!lang || !this.languages.has(lang)) {
 			// Unsupported language - return module-level chunk (with size enforcement + overlap)
 			const moduleChunk = this.createModuleChunk(filepath, content);
@@ -380,6 +1115,44 @@ export class Chunker {
 			};
 		}
 
+		const pluginLanguage = this.pluginLanguages.get(ext.toLowerCase());
+		if (pluginLanguage) {
+			return this.analyzePluginFile(filepath, content, pluginLanguage, {
+				chunkMaxSize,
+				refs: refsOptions,
+			});
+		}
+
+		if (ext.toLowerCase() === '.ipynb') {
+			const notebook = this.analyzeNotebookFile(filepath, content, {
+				chunkMaxSize,
+				definitionMaxChunkSize,
+				refs: refsOptions,
+			});
+			if (notebook) return notebook;
+		}
+
+		if (SFC_EXTENSIONS.has(ext.toLowerCase())) {
+			const sfc = this.analyzeSfcFile(filepath, content, ext, {
+				chunkMaxSize,
+				definitionMaxChunkSize,
+				refs: refsOptions,
+			});
+			if (sfc) return sfc;
+		}
+
+		const outline =
+			outlineSchemaFile(filepath, content) ??
+			outlineInfraFile(filepath, content) ??
+			outlineConfigFile(filepath, content) ??
+			outlineScriptFile(filepath, content);
+		if (outline) {
+			return this.analyzeOutline(filepath, content, outline, {
+				chunkMaxSize,
+				refs: refsOptions,
+			});
+		}
+
 		if (!lang || !this.languages.has(lang)) {
 			const moduleChunk = this.createModuleChunk(filepath, content);
 			return {
@@ -438,6 +1211,16 @@ export class Chunker {
 
 		const refs = this.extractRefsFromTree(tree.rootNode, lang, refsOptions);
 
+		// Doc-test code becomes chunks owned by its definition, and its calls
+		// become refs (so usages include where a symbol is exemplified).
+		const docTests = this.extractDocTests(
+			definition_chunks,
+			content.split('\n'),
+			lang,
+		);
+		chunks.push(...docTests.map(d => d.chunk));
+		refs.push(...this.extractDocTestRefs(docTests, lang, refsOptions));
+
 		return {
 			language: lang,
 			parse_status: 'parsed',
@@ -482,12 +1265,23 @@ export class Chunker {
 		const nodeType = node.type;
 
 		// Check for class
-		if (CLASS_NODE_TYPES[lang].includes(nodeType)) {
-			const className = this.extractName(node, lang);
+		if (
+			CLASS_NODE_TYPES[lang].includes(nodeType) &&
+			this.isTypeDefinition(node, lang)
+		) {
+			// Rust impl blocks own their methods on behalf of the implementing
+			// type: `Greeter` for inherent impls, `<Greeter as Display>` for
+			// trait impls.
+			const implTarget = this.extractImplTarget(node, lang);
+			const className = implTarget
+				? implTarget.traitName
+					? `<${implTarget.typeName} as ${implTarget.traitName}>`
+					: implTarget.typeName
+				: this.extractName(node, lang);
 			const classChunks = this.nodeToChunks(
 				node,
 				lines,
-				'class',
+				this.typeDefinitionKind(node, lang),
 				lang,
 				filepath,
 				null,
@@ -514,11 +1308,62 @@ export class Chunker {
 			return;
 		}
 
+		// Check for macro definitions (C `#define`s without a value are
+		// include guards / feature flags)
+		if (
+			MACRO_NODE_TYPES[lang]?.includes(nodeType) &&
+			!(nodeType === 'preproc_def' && !node.childForFieldName('value'))
+		) {
+			const macroChunks = this.nodeToChunks(
+				node,
+				lines,
+				'macro',
+				lang,
+				filepath,
+				null,
+				maxChunkSize,
+			);
+			chunks.push(...macroChunks);
+
+			return;
+		}
+
+		// Check for constants, variables, type aliases, variants and fields
+		const declarationType = this.declarationChunkType(
+			node,
+			lang,
+			parentClassName !== null,
+		);
+		if (declarationType) {
+			const declarationChunks = this.nodeToChunks(
+				node,
+				lines,
+				declarationType,
+				lang,
+				filepath,
+				parentClassName,
+				maxChunkSize,
+			);
+			chunks.push(...declarationChunks);
+
+			return;
+		}
+
 		// Check for function/method
 		const functionTypes = FUNCTION_NODE_TYPES[lang];
 		const methodTypes = METHOD_NODE_TYPES[lang];
 
-		if (parentClassName && methodTypes.includes(nodeType)) {
+		// Go methods are declared at the top level; the receiver type owns them.
+		// C++ methods defined out of line (`Foo::bar`) belong to `Foo`, Lua
+		// `function Account:deposit()` methods to `Account`.
+		const ownerClassName =
+			parentClassName ??
+			this.extractGoReceiverType(node, lang) ??
+			this.extractCppScopeOwner(node, lang) ??
+			this.extractLuaMethodOwner(node, lang);
+		const isCallable = this.isCallableNode(node, lang);
+
+		if (ownerClassName && isCallable && methodTypes.includes(nodeType)) {
 			// This is a method inside a class
 			const methodChunks = this.nodeToChunks(
 				node,
@@ -526,7 +1371,7 @@ export class Chunker {
 				'method',
 				lang,
 				filepath,
-				parentClassName,
+				ownerClassName,
 				maxChunkSize,
 			);
 			chunks.push(...methodChunks);
@@ -534,7 +1379,7 @@ export class Chunker {
 			return;
 		}
 
-		if (!parentClassName && functionTypes.includes(nodeType)) {
+		if (!parentClassName && isCallable && functionTypes.includes(nodeType)) {
 			// This is a top-level function
 			const functionChunks = this.nodeToChunks(
 				node,
@@ -568,46 +1413,239 @@ export class Chunker {
 	}
 
 	/**
-	 * Convert a syntax node to a chunk.
+	 * Symbol kind of a class-like node. Interfaces (protocols), enums,
+	 * structs and traits get their own kind; everything else, including Rust
+	 * impl blocks and Kotlin/Scala objects, is a class.
 	 */
-	private nodeToChunk(
+	private typeDefinitionKind(
 		node: Parser.SyntaxNode,
-		lines: string[],
-		type: ChunkType,
 		lang: SupportedLanguage,
-		filepath: string,
-		parentClassName: string | null,
-	): Chunk | null {
-		// Get name from the node
-		const name = this.extractName(node, lang);
-
-		// Get start and end lines (1-indexed)
-		const startLine = node.startPosition.row + 1;
-		const endLine = node.endPosition.row + 1;
-
-		// Extract text
-		const text = lines.slice(startLine - 1, endLine).join('\n');
-
-		if (!text.trim()) {
-			return null;
+	): ChunkType {
+		switch (lang) {
+			case 'kotlin': {
+				if (node.children.some(c => c.type === 'interface')) {
+					return 'interface';
+				}
+				return this.modifierKeywords(node).has('enum') ? 'enum' : 'class';
+			}
+			case 'swift': {
+				const declarationKind =
+					node.childForFieldName('declaration_kind')?.text;
+				if (declarationKind === 'struct' || declarationKind === 'enum') {
+					return declarationKind;
+				}
+				break;
+			}
+			case 'go': {
+				if (node.namedChildren.some(c => c.type === 'type_alias')) {
+					return 'type_alias';
+				}
+				const spec = node.namedChildren.find(c => c.type === 'type_spec');
+				const type = spec?.childForFieldName('type')?.type;
+				if (type === 'struct_type') return 'struct';
+				if (type === 'interface_type') return 'interface';
+				// Defined types (`type Celsius float64`) own methods like structs
+				return 'class';
+			}
+			case 'python': {
+				const bases = this.extractSupertypes(node, lang);
+				if (bases.some(b => PYTHON_ENUM_BASES.has(b))) return 'enum';
+				if (bases.some(b => PYTHON_INTERFACE_BASES.has(b))) {
+					return 'interface';
+				}
+				return 'class';
+			}
 		}
+		return TYPE_DEFINITION_KINDS[node.type] ?? 'class';
+	}
 
-		// Build context header
-		const contextHeader = this.buildContextHeader(
-			filepath,
-			parentClassName,
-			type === 'method' ? null : name, // Don't include function name for methods (class provides context)
-			false,
-		);
-
-		// Hash includes context header for unique embedding per context
+	/**
+	 * Chunk type for a constant/variable/type alias/variant/field node, or
+	 * null. `inType` is set for members of a class-like node.
+	 */
+	private declarationChunkType(
+		node: Parser.SyntaxNode,
+		lang: SupportedLanguage,
+		inType: boolean,
+	): ChunkType | null {
+		const type = DECLARATION_NODE_TYPES[lang]?.[node.type];
+		if (!type) return null;
+		if (!this.extractName(node, lang)) return null;
+		// C++ member function prototypes are methods, not fields
+		if (this.extractCFunctionDeclarator(node, lang)) return null;
+		switch (lang) {
+			case 'rust': {
+				// `static mut` items are variables rather than constants
+				if (node.type !== 'static_item') return type;
+				const isMutable = node.children.some(
+					c => c.type === 'mutable_specifier',
+				);
+				return isMutable ? 'variable' : 'constant';
+			}
+			case 'javascript':
+			case 'typescript':
+			case 'tsx':
+				return this.jsDeclarationKind(node, type, inType);
+			case 'python':
+				return this.pythonDeclarationKind(node);
+			case 'java': {
+				// `static final` fields are constants
+				const modifiers = this.modifierKeywords(node);
+				return node.type === 'field_declaration' &&
+					modifiers.has('static') &&
+					modifiers.has('final')
+					? 'constant'
+					: type;
+			}
+			case 'csharp':
+				return node.type === 'field_declaration' &&
+					this.modifierKeywords(node).has('const')
+					? 'constant'
+					: type;
+			case 'kotlin':
+			case 'swift':
+				return node.type === 'property_declaration'
+					? this.propertyDeclarationKind(node, inType)
+					: type;
+			case 'ruby':
+				// Only constant assignments (`MAX_RETRIES = 3`)
+				return node.childForFieldName('left')?.type === 'constant'
+					? type
+					: null;
+			case 'scala': {
+				if (type !== 'constant' && type !== 'variable') return type;
+				// `val`s of an object are constants, of a class or trait fields
+				const owner = inType
+					? this.findAncestor(node, CLASS_NODE_TYPES[lang])
+					: null;
+				return owner && owner.type !== 'object_definition' ? 'field' : type;
+			}
+		}
+		return type;
+	}
+
+	/**
+	 * JS/TS: exported `const`/`let`/`var` bindings whose value is not a
+	 * function or class (those are chunked as such), class and interface
+	 * members, and enum members.
+	 */
+	private jsDeclarationKind(
+		node: Parser.SyntaxNode,
+		type: ChunkType,
+		inType: boolean,
+	): ChunkType | null {
+		if (type === 'variant') {
+			return node.parent?.type === 'enum_body' ? type : null;
+		}
+		if (
+			node.type !== 'lexical_declaration' &&
+			node.type !== 'variable_declaration'
+		) {
+			return type === 'field' && !inType ? null : type;
+		}
+		if (node.parent?.type !== 'export_statement') return null;
+		const declarator = node.namedChildren.find(
+			c => c.type === 'variable_declarator',
+		);
+		const value = declarator?.childForFieldName('value');
+		if (value && JS_CALLABLE_VALUE_TYPES.has(value.type)) return null;
+		return node.child(0)?.type === 'let' ? 'variable' : type;
+	}
+
+	/**
+	 * Python: module-level ALL_CAPS assignments are constants (`TypeAlias`
+	 * annotations type aliases), class attributes fields (members of an enum
+	 * variants). Other assignments are not declarations.
+	 */
+	private pythonDeclarationKind(node: Parser.SyntaxNode): ChunkType | null {
+		if (node.type === 'type_alias_statement') return 'type_alias';
+		const left = node.childForFieldName('left');
+		if (left?.type !== 'identifier' || left.text.startsWith('__')) {
+			return null;
+		}
+		const statement = node.parent;
+		if (statement?.type !== 'expression_statement') return null;
+		const scope = statement.parent;
+		if (scope?.type === 'module') {
+			if (node.childForFieldName('type')?.text === 'TypeAlias') {
+				return 'type_alias';
+			}
+			return /^[A-Z][A-Z0-9_]*$/.test(left.text) ? 'constant' : null;
+		}
+		const owner = scope?.type === 'block' ? scope.parent : null;
+		if (owner?.type !== 'class_definition') return null;
+		return this.typeDefinitionKind(owner, 'python') === 'enum'
+			? 'variant'
+			: 'field';
+	}
+
+	/**
+	 * Kotlin/Swift properties: members of a type are fields (unless
+	 * `const`), top-level `val`/`let` constants and `var` variables.
+	 */
+	private propertyDeclarationKind(
+		node: Parser.SyntaxNode,
+		inType: boolean,
+	): ChunkType {
+		if (this.modifierKeywords(node).has('const')) return 'constant';
+		if (inType) return 'field';
+		const binding = node.children.find(
+			c =>
+				c.type === 'binding_pattern_kind' ||
+				c.type === 'value_binding_pattern',
+		);
+		return binding?.text.startsWith('var') ? 'variable' : 'constant';
+	}
+
+	/**
+	 * Convert a syntax node to a chunk.
+	 */
+	private nodeToChunk(
+		node: Parser.SyntaxNode,
+		lines: string[],
+		type: ChunkType,
+		lang: SupportedLanguage,
+		filepath: string,
+		parentClassName: string | null,
+	): Chunk | null {
+		// Get name from the node
+		const name = this.extractName(node, lang);
+
+		// Get start and end lines (1-indexed)
+		const startLine = node.startPosition.row + 1;
+		const endLine = this.lastRow(node) + 1;
+
+		// Extract text
+		const text = lines.slice(startLine - 1, endLine).join('\n');
+
+		if (!text.trim()) {
+			return null;
+		}
+
+		// Build context header
+		const contextHeader = this.buildContextHeader(
+			filepath,
+			parentClassName,
+			parentClassName ? null : name, // Don't include member names (class provides context)
+			false,
+			this.extractNamespacePath(node, lang),
+		);
+
+		// Hash includes context header for unique embedding per context
 		const fullText = contextHeader ? `${contextHeader}\n${text}` : text;
 
 		// Extract new metadata fields
 		const signature = this.extractSignature(node, lines, lang);
 		const docstring = this.extractDocstring(node, lang);
 		const isExported = this.extractIsExported(node, lang);
+		const visibility = this.extractVisibility(node, lang);
 		const decoratorNames = this.extractDecoratorNames(node, lang);
+		const implTarget = this.extractImplTarget(node, lang);
+		const supertypes = this.extractSupertypes(node, lang);
+		const derives = this.extractRustDerives(node, lang);
+		const isTest =
+			isTestFilePath(filepath) ||
+			this.extractIsTest(node, lang, name, decoratorNames);
 		const tokenFacts = this.extractAstTokenFacts(node, lang);
 
 		return {
@@ -623,7 +1661,14 @@ export class Chunker {
 			signature,
 			docstring,
 			isExported,
+			visibility,
 			decoratorNames,
+			implTypeName: implTarget?.typeName ?? null,
+			implTraitName: implTarget?.traitName ?? null,
+			supertypes,
+			derives,
+			isTest,
+			isDocTest: false,
 			identifiers: tokenFacts.identifiers,
 			identifierParts: tokenFacts.identifierParts,
 			calledNames: tokenFacts.calledNames,
@@ -724,6 +1769,7 @@ export class Chunker {
 				args.parentClassName,
 				functionName,
 				isContinuation,
+				this.extractNamespacePath(args.node, args.lang),
 			);
 			const fullText = `${contextHeader}\n${text}`;
 			const tokenFacts = this.extractAstTokenFacts(args.node, args.lang, {
@@ -744,7 +1790,14 @@ export class Chunker {
 				signature: isContinuation ? null : args.base.signature,
 				docstring: isContinuation ? null : args.base.docstring,
 				isExported: args.base.isExported,
+				visibility: args.base.visibility,
 				decoratorNames: isContinuation ? null : args.base.decoratorNames,
+				implTypeName: args.base.implTypeName,
+				implTraitName: args.base.implTraitName,
+				supertypes: args.base.supertypes,
+				derives: args.base.derives,
+				isTest: args.base.isTest,
+				isDocTest: false,
 				identifiers: tokenFacts.identifiers,
 				identifierParts: tokenFacts.identifierParts,
 				calledNames: tokenFacts.calledNames,
@@ -793,6 +1846,20 @@ export class Chunker {
 	): string | null {
 		const startLine = node.startPosition.row;
 
+		// Declarations (constants, type aliases, variants, fields): the first
+		// line without its terminator or body
+		if (DECLARATION_NODE_TYPES[lang]?.[node.type]) {
+			const firstLine = node.text.split('\n')[0] ?? '';
+			const braceIndex = firstLine.indexOf('{');
+			const result = (
+				braceIndex === -1 ? firstLine : firstLine.slice(0, braceIndex)
+			)
+				.trim()
+				.replace(/[;,]$/, '')
+				.trim();
+			return result || null;
+		}
+
 		// Python: Signature ends with colon
 		if (lang === 'python') {
 			let signatureEnd = startLine;
@@ -809,8 +1876,45 @@ export class Chunker {
 				.trim();
 		}
 
-		// C-style languages (Go, Rust, Java, C#, Swift, Kotlin, Dart, PHP):
-		// Signature ends at opening brace, may span multiple lines
+		// Ruby, Lua: the definition through its parameters (or name / superclass)
+		if (lang === 'ruby' || lang === 'lua') {
+			const end =
+				node.childForFieldName('parameters') ??
+				node.childForFieldName('superclass') ??
+				node.childForFieldName('name');
+			const header = end
+				? node.text.slice(0, end.endIndex - node.startIndex)
+				: (node.text.split('\n')[0] ?? '');
+			return header.trim() || null;
+		}
+
+		// Scala: the definition up to its body (`= expr`, `{ ... }`)
+		if (lang === 'scala') {
+			const body = node.childForFieldName('body');
+			const header = body
+				? node.text.slice(0, body.startIndex - node.startIndex)
+				: node.text;
+			return (
+				header
+					.trim()
+					.replace(/\s*[={:]$/, '')
+					.trim() || null
+			);
+		}
+
+		// Elixir: the first line without its `do` block / `, do:` body
+		if (lang === 'elixir') {
+			const firstLine = lines[startLine] ?? '';
+			return (
+				firstLine
+					.replace(/,\s*do:.*$/, '')
+					.replace(/\s+do\s*$/, '')
+					.trim() || null
+			);
+		}
+
+		// C-style languages (Go, Rust, Java, C#, Swift, Kotlin, Dart, PHP,
+		// C/C++): Signature ends at opening brace, may span multiple lines
 		if (
 			lang === 'go' ||
 			lang === 'rust' ||
@@ -819,10 +1923,14 @@ export class Chunker {
 			lang === 'swift' ||
 			lang === 'kotlin' ||
 			lang === 'dart' ||
-			lang === 'php'
+			lang === 'php' ||
+			lang === 'c' ||
+			lang === 'cpp'
 		) {
+			// Bodiless members (interface methods) end at the node itself.
+			const endRow = Math.min(this.lastRow(node), startLine + 9);
 			const signatureLines: string[] = [];
-			for (let i = startLine; i < lines.length && i < startLine + 10; i++) {
+			for (let i = startLine; i < lines.length && i <= endRow; i++) {
 				const line = lines[i];
 				if (!line) continue;
 				signatureLines.push(line);
@@ -839,7 +1947,11 @@ export class Chunker {
 					break;
 				}
 			}
-			const result = signatureLines.join('\n').trim();
+			let result = signatureLines.join('\n').trim();
+			// C/C++ prototypes match their definition's signature
+			if (lang === 'c' || lang === 'cpp') {
+				result = result.replace(/\s*;$/, '');
+			}
 			return result || null;
 		}
 
@@ -918,8 +2030,8 @@ export class Chunker {
 					} else {
 						break;
 					}
-				} else if (sibling.type === 'block_comment') {
-					break;
+				} else if (sibling.type === 'attribute_item' && comments.length === 0) {
+					// Doc comments come before `#[...]` attributes
 				} else {
 					break;
 				}
@@ -954,8 +2066,13 @@ export class Chunker {
 			return comments.length > 0 ? comments.join(' ').trim() : null;
 		}
 
-		// Java, Kotlin, PHP: /** Javadoc */ style
-		if (lang === 'java' || lang === 'kotlin' || lang === 'php') {
+		// Java, Kotlin, PHP, Scala: /** Javadoc */ style
+		if (
+			lang === 'java' ||
+			lang === 'kotlin' ||
+			lang === 'php' ||
+			lang === 'scala'
+		) {
 			let sibling = node.previousSibling;
 			while (sibling) {
 				if (
@@ -988,6 +2105,71 @@ export class Chunker {
 			return null;
 		}
 
+		// C/C++: Doxygen /** */, /*! */, /// or //! comments
+		if (lang === 'c' || lang === 'cpp') {
+			const target =
+				node.parent?.type === 'template_declaration' ? node.parent : node;
+			const comments: string[] = [];
+			let sibling = target.previousSibling;
+			while (sibling?.type === 'comment') {
+				const text = sibling.text;
+				if (/^\/\*[*!]/.test(text) && comments.length === 0) {
+					return (
+						text
+							.replace(/^\/\*[*!]/, '')
+							.replace(/\*\/$/, '')
+							.replace(/^\s*\* ?/gm, '')
+							.replace(/^\s*[@\\]brief\s+/, '')
+							.trim() || null
+					);
+				}
+				if (!/^\/\/[/!]/.test(text)) break;
+				comments.unshift(text.replace(/^\/\/[/!]\s?/, ''));
+				sibling = sibling.previousSibling;
+			}
+			return comments.length > 0
+				? comments
+						.join('\n')
+						.replace(/^\s*[@\\]brief\s+/, '')
+						.trim()
+				: null;
+		}
+
+		// Ruby: # comment(s) immediately before (RDoc / YARD); Lua: --- LDoc
+		// comments
+		if (lang === 'ruby' || lang === 'lua') {
+			const prefix = lang === 'ruby' ? /^#/ : /^---/;
+			const comments: string[] = [];
+			let sibling = node.previousSibling;
+			while (sibling?.type === 'comment' && prefix.test(sibling.text)) {
+				comments.unshift(sibling.text.replace(/^(#|-{3,})\s?/, ''));
+				sibling = sibling.previousSibling;
+			}
+			return comments.length > 0 ? comments.join('\n').trim() : null;
+		}
+
+		// Elixir: `@moduledoc` inside a module, `@doc` before a function
+		if (lang === 'elixir') {
+			if (this.isTypeDefinition(node, lang)) {
+				const block = node.namedChildren.find(c => c.type === 'do_block');
+				const moduledoc = block?.namedChildren
+					.map(c => this.extractElixirAttribute(c))
+					.find(a => a?.name === 'moduledoc');
+				return moduledoc ? this.elixirDocText(moduledoc.value) : null;
+			}
+			let sibling = node.previousSibling;
+			while (sibling) {
+				const attribute = this.extractElixirAttribute(sibling);
+				if (attribute?.name === 'doc') {
+					return this.elixirDocText(attribute.value);
+				}
+				// `@spec` / `@impl` sit between `@doc` and the definition
+				if (!attribute && sibling.type !== 'comment') break;
+				sibling = sibling.previousSibling;
+			}
+			return null;
+		}
+
 		// Swift, Dart: /// or /** */ style
 		if (lang === 'swift' || lang === 'dart') {
 			const comments: string[] = [];
@@ -1075,9 +2257,20 @@ export class Chunker {
 			return name.length > 0 && name[0] === name[0]?.toUpperCase();
 		}
 
-		// Rust: Look for 'pub' visibility modifier
-		if (lang === 'rust') {
-			return this.hasVisibilityModifier(node, 'pub');
+		// Rust: Only fully public items (`pub`, not `pub(crate)`, and not
+		// inside a private module) are exported. C/C++: not `static`, not in
+		// an anonymous namespace and not a private/protected member. Ruby,
+		// Scala, Elixir, Lua: public by default.
+		if (
+			lang === 'rust' ||
+			lang === 'c' ||
+			lang === 'cpp' ||
+			lang === 'ruby' ||
+			lang === 'scala' ||
+			lang === 'elixir' ||
+			lang === 'lua'
+		) {
+			return this.extractVisibility(node, lang) === 'public';
 		}
 
 		// Java, C#, Swift: Look for 'public' keyword
@@ -1145,6 +2338,290 @@ export class Chunker {
 		return false;
 	}
 
+	/**
+	 * Declared visibility of a node, normalized across languages.
+	 */
+	private extractVisibility(
+		node: Parser.SyntaxNode,
+		lang: SupportedLanguage,
+	): Visibility {
+		switch (lang) {
+			case 'rust':
+				return this.extractRustVisibility(node);
+
+			// Python: `__name` is name-mangled, `_name` is internal by convention
+			case 'python': {
+				const name = this.extractName(node, lang);
+				if (name.startsWith('__') && !name.endsWith('__')) return 'private';
+				if (name.startsWith('_') && !name.startsWith('__')) {
+					return this.findAncestor(node, ['class_definition'])
+						? 'protected'
+						: 'private';
+				}
+				return 'public';
+			}
+
+			// Dart: underscore prefix is library-private
+			case 'dart':
+				return this.extractName(node, lang).startsWith('_')
+					? 'private'
+					: 'public';
+
+			// Go: unexported names are package-visible
+			case 'go':
+				return this.extractIsExported(node, lang) ? 'public' : 'crate';
+
+			// Java: no modifier means package-private (public in interfaces)
+			case 'java': {
+				const modifiers = this.modifierKeywords(node);
+				if (modifiers.has('public')) return 'public';
+				if (modifiers.has('protected')) return 'protected';
+				if (modifiers.has('private')) return 'private';
+				return node.parent?.type === 'interface_body' ? 'public' : 'crate';
+			}
+
+			// C#: members default to private, top-level types to internal
+			case 'csharp': {
+				const modifiers = this.modifierKeywords(node);
+				if (modifiers.has('public')) return 'public';
+				if (modifiers.has('protected')) return 'protected';
+				if (modifiers.has('internal')) return 'crate';
+				if (modifiers.has('private')) return 'private';
+				const owner =
+					node.parent?.type === 'declaration_list' ? node.parent.parent : null;
+				if (!owner || owner.type === 'namespace_declaration') return 'crate';
+				return owner.type === 'interface_declaration' ? 'public' : 'private';
+			}
+
+			// Kotlin: default is public
+			case 'kotlin': {
+				const modifiers = this.modifierKeywords(node);
+				if (modifiers.has('private')) return 'private';
+				if (modifiers.has('protected')) return 'protected';
+				if (modifiers.has('internal')) return 'crate';
+				return 'public';
+			}
+
+			// Swift: default is internal (module-visible)
+			case 'swift': {
+				const modifiers = this.modifierKeywords(node);
+				if (modifiers.has('public') || modifiers.has('open')) return 'public';
+				if (modifiers.has('fileprivate')) return 'restricted';
+				if (modifiers.has('private')) return 'private';
+				return 'crate';
+			}
+
+			// PHP: no modifier means public
+			case 'php': {
+				const modifiers = this.modifierKeywords(node);
+				if (modifiers.has('private')) return 'private';
+				if (modifiers.has('protected')) return 'protected';
+				return 'public';
+			}
+
+			case 'c':
+			case 'cpp':
+				return this.extractCVisibility(node);
+
+			case 'ruby':
+				return this.extractRubyVisibility(node);
+
+			// Scala: default is public; `private[pkg]` is package-visible
+			case 'scala': {
+				const modifiers = node.children.find(c => c.type === 'modifiers');
+				const text = modifiers?.text.replace(/\s+/g, '') ?? '';
+				if (/\b(private|protected)\[(?!this\])/.test(text)) return 'crate';
+				if (/\bprivate\b/.test(text)) return 'private';
+				if (/\bprotected\b/.test(text)) return 'protected';
+				return 'public';
+			}
+
+			// Elixir: `defp` / `defmacrop` / `defguardp` are module-private
+			case 'elixir':
+				return ELIXIR_PRIVATE_CALLS.has(this.elixirCallName(node) ?? '')
+					? 'private'
+					: 'public';
+
+			// Lua: `local function` is file-local
+			case 'lua':
+				return node.type === 'local_function' ||
+					node.children.some(c => c.type === 'local')
+					? 'private'
+					: 'public';
+
+			// JS/TS: class members use accessibility modifiers / #private names
+			// and otherwise follow their class; everything else follows `export`
+			default: {
+				if (node.type === 'method_definition') {
+					const modifiers = this.modifierKeywords(node);
+					if (modifiers.has('private')) return 'private';
+					if (modifiers.has('protected')) return 'protected';
+					const nameNode = node.childForFieldName('name');
+					if (nameNode?.type === 'private_property_identifier') {
+						return 'private';
+					}
+					const owner = this.findAncestor(node, CLASS_NODE_TYPES[lang]);
+					if (owner) return this.extractVisibility(owner, lang);
+				}
+				return this.extractIsExported(node, lang) ? 'public' : 'private';
+			}
+		}
+	}
+
+	/**
+	 * Rust visibility: the item's own `pub(...)`, inherited for enum variants
+	 * and trait items, then narrowed by enclosing inline modules (a `pub fn`
+	 * inside a private `mod` is only reachable from the parent module).
+	 */
+	private extractRustVisibility(node: Parser.SyntaxNode): Visibility {
+		// macro_rules! definitions are exported via #[macro_export]
+		if (node.type === 'macro_definition') {
+			const attributes = this.extractDecoratorNames(node, 'rust') ?? '';
+			return attributes.split(',').includes('macro_export')
+				? 'public'
+				: 'private';
+		}
+
+		let visibility: Visibility;
+		const container = node.parent?.parent ?? null;
+		if (node.type === 'enum_variant') {
+			const owner = this.findAncestor(node, ['enum_item']);
+			visibility = owner ? this.rustDeclaredVisibility(owner) : 'private';
+		} else if (container?.type === 'trait_item') {
+			visibility = this.rustDeclaredVisibility(container);
+		} else if (
+			container?.type === 'impl_item' &&
+			container.childForFieldName('trait')
+		) {
+			// Trait impl items are as visible as the trait and type
+			visibility = 'public';
+		} else {
+			visibility = this.rustDeclaredVisibility(node);
+		}
+
+		for (let p = node.parent; p; p = p.parent) {
+			if (p.type !== 'mod_item') continue;
+			const modVisibility = this.rustDeclaredVisibility(p);
+			const cap = modVisibility === 'private' ? 'restricted' : modVisibility;
+			if (VISIBILITY_RANK[cap] < VISIBILITY_RANK[visibility]) {
+				visibility = cap;
+			}
+		}
+		return visibility;
+	}
+
+	/**
+	 * Map a Rust `visibility_modifier` (`pub`, `pub(crate)`, `pub(super)`,
+	 * `pub(in path)`, `pub(self)`) to a visibility level.
+	 */
+	private rustDeclaredVisibility(node: Parser.SyntaxNode): Visibility {
+		const modifier = node.children.find(
+			c => c.type === 'visibility_modifier',
+		);
+		if (!modifier) return 'private';
+		const text = modifier.text.replace(/\s+/g, '');
+		if (text === 'pub') return 'public';
+		if (text === 'pub(crate)' || text === 'crate') return 'crate';
+		if (text === 'pub(self)') return 'private';
+		return 'restricted';
+	}
+
+	/**
+	 * C/C++ visibility: class members follow the nearest preceding
+	 * `public:`/`protected:`/`private:` label (private by default in a
+	 * `class`, public in a `struct`/`union`); `static` and anonymous-namespace
+	 * definitions are file-local. Out-of-line member definitions carry no
+	 * access label and are treated as public.
+	 */
+	private extractCVisibility(node: Parser.SyntaxNode): Visibility {
+		const member =
+			node.parent?.type === 'template_declaration' ? node.parent : node;
+		const body = member.parent;
+		if (body?.type === 'field_declaration_list') {
+			for (let s = member.previousSibling; s; s = s.previousSibling) {
+				if (s.type !== 'access_specifier') continue;
+				const access = s.text.replace(':', '').trim();
+				if (access === 'private') return 'private';
+				if (access === 'protected') return 'protected';
+				return 'public';
+			}
+			return body.parent?.type === 'class_specifier' ? 'private' : 'public';
+		}
+
+		const isStatic = node.children.some(
+			c => c.type === 'storage_class_specifier' && c.text === 'static',
+		);
+		if (isStatic) return 'private';
+		for (let p = node.parent; p; p = p.parent) {
+			if (p.type === 'namespace_definition' && !p.childForFieldName('name')) {
+				return 'private';
+			}
+		}
+		return 'public';
+	}
+
+	/**
+	 * Ruby visibility of a method: a `private def foo` wrapper, a later
+	 * `private :foo`, or the nearest preceding bare `private`/`protected`/
+	 * `public` in the class body. Everything else is public.
+	 */
+	private extractRubyVisibility(node: Parser.SyntaxNode): Visibility {
+		if (node.type !== 'method') return 'public';
+
+		const wrapper =
+			node.parent?.type === 'argument_list' ? node.parent.parent : null;
+		const wrapped =
+			wrapper?.type === 'call'
+				? RUBY_VISIBILITY_KEYWORDS.get(
+						wrapper.childForFieldName('method')?.text ?? '',
+					)
+				: undefined;
+		if (wrapped) return wrapped;
+
+		const symbol = `:${this.extractName(node, 'ruby')}`;
+		for (let s = node.nextSibling; s; s = s.nextSibling) {
+			if (s.type !== 'call') continue;
+			const keyword = RUBY_VISIBILITY_KEYWORDS.get(
+				s.childForFieldName('method')?.text ?? '',
+			);
+			const names = s.childForFieldName('arguments')?.namedChildren ?? [];
+			if (keyword && names.some(n => n.text === symbol)) return keyword;
+		}
+
+		for (let s = node.previousSibling; s; s = s.previousSibling) {
+			const keyword =
+				s.type === 'identifier'
+					? RUBY_VISIBILITY_KEYWORDS.get(s.text)
+					: undefined;
+			if (keyword) return keyword;
+		}
+		return 'public';
+	}
+
+	/**
+	 * Keywords from a node's modifier children (`public`, `internal`, ...).
+	 */
+	private modifierKeywords(node: Parser.SyntaxNode): Set<string> {
+		const keywords = new Set<string>();
+		for (const child of node.children) {
+			if (!MODIFIER_NODE_TYPES.has(child.type)) continue;
+			for (const word of child.text.split(/[^A-Za-z]+/)) {
+				if (word) keywords.add(word);
+			}
+		}
+		return keywords;
+	}
+
+	private findAncestor(
+		node: Parser.SyntaxNode,
+		types: string[],
+	): Parser.SyntaxNode | null {
+		for (let p = node.parent; p; p = p.parent) {
+			if (types.includes(p.type)) return p;
+		}
+		return null;
+	}
+
 	/**
 	 * Helper to check for visibility modifiers in a node.
 	 */
@@ -1406,6 +2883,17 @@ export class Chunker {
 			}
 		}
 
+		// Scala: @annotation syntax (children of the definition)
+		else if (lang === 'scala') {
+			for (const child of node.children) {
+				if (child.type !== 'annotation') continue;
+				const nameNode = child.childForFieldName('name');
+				if (nameNode) {
+					decorators.push(nameNode.text);
+				}
+			}
+		}
+
 		// Go: No decorators (uses comments like //go:embed but not proper decorators)
 
 		// JS/TS: @decorator syntax
@@ -1469,46 +2957,221 @@ export class Chunker {
 	}
 
 	/**
-	 * Helper to find a child node of specific types.
+	 * Whether a definition is test code (independent of its file path):
+	 * Rust `#[test]` / `#[cfg(test)]`, pytest `test_*`, Go `TestX`,
+	 * JS/TS `describe`/`it` blocks, JUnit/xUnit/NUnit annotations, XCTest and
+	 * PHPUnit `test*` methods, GoogleTest/Catch2 `TEST(...)` macros, RSpec
+	 * and busted blocks, minitest `test_*` methods, ExUnit case modules.
 	 */
-	private findChildOfType(
+	private extractIsTest(
 		node: Parser.SyntaxNode,
-		types: string[],
-	): Parser.SyntaxNode | null {
-		for (let i = 0; i < node.childCount; i++) {
-			const child = node.child(i);
-			if (child && types.includes(child.type)) {
-				return child;
-			}
-		}
-		return null;
-	}
-
-	/**
-	 * Build a context header for a chunk.
-	 */
-	private buildContextHeader(
-		filepath: string,
-		parentClassName: string | null,
-		functionName: string | null,
-		isContinuation: boolean,
-	): string {
-		const parts = [`// File: ${filepath}`];
-		if (parentClassName) {
-			parts.push(`Class: ${parentClassName}`);
-		}
-		if (functionName) {
-			parts.push(`Function: ${functionName}`);
-		}
-		if (isContinuation) {
-			parts.push('(continued)');
-		}
-		return parts.join(', ');
-	}
+		lang: SupportedLanguage,
+		name: string,
+		decoratorNames: string | null,
+	): boolean {
+		const decorators = (decoratorNames ?? '')
+			.split(',')
+			.map(d => d.trim())
+			.filter(Boolean);
 
-	private uniqueStable(values: string[]): string[] {
-		if (values.length <= 1) return values;
-		const out: string[] = [];
+		switch (lang) {
+			case 'rust': {
+				if (
+					decorators.some(
+						d => d === 'test' || d === 'bench' || d.endsWith('::test'),
+					)
+				) {
+					return true;
+				}
+				for (let n: Parser.SyntaxNode | null = node; n; n = n.parent) {
+					if (this.hasRustCfgTestAttribute(n)) return true;
+				}
+				return false;
+			}
+			case 'python': {
+				if (decorators.some(d => d === 'pytest.fixture')) return true;
+				if (/^test_/.test(name) || /^Test[A-Z_]/.test(name)) return true;
+				return this.hasAncestorClassNamed(node, /^Test[A-Z_]/);
+			}
+			case 'go':
+				return /^(Test|Benchmark|Fuzz|Example)([A-Z_]|$)/.test(name);
+			case 'javascript':
+			case 'typescript':
+			case 'tsx':
+				return this.isInsideJsTestBlock(node);
+			case 'java':
+			case 'kotlin':
+			case 'csharp':
+			case 'scala': {
+				const annotations = [
+					...decorators,
+					...(node.text.slice(0, 400).match(/(?<=@|\[)[A-Za-z.]+/g) ?? []),
+				].map(d => d.split('.').pop() ?? d);
+				return annotations.some(a => TEST_ANNOTATIONS.has(a));
+			}
+			case 'swift':
+				return (
+					/^test/.test(name) &&
+					this.hasAncestorClassNamed(node, /./, 'XCTestCase')
+				);
+			case 'php':
+				return (
+					/^test/.test(name) &&
+					this.hasAncestorClassNamed(node, /./, 'TestCase')
+				);
+			// GoogleTest / Catch2 test macros parse as function definitions
+			case 'c':
+			case 'cpp':
+				return C_TEST_MACROS.has(name);
+			case 'ruby':
+				return (
+					(/^test_/.test(name) &&
+						this.hasAncestorClassNamed(node, /./, 'Test')) ||
+					this.isInsideSpecBlock(node)
+				);
+			case 'lua':
+				return this.isInsideSpecBlock(node);
+			case 'elixir':
+				for (let n: Parser.SyntaxNode | null = node; n; n = n.parent) {
+					if (
+						this.elixirCallName(n) === 'defmodule' &&
+						/\buse\s+ExUnit\.Case\b/.test(n.text)
+					) {
+						return true;
+					}
+				}
+				return false;
+			default:
+				return false;
+		}
+	}
+
+	/**
+	 * Rust: `#[cfg(test)]` (or `#[cfg(all(test, ...))]`) on the item itself.
+	 */
+	private hasRustCfgTestAttribute(node: Parser.SyntaxNode): boolean {
+		let sibling = node.previousSibling;
+		while (sibling) {
+			if (sibling.type === 'attribute_item') {
+				const text = sibling.text.replace(/\s+/g, '');
+				if (/^#\[cfg\(.*\btest\b/.test(text) && !/not\(test\)/.test(text)) {
+					return true;
+				}
+			} else if (
+				sibling.type !== 'line_comment' &&
+				sibling.type !== 'block_comment'
+			) {
+				break;
+			}
+			sibling = sibling.previousSibling;
+		}
+		return false;
+	}
+
+	private hasAncestorClassNamed(
+		node: Parser.SyntaxNode,
+		namePattern: RegExp,
+		headerContains?: string,
+	): boolean {
+		for (let n = node.parent; n; n = n.parent) {
+			if (!n.type.includes('class')) continue;
+			const className = n.childForFieldName('name')?.text ?? '';
+			if (!namePattern.test(className)) continue;
+			if (headerContains) {
+				const body = n.childForFieldName('body');
+				const header = body
+					? n.text.slice(0, body.startIndex - n.startIndex)
+					: n.text.slice(0, 200);
+				if (!header.includes(headerContains)) continue;
+			}
+			return true;
+		}
+		return false;
+	}
+
+	/**
+	 * JS/TS: inside a `describe(...)` / `it(...)` / `test(...)` callback.
+	 */
+	private isInsideJsTestBlock(node: Parser.SyntaxNode): boolean {
+		for (let n = node.parent; n; n = n.parent) {
+			if (n.type !== 'call_expression') continue;
+			let callee = n.childForFieldName('function');
+			// describe.each([...])(...), it.only(...)
+			while (
+				callee &&
+				(callee.type === 'member_expression' ||
+					callee.type === 'call_expression')
+			) {
+				callee =
+					callee.type === 'member_expression'
+						? callee.childForFieldName('object')
+						: callee.childForFieldName('function');
+			}
+			if (callee?.type === 'identifier' && JS_TEST_BLOCKS.has(callee.text)) {
+				return true;
+			}
+		}
+		return false;
+	}
+
+	/**
+	 * Ruby / Lua: inside an RSpec or busted `describe`/`context`/`it` block.
+	 */
+	private isInsideSpecBlock(node: Parser.SyntaxNode): boolean {
+		for (let n = node.parent; n; n = n.parent) {
+			if (n.type !== 'call' && n.type !== 'function_call') continue;
+			const callee =
+				n.childForFieldName('method') ?? n.childForFieldName('name');
+			if (callee && SPEC_TEST_BLOCKS.has(callee.text)) return true;
+		}
+		return false;
+	}
+
+	/**
+	 * Helper to find a child node of specific types.
+	 */
+	private findChildOfType(
+		node: Parser.SyntaxNode,
+		types: string[],
+	): Parser.SyntaxNode | null {
+		for (let i = 0; i < node.childCount; i++) {
+			const child = node.child(i);
+			if (child && types.includes(child.type)) {
+				return child;
+			}
+		}
+		return null;
+	}
+
+	/**
+	 * Build a context header for a chunk.
+	 */
+	private buildContextHeader(
+		filepath: string,
+		parentClassName: string | null,
+		functionName: string | null,
+		isContinuation: boolean,
+		namespace: string | null = null,
+	): string {
+		const parts = [`// File: ${filepath}`];
+		if (namespace) {
+			parts.push(`Namespace: ${namespace}`);
+		}
+		if (parentClassName) {
+			parts.push(`Class: ${parentClassName}`);
+		}
+		if (functionName) {
+			parts.push(`Function: ${functionName}`);
+		}
+		if (isContinuation) {
+			parts.push('(continued)');
+		}
+		return parts.join(', ');
+	}
+
+	private uniqueStable(values: string[]): string[] {
+		if (values.length <= 1) return values;
+		const out: string[] = [];
 		const seen = new Set<string>();
 		for (const value of values) {
 			if (!seen.has(value)) {
@@ -1589,6 +3252,8 @@ export class Chunker {
 
 	private isIdentifierNodeType(nodeType: string): boolean {
 		if (nodeType === 'identifier' || nodeType === 'name') return true;
+		// Ruby class/module names
+		if (nodeType === 'constant') return true;
 		if (nodeType.endsWith('identifier')) return true;
 		return false;
 	}
@@ -1643,7 +3308,7 @@ export class Chunker {
 		const walk = (node: Parser.SyntaxNode) => {
 			if (this.isCommentNodeType(node.type)) return;
 
-			if (this.isImportNodeType(lang, node.type)) {
+			if (this.isImportNode(lang, node)) {
 				for (const ref of this.extractImportRefsFromNode(node, lang)) {
 					if (!ref.imported_name) continue; // ignore side-effect imports
 					const local = ref.token_texts[0] ?? '';
@@ -1907,6 +3572,8 @@ export class Chunker {
 	): ExtractedRef[] {
 		const identifierMode = options.identifier_mode ?? 'symbolish';
 		const includeStringLiterals = options.include_string_literals ?? false;
+		const includeKeyPathLiterals =
+			!includeStringLiterals && (options.include_key_path_literals ?? false);
 		const maxOccurrencesPerToken = options.max_occurrences_per_token ?? 0;
 
 		const excludeDefinitionNameRanges = this.collectDefinitionNameRanges(
@@ -1919,9 +3586,37 @@ export class Chunker {
 		const refs: ExtractedRef[] = [];
 
 		const walk = (node: Parser.SyntaxNode) => {
-			if (this.isCommentNodeType(node.type)) return;
+			if (this.isCommentNodeType(node.type)) {
+				if (isDocComment(node.text, lang)) {
+					refs.push(...this.extractDocLinkRefs(node, lang));
+				}
+				return;
+			}
+
+			// Rust `mod foo;` pulls in foo.rs / foo/mod.rs: record it as an import
+			// of module `foo` (resolved relative to the current module).
+			if (
+				lang === 'rust' &&
+				node.type === 'mod_item' &&
+				!node.childForFieldName('body')
+			) {
+				const name = node.childForFieldName('name')?.text;
+				if (name) {
+					refs.push({
+						ref_kind: 'import',
+						token_texts: [name],
+						start_line: node.startPosition.row + 1,
+						end_line: node.endPosition.row + 1,
+						start_byte: node.startIndex,
+						end_byte: node.endIndex,
+						module_name: name,
+						imported_name: name,
+					});
+				}
+				return;
+			}
 
-			if (this.isImportNodeType(lang, node.type)) {
+			if (this.isImportNode(lang, node)) {
 				refs.push(...this.extractImportRefsFromNode(node, lang));
 				// JS/TS export_statement nodes can wrap real declarations (export function/class/const).
 				// We still want to traverse those to capture call refs inside bodies.
@@ -1937,6 +3632,13 @@ export class Chunker {
 			}
 
 			if (this.isStringLiteralNodeType(node.type)) {
+				// Python docstrings carry Sphinx cross-references
+				if (
+					lang === 'python' &&
+					node.parent?.type === 'expression_statement'
+				) {
+					refs.push(...this.extractDocLinkRefs(node, lang));
+				}
 				if (includeStringLiterals) {
 					const stripped = this.stripStringLiteral(node.text);
 					if (stripped && stripped.trim().length > 0) {
@@ -1951,6 +3653,25 @@ export class Chunker {
 							imported_name: null,
 						});
 					}
+				} else if (
+					includeKeyPathLiterals &&
+					!this.isStringLiteralNodeType(node.parent?.type ?? '')
+				) {
+					const keyPath = keyPathFromLiteral(
+						this.stripStringLiteral(node.text) ?? '',
+					);
+					if (keyPath) {
+						refs.push({
+							ref_kind: 'string_literal',
+							token_texts: [keyPath],
+							start_line: node.startPosition.row + 1,
+							end_line: node.endPosition.row + 1,
+							start_byte: node.startIndex,
+							end_byte: node.endIndex,
+							module_name: null,
+							imported_name: null,
+						});
+					}
 				}
 				// Avoid capturing identifiers from literal content, but still traverse into
 				// interpolations / substitutions (e.g., JS/TS template strings, Python f-strings).
@@ -1963,7 +3684,38 @@ export class Chunker {
 				return;
 			}
 
-			if (this.isCallExpressionNodeType(node.type)) {
+			if (lang === 'rust' && node.type === 'token_tree') {
+				refs.push(...this.extractMacroCallRefsFromTokenTree(node));
+			}
+
+			const instantiated = this.extractInstantiatedNode(node, lang);
+			if (instantiated) {
+				const qualified = this.extractQualifiedCallToken(
+					node,
+					importedReceivers,
+				);
+				refs.push({
+					ref_kind: 'instantiation',
+					token_texts: this.uniqueStable(
+						[instantiated.name.text, qualified].filter(
+							(t): t is string =>
+								t !== null &&
+								(this.isIdentifierLike(t) || this.isQualifiedCallToken(t)),
+						),
+					),
+					start_line: instantiated.name.startPosition.row + 1,
+					end_line: instantiated.name.endPosition.row + 1,
+					start_byte: instantiated.name.startIndex,
+					end_byte: instantiated.name.endIndex,
+					module_name: null,
+					imported_name: null,
+					has_receiver:
+						instantiated.name.startIndex > instantiated.type.startIndex,
+				});
+			} else if (
+				this.isCallExpressionNodeType(node.type) &&
+				!(lang === 'elixir' && this.isElixirDefinitionCall(node))
+			) {
 				const calledNode = this.extractCalledNameNode(node);
 				const locNode = calledNode ?? node;
 				const base = (calledNode?.text ?? '').trim();
@@ -1990,24 +3742,32 @@ export class Chunker {
 						end_byte: locNode.endIndex,
 						module_name: null,
 						imported_name: null,
+						has_receiver: this.callHasReceiver(node, calledNode),
 					});
 				}
 			}
 
 			if (identifierMode !== 'none' && this.isIdentifierNodeType(node.type)) {
 				const text = node.text.trim();
+				const definesName = excludeDefinitionNameRanges.has(
+					`${node.startIndex}|${node.endIndex}`,
+				);
 				if (
 					text &&
 					this.isIdentifierLike(text) &&
-					!excludeDefinitionNameRanges.has(
-						`${node.startIndex}|${node.endIndex}`,
-					)
+					(!definesName || this.isInitializedFieldName(node))
 				) {
+					// Writes are kept even for names symbolish mode drops, so
+					// assignments to fields and variables can be found.
+					const access = this.classifyAccess(node);
 					const shouldInclude =
-						identifierMode === 'all' || this.isSymbolishIdentifier(text);
+						identifierMode === 'all' ||
+						this.isSymbolishIdentifier(text) ||
+						(access !== 'read' && text.length > 1);
 					if (shouldInclude) {
+						const refKind = this.classifyNameRef(node, lang);
 						refs.push({
-							ref_kind: 'identifier',
+							ref_kind: refKind,
 							token_texts: [text],
 							start_line: node.startPosition.row + 1,
 							end_line: node.endPosition.row + 1,
@@ -2015,6 +3775,8 @@ export class Chunker {
 							end_byte: node.endIndex,
 							module_name: null,
 							imported_name: null,
+							has_receiver: this.identifierHasReceiver(node),
+							...(refKind === 'identifier' ? {access} : {}),
 						});
 					}
 				}
@@ -2034,7 +3796,172 @@ export class Chunker {
 			: deduped;
 	}
 
-	private isImportNodeType(lang: SupportedLanguage, nodeType: string): boolean {
+	/**
+	 * `doc_link` refs for links inside a doc comment / docstring node.
+	 */
+	private extractDocLinkRefs(
+		node: Parser.SyntaxNode,
+		lang: SupportedLanguage,
+	): ExtractedRef[] {
+		const text = node.text;
+		return extractDocLinks(text, lang).map(link => {
+			const before = text.slice(0, link.offset);
+			const startLine =
+				node.startPosition.row + 1 + (before.match(/\n/g)?.length ?? 0);
+			return {
+				ref_kind: 'doc_link',
+				token_texts: this.uniqueStable([link.name, link.path]),
+				start_line: startLine,
+				end_line: startLine,
+				start_byte: node.startIndex + link.offset,
+				end_byte: node.startIndex + link.offset + link.length,
+				module_name: null,
+				imported_name: null,
+			};
+		});
+	}
+
+	/**
+	 * Code examples in each definition's documentation, as chunks that share
+	 * the definition's type/name/header so they attach to its symbol.
+	 */
+	private extractDocTests(
+		definitions: Chunk[],
+		lines: string[],
+		lang: SupportedLanguage,
+	): DocTest[] {
+		const docTests: DocTest[] = [];
+		for (const definition of definitions) {
+			if (definition.type === 'module') continue;
+			const docLines = collectDocLines(
+				lines,
+				definition.startLine,
+				definition.endLine,
+				lang,
+			);
+			if (docLines.length === 0) continue;
+
+			for (const example of extractDocExamples(docLines, lang)) {
+				const contextHeader = `${definition.contextHeader}, (doc test)`;
+				const tokenFacts = this.extractFallbackTokenFacts(example.code);
+				docTests.push({
+					lineNumbers: example.lineNumbers,
+					chunk: {
+						text: example.code,
+						contextHeader,
+						type: definition.type,
+						name: definition.name,
+						startLine: example.startLine,
+						endLine: example.endLine,
+						startByte: null,
+						endByte: null,
+						contentHash: computeStringHash(
+							`${contextHeader}\n${example.code}`,
+						),
+						signature: null,
+						docstring: null,
+						isExported: definition.isExported,
+						visibility: definition.visibility,
+						decoratorNames: null,
+						implTypeName: null,
+						implTraitName: null,
+						supertypes: [],
+						derives: [],
+						isTest: true,
+						isDocTest: true,
+						identifiers: tokenFacts.identifiers,
+						identifierParts: tokenFacts.identifierParts,
+						calledNames: tokenFacts.calledNames,
+						stringLiterals: tokenFacts.stringLiterals,
+					},
+				});
+			}
+		}
+		return docTests;
+	}
+
+	/**
+	 * Call/identifier refs inside doc-test code, mapped back to file lines.
+	 */
+	private extractDocTestRefs(
+		docTests: DocTest[],
+		lang: SupportedLanguage,
+		options: RefExtractionOptions,
+	): ExtractedRef[] {
+		if (!this.parser) return [];
+		const refs: ExtractedRef[] = [];
+		for (const {chunk, lineNumbers} of docTests) {
+			// rustdoc wraps doc tests in `fn main`; do the same so statements
+			// parse as a function body.
+			const wrapped = lang === 'rust';
+			const source = wrapped ? `fn main() {\n${chunk.text}\n}` : chunk.text;
+			const tree = this.parser.parse(source);
+			if (!tree) continue;
+			const lineOffset = wrapped ? 1 : 0;
+			const snippetRefs = this.extractRefsFromTree(
+				tree.rootNode,
+				lang,
+				options,
+			);
+			for (const ref of snippetRefs) {
+				if (!DOC_TEST_REF_KINDS.has(ref.ref_kind)) continue;
+				const startLine = lineNumbers[ref.start_line - 1 - lineOffset];
+				if (startLine == null) continue;
+				refs.push({
+					...ref,
+					start_line: startLine,
+					end_line: lineNumbers[ref.end_line - 1 - lineOffset] ?? startLine,
+					start_byte: null,
+					end_byte: null,
+				});
+			}
+		}
+		return refs;
+	}
+
+	/**
+	 * Rust macro arguments are unparsed token trees, so nested invocations
+	 * (`vec![format!(..)]`, macro calls inside `macro_rules!` bodies) appear as
+	 * `identifier ! token_tree` sequences instead of macro_invocation nodes.
+	 */
+	private extractMacroCallRefsFromTokenTree(
+		node: Parser.SyntaxNode,
+	): ExtractedRef[] {
+		const refs: ExtractedRef[] = [];
+		for (let i = 0; i + 2 < node.childCount; i++) {
+			const nameNode = node.child(i);
+			const bang = node.child(i + 1);
+			const args = node.child(i + 2);
+			if (!nameNode || !bang || !args) continue;
+			if (nameNode.type !== 'identifier') continue;
+			if (bang.text !== '!' || args.type !== 'token_tree') continue;
+
+			const name = nameNode.text.trim();
+			if (!this.isIdentifierLike(name) || name === 'macro_rules') continue;
+
+			refs.push({
+				ref_kind: 'call',
+				token_texts: [name],
+				start_line: nameNode.startPosition.row + 1,
+				end_line: nameNode.endPosition.row + 1,
+				start_byte: nameNode.startIndex,
+				end_byte: nameNode.endIndex,
+				module_name: null,
+				imported_name: null,
+			});
+		}
+		return refs;
+	}
+
+	/**
+	 * Whether a node imports another module. Ruby, Elixir and Lua imports are
+	 * ordinary calls (`require 'x'`, `alias Foo.Bar`, `require("x")`).
+	 */
+	private isImportNode(
+		lang: SupportedLanguage,
+		node: Parser.SyntaxNode,
+	): boolean {
+		const nodeType = node.type;
 		switch (lang) {
 			case 'javascript':
 			case 'typescript':
@@ -2067,6 +3994,24 @@ export class Chunker {
 					nodeType === 'namespace_use_declaration' ||
 					nodeType === 'namespace_use_clause'
 				);
+			case 'c':
+			case 'cpp':
+				return nodeType === 'preproc_include';
+			case 'scala':
+				return nodeType === 'import_declaration';
+			case 'ruby':
+				return (
+					nodeType === 'call' &&
+					!node.childForFieldName('receiver') &&
+					RUBY_IMPORT_METHODS.has(node.childForFieldName('method')?.text ?? '')
+				);
+			case 'elixir':
+				return ELIXIR_IMPORT_CALLS.has(this.elixirCallName(node) ?? '');
+			case 'lua':
+				return (
+					nodeType === 'function_call' &&
+					node.childForFieldName('name')?.text === 'require'
+				);
 			default:
 				return false;
 		}
@@ -2103,6 +4048,21 @@ export class Chunker {
 		if (lang === 'php') {
 			return this.extractImportRefsFromPhpNode(node);
 		}
+		if (lang === 'c' || lang === 'cpp') {
+			return this.extractImportRefsFromCNode(node);
+		}
+		if (lang === 'scala') {
+			return this.extractImportRefsFromScalaNode(node);
+		}
+		if (lang === 'ruby') {
+			return this.extractImportRefsFromRubyNode(node);
+		}
+		if (lang === 'elixir') {
+			return this.extractImportRefsFromElixirNode(node);
+		}
+		if (lang === 'lua') {
+			return this.extractImportRefsFromLuaNode(node);
+		}
 		return [];
 	}
 
@@ -2489,51 +4449,29 @@ export class Chunker {
 			];
 		}
 
-		if (!normalized.startsWith('use ')) return [];
-
-		const body = normalized
-			.replace(/^use\s+/, '')
-			.replace(/;\s*$/, '')
-			.trim();
-		if (!body) return [];
+		if (node.type !== 'use_declaration') return [];
+		const argument = node.childForFieldName('argument');
+		if (!argument) return [];
 
 		const refs: ExtractedRef[] = [];
-
-		const braceStart = body.indexOf('{');
-		const braceEnd = body.lastIndexOf('}');
-		const hasBraces = braceStart >= 0 && braceEnd > braceStart;
-
-		const prefix = hasBraces
-			? body
-					.slice(0, braceStart)
-					.replace(/::\s*$/, '')
-					.trim()
-			: body.split('::').slice(0, -1).join('::').trim();
-
-		const entriesPart = hasBraces
-			? body.slice(braceStart + 1, braceEnd).trim()
-			: body.split('::').pop()!.trim();
-
-		const entries = entriesPart
-			.split(',')
-			.map(e => e.trim())
-			.filter(Boolean)
-			.filter(e => e !== '*' && e !== 'self' && e !== 'super' && e !== 'crate');
+		const entries: Array<{path: string[]; alias: string | null}> = [];
+		this.collectRustUseEntries(argument, [], entries);
 
 		for (const entry of entries) {
-			const cleaned = entry.replace(/^pub\s+/, '').trim();
-			if (!cleaned) continue;
-			const match = cleaned.match(
-				/^([A-Za-z_][A-Za-z0-9_]*)(?:\s+as\s+([A-Za-z_][A-Za-z0-9_]*))?$/,
-			);
-			if (!match?.[1]) continue;
-			const imported = match[1];
-			const local = match[2] ?? imported;
-			const module_name = prefix || body;
+			const segments =
+				entry.path[entry.path.length - 1] === 'self'
+					? entry.path.slice(0, -1)
+					: entry.path;
+			const imported = segments[segments.length - 1];
+			if (!imported || RUST_PATH_KEYWORDS.has(imported)) continue;
+			const module_name =
+				segments.length > 1 ? segments.slice(0, -1).join('::') : imported;
+			const local =
+				entry.alias && entry.alias !== '_' ? entry.alias : imported;
 
 			refs.push({
 				ref_kind: 'import',
-				token_texts: [local],
+				token_texts: local === imported ? [local] : [local, imported],
 				start_line: node.startPosition.row + 1,
 				end_line: node.endPosition.row + 1,
 				start_byte: node.startIndex,
@@ -2546,6 +4484,60 @@ export class Chunker {
 		return refs;
 	}
 
+	/**
+	 * Flatten a Rust use tree (`a::{b, c::d as e}`) into full paths + aliases.
+	 * Glob imports are skipped (they bind no single name).
+	 */
+	private collectRustUseEntries(
+		node: Parser.SyntaxNode,
+		prefix: string[],
+		out: Array<{path: string[]; alias: string | null}>,
+	): void {
+		switch (node.type) {
+			case 'use_as_clause': {
+				const pathNode = node.childForFieldName('path');
+				const alias = node.childForFieldName('alias');
+				if (!pathNode) return;
+				out.push({
+					path: [...prefix, ...this.splitRustPath(pathNode.text)],
+					alias: alias?.text ?? null,
+				});
+				return;
+			}
+			case 'use_list': {
+				for (let i = 0; i < node.namedChildCount; i++) {
+					const child = node.namedChild(i);
+					if (child) this.collectRustUseEntries(child, prefix, out);
+				}
+				return;
+			}
+			case 'scoped_use_list': {
+				const pathNode = node.childForFieldName('path');
+				const list = node.childForFieldName('list');
+				if (!list) return;
+				const nextPrefix = pathNode
+					? [...prefix, ...this.splitRustPath(pathNode.text)]
+					: prefix;
+				this.collectRustUseEntries(list, nextPrefix, out);
+				return;
+			}
+			case 'use_wildcard':
+				return;
+			default: {
+				const segments = this.splitRustPath(node.text);
+				if (segments.length === 0) return;
+				out.push({path: [...prefix, ...segments], alias: null});
+			}
+		}
+	}
+
+	private splitRustPath(text: string): string[] {
+		return text
+			.replace(/\s+/g, '')
+			.split('::')
+			.filter(Boolean);
+	}
+
 	private extractImportRefsFromJavaNode(
 		node: Parser.SyntaxNode,
 	): ExtractedRef[] {
@@ -2699,6 +4691,213 @@ export class Chunker {
 		];
 	}
 
+	/**
+	 * `#include "geo/shape.h"` / `#include <vector>`: the module is the
+	 * include path and the token its file stem (`shape`).
+	 */
+	private extractImportRefsFromCNode(node: Parser.SyntaxNode): ExtractedRef[] {
+		const pathNode = node.childForFieldName('path');
+		if (!pathNode) return [];
+		const module_name = pathNode.text.replace(/^["<]|[">]$/g, '').trim();
+		if (!module_name) return [];
+		const stem = path.posix.basename(module_name).replace(/\.[^.]*$/, '');
+		return [
+			{
+				ref_kind: 'import',
+				token_texts: [stem || module_name],
+				start_line: node.startPosition.row + 1,
+				end_line: this.lastRow(node) + 1,
+				start_byte: node.startIndex,
+				end_byte: node.endIndex,
+				module_name,
+				imported_name: null,
+			},
+		];
+	}
+
+	/**
+	 * Scala imports, one ref per imported name: `import a.b.C`,
+	 * `import a.b.{C, D => E}`, `import a.b.C as E`, `import a.b._`.
+	 * Like Java, `module_name` is the full path of the imported name and
+	 * wildcards import the package itself.
+	 */
+	private extractImportRefsFromScalaNode(
+		node: Parser.SyntaxNode,
+	): ExtractedRef[] {
+		const text = node.text
+			.replace(/\s+/g, ' ')
+			.replace(/^\s*import\s+/, '')
+			.trim();
+
+		// Split `a.b, c.{d, e}` at top-level commas
+		const clauses: string[] = [];
+		let depth = 0;
+		let current = '';
+		for (const ch of text) {
+			if (ch === '{') depth++;
+			if (ch === '}') depth--;
+			if (ch === ',' && depth === 0) {
+				clauses.push(current);
+				current = '';
+			} else {
+				current += ch;
+			}
+		}
+		clauses.push(current);
+
+		const entries: Array<{path: string; local: string; imported: string}> = [];
+		const addSelector = (prefix: string, selector: string) => {
+			const [name = '', alias] = selector
+				.split(/\s*(?:=>|\bas\b)\s*/)
+				.map(p => p.trim());
+			if (!name || alias === '_') return;
+			if (name === '_' || name === '*' || name === 'given') {
+				const pkg = prefix.split('.').pop() ?? prefix;
+				entries.push({path: prefix, local: pkg, imported: pkg});
+				return;
+			}
+			entries.push({
+				path: prefix ? `${prefix}.${name}` : name,
+				local: alias || name,
+				imported: name,
+			});
+		};
+
+		for (const raw of clauses) {
+			const clause = raw.trim();
+			const braces = clause.match(/^([\w.]+)\.\{(.*)\}$/);
+			if (braces?.[1]) {
+				for (const selector of (braces[2] ?? '').split(',')) {
+					addSelector(braces[1], selector.trim());
+				}
+				continue;
+			}
+			const simple = clause.match(/^([\w.]+)\.([\w*]+(?:\s+as\s+\w+)?)$/);
+			if (simple?.[1] && simple[2]) addSelector(simple[1], simple[2]);
+		}
+
+		const refs: ExtractedRef[] = [];
+		for (const entry of entries) {
+			refs.push({
+				ref_kind: 'import',
+				token_texts: this.uniqueStable([entry.local, entry.imported]),
+				start_line: node.startPosition.row + 1,
+				end_line: node.endPosition.row + 1,
+				start_byte: node.startIndex,
+				end_byte: node.endIndex,
+				module_name: entry.path,
+				imported_name: entry.imported,
+			});
+		}
+		return refs;
+	}
+
+	/**
+	 * Ruby `require 'json'` / `require_relative 'models/user'` / `load`.
+	 * Relative requires are recorded as `./models/user`; the token is the
+	 * file stem (`user`).
+	 */
+	private extractImportRefsFromRubyNode(
+		node: Parser.SyntaxNode,
+	): ExtractedRef[] {
+		const args = node.childForFieldName('arguments');
+		const pathNode = args ? this.findFirstStringLiteralNode(args) : null;
+		const required = pathNode ? this.stripStringLiteral(pathNode.text) : null;
+		if (!required) return [];
+		const isRelative =
+			node.childForFieldName('method')?.text === 'require_relative';
+		const module_name =
+			isRelative && !required.startsWith('.') ? `./${required}` : required;
+		const stem = path.posix.basename(required).replace(/\.rb$/, '');
+		return [
+			{
+				ref_kind: 'import',
+				token_texts: [stem || module_name],
+				start_line: node.startPosition.row + 1,
+				end_line: node.endPosition.row + 1,
+				start_byte: node.startIndex,
+				end_byte: node.endIndex,
+				module_name,
+				imported_name: null,
+			},
+		];
+	}
+
+	/**
+	 * Elixir `alias Foo.Bar`, `alias Foo.Bar, as: Baz`, `alias Foo.{Bar, Baz}`
+	 * and `import`/`use`/`require Foo.Bar`. The token is the alias (or the
+	 * last segment) the module is referenced by.
+	 */
+	private extractImportRefsFromElixirNode(
+		node: Parser.SyntaxNode,
+	): ExtractedRef[] {
+		const normalized = node.text.replace(/\s+/g, ' ').trim();
+		const match = normalized.match(
+			/^\w+ ([A-Z][\w.]*?)(?:\.\{([^}]*)\})?(?:\s*,\s*as:\s*([A-Z]\w*))?(?:\s*,.*)?$/,
+		);
+		if (!match?.[1]) return [];
+		const base = match[1];
+		const modules = match[2]
+			? match[2]
+					.split(',')
+					.map(name => name.trim())
+					.filter(Boolean)
+					.map(name => `${base}.${name}`)
+			: [base];
+		const refs: ExtractedRef[] = [];
+		for (const module_name of modules) {
+			const imported = module_name.split('.').pop() ?? module_name;
+			refs.push({
+				ref_kind: 'import',
+				token_texts: this.uniqueStable([match[3] ?? imported, imported]),
+				start_line: node.startPosition.row + 1,
+				end_line: node.endPosition.row + 1,
+				start_byte: node.startIndex,
+				end_byte: node.endIndex,
+				module_name,
+				imported_name: imported,
+			});
+		}
+		return refs;
+	}
+
+	/**
+	 * Lua `require("geo.vector")` / `require "geo.vector"`. The token is the
+	 * local the module is assigned to (`local vec = require(...)`), else its
+	 * last segment.
+	 */
+	private extractImportRefsFromLuaNode(
+		node: Parser.SyntaxNode,
+	): ExtractedRef[] {
+		const args = node.childForFieldName('arguments');
+		const pathNode = args ? this.findFirstStringLiteralNode(args) : null;
+		const module_name = pathNode
+			? this.stripStringLiteral(pathNode.text)
+			: null;
+		if (!module_name) return [];
+		const assignment =
+			node.parent?.type === 'expression_list' ? node.parent.parent : null;
+		const target =
+			assignment?.type === 'assignment_statement'
+				? assignment.namedChildren.find(c => c.type === 'variable_list')
+						?.namedChildren[0]
+				: undefined;
+		const local = target?.type === 'identifier' ? target.text : null;
+		const last = module_name.split(/[./]/).pop() || module_name;
+		return [
+			{
+				ref_kind: 'import',
+				token_texts: this.uniqueStable([local ?? last, last]),
+				start_line: node.startPosition.row + 1,
+				end_line: node.endPosition.row + 1,
+				start_byte: node.startIndex,
+				end_byte: node.endIndex,
+				module_name,
+				imported_name: local ? last : null,
+			},
+		];
+	}
+
 	private findFirstStringLiteralNode(
 		node: Parser.SyntaxNode,
 	): Parser.SyntaxNode | null {
@@ -2736,10 +4935,10 @@ export class Chunker {
 		return found;
 	}
 
-	private extractCalledNameNode(
+	private extractCalleeNode(
 		callNode: Parser.SyntaxNode,
 	): Parser.SyntaxNode | null {
-		const callee =
+		return (
 			callNode.childForFieldName('function') ??
 			callNode.childForFieldName('name') ??
 			callNode.childForFieldName('method') ??
@@ -2747,7 +4946,60 @@ export class Chunker {
 			callNode.childForFieldName('target') ??
 			callNode.childForFieldName('macro') ??
 			callNode.childForFieldName('constructor') ??
-			(callNode.namedChildCount > 0 ? callNode.namedChild(0) : null);
+			(callNode.namedChildCount > 0 ? callNode.namedChild(0) : null)
+		);
+	}
+
+	/**
+	 * Whether a call reaches its callee through a receiver (`obj.run()`,
+	 * `pkg.Run()`, `Foo::new()`) rather than by bare name (`run()`,
+	 * `new Foo()`).
+	 */
+	private callHasReceiver(
+		callNode: Parser.SyntaxNode,
+		calledNode: Parser.SyntaxNode | null,
+	): boolean {
+		if (
+			callNode.childForFieldName('object') ??
+			callNode.childForFieldName('receiver')
+		) {
+			return true;
+		}
+		const callee = this.extractCalleeNode(callNode);
+		return (
+			callee != null &&
+			calledNode != null &&
+			calledNode.startIndex > callee.startIndex
+		);
+	}
+
+	/**
+	 * Whether an identifier is the member side of an access (`cfg.Config`,
+	 * `self.Config`, `config::Config`).
+	 */
+	private identifierHasReceiver(node: Parser.SyntaxNode): boolean {
+		const parent = node.parent;
+		if (!parent) return false;
+		const member =
+			parent.childForFieldName('property') ??
+			parent.childForFieldName('field') ??
+			parent.childForFieldName('attribute') ??
+			(parent.type.startsWith('scoped_') ||
+			parent.type === 'nested_type_identifier' ||
+			parent.type === 'qualified_type'
+				? parent.childForFieldName('name')
+				: null);
+		return (
+			member != null &&
+			member.startIndex === node.startIndex &&
+			member.startIndex > parent.startIndex
+		);
+	}
+
+	private extractCalledNameNode(
+		callNode: Parser.SyntaxNode,
+	): Parser.SyntaxNode | null {
+		const callee = this.extractCalleeNode(callNode);
 		if (!callee) return null;
 
 		if (this.isIdentifierNodeType(callee.type)) {
@@ -2797,15 +5049,18 @@ export class Chunker {
 	): Set<string> {
 		const out = new Set<string>();
 
-		const isDefinitionNode = (nodeType: string) =>
-			CLASS_NODE_TYPES[lang].includes(nodeType) ||
-			FUNCTION_NODE_TYPES[lang].includes(nodeType) ||
-			METHOD_NODE_TYPES[lang].includes(nodeType);
+		const isDefinitionNode = (node: Parser.SyntaxNode) =>
+			(CLASS_NODE_TYPES[lang].includes(node.type) &&
+				this.isTypeDefinition(node, lang)) ||
+			((FUNCTION_NODE_TYPES[lang].includes(node.type) ||
+				METHOD_NODE_TYPES[lang].includes(node.type)) &&
+				this.isCallableNode(node, lang)) ||
+			(MACRO_NODE_TYPES[lang]?.includes(node.type) ?? false);
 
 		const walk = (node: Parser.SyntaxNode) => {
 			if (this.isCommentNodeType(node.type)) return;
 
-			if (isDefinitionNode(node.type)) {
+			if (isDefinitionNode(node)) {
 				const nameNode = this.extractNameNode(node, lang);
 				if (nameNode) {
 					out.add(`${nameNode.startIndex}|${nameNode.endIndex}`);
@@ -2832,8 +5087,14 @@ export class Chunker {
 		const priority = (kind: ExtractedRef['ref_kind']): number => {
 			switch (kind) {
 				case 'import':
-					return 4;
+					return 6;
+				case 'extends':
+				case 'implements':
+					return 5;
 				case 'call':
+				case 'instantiation':
+					return 4;
+				case 'type_ref':
 					return 3;
 				case 'identifier':
 					return 2;
@@ -2842,192 +5103,1873 @@ export class Chunker {
 				default:
 					return 0;
 			}
-		};
+		};
+
+		const mergeTokenTexts = (base: string[], extra: string[]): string[] => {
+			if (extra.length === 0) return base;
+			const seen = new Set(base);
+			const out = [...base];
+			for (const t of extra) {
+				if (!t) continue;
+				if (seen.has(t)) continue;
+				seen.add(t);
+				out.push(t);
+			}
+			return out;
+		};
+
+		const byKey = new Map<string, {ref: ExtractedRef; order: number}>();
+		for (let i = 0; i < refs.length; i++) {
+			const ref = refs[i]!;
+			const primary = ref.token_texts[0] ?? '';
+			const key = `${ref.start_byte ?? ''}|${ref.end_byte ?? ''}|${primary}`;
+			const existing = byKey.get(key);
+			if (!existing) {
+				byKey.set(key, {ref, order: i});
+				continue;
+			}
+
+			const existingPriority = priority(existing.ref.ref_kind);
+			const nextPriority = priority(ref.ref_kind);
+
+			if (nextPriority > existingPriority) {
+				byKey.set(key, {
+					ref: {
+						...ref,
+						token_texts: mergeTokenTexts(
+							ref.token_texts,
+							existing.ref.token_texts,
+						),
+					},
+					order: existing.order,
+				});
+			} else if (nextPriority === existingPriority) {
+				byKey.set(key, {
+					ref: {
+						...existing.ref,
+						token_texts: mergeTokenTexts(
+							existing.ref.token_texts,
+							ref.token_texts,
+						),
+					},
+					order: existing.order,
+				});
+			}
+		}
+
+		return [...byKey.values()]
+			.sort((a, b) => a.order - b.order)
+			.map(v => v.ref);
+	}
+
+	private limitRefsPerToken(
+		refs: ExtractedRef[],
+		maxPerToken: number,
+	): ExtractedRef[] {
+		if (maxPerToken <= 0) return refs;
+		const counts = new Map<string, number>();
+		const out: ExtractedRef[] = [];
+
+		for (const ref of refs) {
+			const primary = ref.token_texts[0] ?? '';
+			const key = `${ref.ref_kind}|${primary}`;
+			const prev = counts.get(key) ?? 0;
+			if (prev >= maxPerToken) continue;
+			counts.set(key, prev + 1);
+			out.push(ref);
+		}
+
+		return out;
+	}
+
+	private extractFallbackTokenFacts(text: string): TokenFacts {
+		const identifiers: string[] = [];
+		const stringLiterals: string[] = [];
+
+		for (const match of text.matchAll(/[A-Za-z_][A-Za-z0-9_]*/g)) {
+			if (!match[0]) continue;
+			identifiers.push(match[0]);
+		}
+
+		for (const match of text.matchAll(/(['"])(?:(?=(\\?))\2.)*?\1/g)) {
+			if (!match[0]) continue;
+			const stripped = this.stripStringLiteral(match[0]);
+			if (stripped) stringLiterals.push(stripped.slice(0, 512));
+		}
+
+		const uniqueIdentifiers = this.uniqueStable(identifiers);
+		const identifierParts = this.uniqueStable(
+			uniqueIdentifiers.flatMap(id => this.splitIdentifierParts(id)),
+		);
+
+		return {
+			identifiers: uniqueIdentifiers,
+			identifierParts,
+			calledNames: [],
+			stringLiterals: this.uniqueStable(stringLiterals),
+		};
+	}
+
+	/**
+	 * Extract the name of a function/class/method from its node.
+	 */
+	private extractNameNode(
+		node: Parser.SyntaxNode,
+		lang: SupportedLanguage,
+	): Parser.SyntaxNode | null {
+		// Rust impl blocks reference (not define) their type and trait names.
+		if (node.type === 'impl_item') {
+			return null;
+		}
+
+		// Elixir definitions are macro calls named by their first argument
+		// (`defmodule Geo.Shape`, `def area(shape) when ...`).
+		if (lang === 'elixir') {
+			return this.extractElixirNameNode(node);
+		}
+
+		// C/C++ functions, prototypes, fields and typedefs are named by their
+		// declarator (`char *name(void)`, `typedef struct {...} Point`).
+		if (lang === 'c' || lang === 'cpp') {
+			const declarator = node.childForFieldName('declarator');
+			if (declarator) {
+				return this.extractCDeclaratorNameNode(declarator);
+			}
+		}
+
+		// Variables, constants and fields are named by their declarator
+		// (`const MAX = 1`, `private int count;`) or assignment target
+		// (`MAX_RETRIES = 3`, Scala `val limit = 3`).
+		if (DECLARATION_NODE_TYPES[lang]?.[node.type]) {
+			// TS enum members without a value are bare names
+			if (node.type === 'property_identifier') return node;
+			const target =
+				this.extractDeclaratorNameNode(node) ??
+				node.childForFieldName('left') ??
+				node.childForFieldName('pattern');
+			if (target) return target;
+		}
+
+		// Try to get name via field first (works for many languages)
+		const nameField = node.childForFieldName('name');
+		// Lua `function M.greet()` / `function Account:deposit()`
+		if (
+			nameField?.type === 'dot_index_expression' ||
+			nameField?.type === 'method_index_expression'
+		) {
+			return (
+				nameField.childForFieldName('field') ??
+				nameField.childForFieldName('method')
+			);
+		}
+		if (nameField) {
+			return nameField;
+		}
+
+		// Look for common identifier node types
+		for (let i = 0; i < node.childCount; i++) {
+			const child = node.child(i);
+			if (!child) continue;
+
+			// Common identifier types across languages
+			if (
+				child.type === 'identifier' ||
+				child.type === 'name' ||
+				child.type === 'simple_identifier' || // Kotlin, Swift
+				child.type === 'type_identifier' // Rust, Go struct types
+			) {
+				return child;
+			}
+
+			// JS/TS method names
+			if (child.type === 'property_identifier') {
+				return child;
+			}
+
+			// Go type declarations (type Foo struct { }, type Foo = Bar)
+			if (child.type === 'type_spec' || child.type === 'type_alias') {
+				const specName = child.childForFieldName('name');
+				if (specName) {
+					return specName;
+				}
+			}
+		}
+
+		// For JS/TS variable declarations with arrow functions
+		if (node.parent?.type === 'variable_declarator') {
+			const varName = node.parent.childForFieldName('name');
+			if (varName) {
+				return varName;
+			}
+		}
+
+		return null;
+	}
+
+	/**
+	 * Name of the declarator of a variable/field declaration, looking through
+	 * wrapper nodes (C# `variable_declaration`, PHP `property_element`), or
+	 * null when it binds a pattern (`const {a, b} = ...`) or has none.
+	 */
+	private extractDeclaratorNameNode(
+		node: Parser.SyntaxNode,
+	): Parser.SyntaxNode | null {
+		let declarator = node;
+		for (;;) {
+			const next =
+				declarator.childForFieldName('declarator') ??
+				declarator.namedChildren.find(c => DECLARATOR_NODE_TYPES.has(c.type));
+			if (!next) break;
+			declarator = next;
+		}
+		if (declarator === node) return null;
+		const name =
+			declarator.childForFieldName('name') ??
+			declarator.namedChildren.find(c =>
+				DECLARATION_NAME_NODE_TYPES.has(c.type),
+			);
+		return name && DECLARATION_NAME_NODE_TYPES.has(name.type) ? name : null;
+	}
+
+	private extractName(
+		node: Parser.SyntaxNode,
+		_lang: SupportedLanguage,
+	): string {
+		if (node.type === 'impl_item') {
+			const target = this.extractImplTarget(node, _lang);
+			if (!target) return '';
+			return target.traitName
+				? `impl ${target.traitName} for ${target.typeName}`
+				: `impl ${target.typeName}`;
+		}
+
+		// Elixir `defimpl Proto, for: Type` defines module `Proto.Type`
+		if (_lang === 'elixir' && this.elixirCallName(node) === 'defimpl') {
+			return this.extractElixirImplName(node) ?? '';
+		}
+
+		const nameNode = this.extractNameNode(node, _lang);
+		if (!nameNode) return '';
+
+		return nameNode.text;
+	}
+
+	/**
+	 * Extract the implementing type and (optional) trait of a Rust impl block.
+	 * Names are reduced to their base identifier (`fmt::Display` -> `Display`).
+	 */
+	private extractImplTarget(
+		node: Parser.SyntaxNode,
+		lang: SupportedLanguage,
+	): {typeName: string; traitName: string | null} | null {
+		if (lang !== 'rust' || node.type !== 'impl_item') return null;
+
+		const typeNode = node.childForFieldName('type');
+		const typeName = typeNode ? this.extractRustTypeBaseName(typeNode) : null;
+		if (!typeName) return null;
+
+		const traitNode = node.childForFieldName('trait');
+		const traitName = traitNode
+			? this.extractRustTypeBaseName(traitNode)
+			: null;
+
+		return {typeName, traitName};
+	}
+
+	private extractRustTypeBaseName(node: Parser.SyntaxNode): string | null {
+		switch (node.type) {
+			case 'type_identifier':
+			case 'identifier':
+			case 'primitive_type':
+				return node.text;
+			case 'scoped_type_identifier':
+			case 'scoped_identifier': {
+				const name = node.childForFieldName('name');
+				return name ? name.text : null;
+			}
+			case 'generic_type':
+			case 'reference_type':
+			case 'pointer_type': {
+				const inner = node.childForFieldName('type');
+				return inner ? this.extractRustTypeBaseName(inner) : null;
+			}
+			default: {
+				const typeId = this.findChildOfType(node, [
+					'type_identifier',
+					'identifier',
+				]);
+				return typeId ? typeId.text : null;
+			}
+		}
+	}
+
+	/**
+	 * Base type name of a Go method's receiver (`(g *Greeter)` -> `Greeter`).
+	 */
+	private extractGoReceiverType(
+		node: Parser.SyntaxNode,
+		lang: SupportedLanguage,
+	): string | null {
+		if (lang !== 'go' || node.type !== 'method_declaration') return null;
+		const receiver = node.childForFieldName('receiver');
+		const typeNode = receiver?.descendantsOfType('type_identifier')[0];
+		return typeNode ? typeNode.text : null;
+	}
+
+	/**
+	 * Owning table of a Lua method (`function Account:deposit()` ->
+	 * `Account`).
+	 */
+	private extractLuaMethodOwner(
+		node: Parser.SyntaxNode,
+		lang: SupportedLanguage,
+	): string | null {
+		if (lang !== 'lua') return null;
+		const name = node.childForFieldName('name');
+		if (name?.type !== 'method_index_expression') return null;
+		return name.childForFieldName('table')?.text ?? null;
+	}
+
+	/**
+	 * Macro an Elixir call invokes when its target is a plain identifier
+	 * (`def`, `defmodule`, `alias`, `doc` in `@doc`).
+	 */
+	private elixirCallName(node: Parser.SyntaxNode): string | null {
+		if (node.type !== 'call') return null;
+		const target = node.childForFieldName('target');
+		return target?.type === 'identifier' ? target.text : null;
+	}
+
+	/**
+	 * Name node of an Elixir definition: the module alias, or the function
+	 * name in its head (`def area(shape) when is_map(shape)` -> `area`).
+	 */
+	private extractElixirNameNode(
+		node: Parser.SyntaxNode,
+	): Parser.SyntaxNode | null {
+		const args = node.namedChildren.find(c => c.type === 'arguments');
+		let head = args?.namedChildren[0] ?? null;
+		if (head?.type === 'binary_operator') {
+			head = head.childForFieldName('left');
+		}
+		if (head?.type === 'call') {
+			head = head.childForFieldName('target');
+		}
+		return head;
+	}
+
+	/**
+	 * Module defined by `defimpl Proto, for: Type` (`Proto.Type`); without
+	 * `for:` the enclosing module is the implementing type.
+	 */
+	private extractElixirImplName(node: Parser.SyntaxNode): string | null {
+		const protocol = this.extractElixirNameNode(node)?.text;
+		if (!protocol) return null;
+		const args = node.namedChildren.find(c => c.type === 'arguments');
+		let type = args?.text.match(/\bfor:\s*([A-Z][\w.]*)/)?.[1] ?? null;
+		for (let p = node.parent; p && !type; p = p.parent) {
+			if (this.elixirCallName(p) === 'defmodule') {
+				type = this.extractElixirNameNode(p)?.text ?? null;
+			}
+		}
+		return type ? `${protocol}.${type}` : protocol;
+	}
+
+	/**
+	 * Name and value of an Elixir module attribute (`@doc "..."`), or null
+	 * for other nodes.
+	 */
+	private extractElixirAttribute(
+		node: Parser.SyntaxNode,
+	): {name: string; value: Parser.SyntaxNode | null} | null {
+		if (node.type !== 'unary_operator' || !node.text.startsWith('@')) {
+			return null;
+		}
+		const operand = node.childForFieldName('operand');
+		if (!operand) return null;
+		if (operand.type === 'identifier') {
+			return {name: operand.text, value: null};
+		}
+		const name = this.elixirCallName(operand);
+		if (!name) return null;
+		const args = operand.namedChildren.find(c => c.type === 'arguments');
+		return {name, value: args?.namedChildren[0] ?? null};
+	}
+
+	/**
+	 * Text of an Elixir `@doc` / `@moduledoc` value; `@doc false` hides the
+	 * definition and has none.
+	 */
+	private elixirDocText(value: Parser.SyntaxNode | null): string | null {
+		if (!value || value.type === 'boolean') return null;
+		const text = value.text
+			.replace(/^~[a-zA-Z]/, '')
+			.replace(/^("""|'''|"|')([\s\S]*)\1$/, '$2');
+		return text.trim() || null;
+	}
+
+	/**
+	 * Elixir calls that are not function calls: definition macros (`def`,
+	 * `defmodule`), function heads and module attributes (`@doc`).
+	 */
+	private isElixirDefinitionCall(node: Parser.SyntaxNode): boolean {
+		const name = this.elixirCallName(node) ?? '';
+		if (ELIXIR_MODULE_CALLS.has(name) || ELIXIR_FUNCTION_CALLS.has(name)) {
+			return true;
+		}
+		const parent = node.parent;
+		if (parent?.type === 'unary_operator' && parent.text.startsWith('@')) {
+			return true;
+		}
+		// `def area(shape) when is_map(shape)`: the head is the guard's left side
+		const head =
+			parent?.type === 'binary_operator' &&
+			parent.childForFieldName('left')?.equals(node)
+				? parent
+				: node;
+		const args = head.parent;
+		if (args?.type !== 'arguments' || !args.namedChildren[0]?.equals(head)) {
+			return false;
+		}
+		return (
+			!!args.parent &&
+			ELIXIR_FUNCTION_CALLS.has(this.elixirCallName(args.parent) ?? '')
+		);
+	}
+
+	/**
+	 * C/C++ struct/class/union/enum specifiers also appear as type references
+	 * (`struct Point *p`); only those with a body define the type. Elixir
+	 * modules are `defmodule`/`defprotocol`/`defimpl` calls.
+	 */
+	private isTypeDefinition(
+		node: Parser.SyntaxNode,
+		lang: SupportedLanguage,
+	): boolean {
+		if (lang === 'elixir') {
+			return ELIXIR_MODULE_CALLS.has(this.elixirCallName(node) ?? '');
+		}
+		if (lang !== 'c' && lang !== 'cpp') return true;
+		return node.childForFieldName('body') !== null;
+	}
+
+	/**
+	 * Whether a function/method node type really declares a function. C/C++
+	 * `declaration` / `field_declaration` nodes only do so when their
+	 * declarator is a function declarator (prototypes); Elixir calls when
+	 * they invoke `def`/`defp`/`defmacro`.
+	 */
+	private isCallableNode(
+		node: Parser.SyntaxNode,
+		lang: SupportedLanguage,
+	): boolean {
+		if (lang === 'elixir') {
+			return ELIXIR_FUNCTION_CALLS.has(this.elixirCallName(node) ?? '');
+		}
+		if (lang !== 'c' && lang !== 'cpp') return true;
+		if (!C_PROTOTYPE_NODE_TYPES.has(node.type)) return true;
+		return this.extractCFunctionDeclarator(node, lang) !== null;
+	}
+
+	/**
+	 * The function declarator of a C/C++ function definition or prototype,
+	 * looking through pointer/reference return types (`char *name(void)`).
+	 * Function pointers (`int (*cb)(int)`) and friend declarations are not
+	 * functions of their own.
+	 */
+	private extractCFunctionDeclarator(
+		node: Parser.SyntaxNode,
+		lang: SupportedLanguage,
+	): Parser.SyntaxNode | null {
+		if (lang !== 'c' && lang !== 'cpp') return null;
+		if (node.parent?.type === 'friend_declaration') return null;
+		let declarator = node.childForFieldName('declarator');
+		while (
+			declarator &&
+			(declarator.type === 'pointer_declarator' ||
+				declarator.type === 'reference_declarator')
+		) {
+			declarator =
+				declarator.childForFieldName('declarator') ??
+				declarator.namedChildren.find(c => c.type.endsWith('declarator')) ??
+				null;
+		}
+		if (declarator?.type !== 'function_declarator') return null;
+		const inner = declarator.childForFieldName('declarator');
+		if (inner?.type === 'parenthesized_declarator') return null;
+		return declarator;
+	}
+
+	/**
+	 * Name node of a C/C++ declarator: the innermost identifier, and the last
+	 * segment of a qualified name (`geo::Shape::area` -> `area`).
+	 */
+	private extractCDeclaratorNameNode(
+		declarator: Parser.SyntaxNode,
+	): Parser.SyntaxNode | null {
+		let current: Parser.SyntaxNode | null = declarator;
+		while (current) {
+			if (C_DECLARATOR_NAME_NODE_TYPES.has(current.type)) return current;
+			if (
+				current.type === 'qualified_identifier' ||
+				current.type === 'template_function'
+			) {
+				current = current.childForFieldName('name');
+				continue;
+			}
+			current =
+				current.childForFieldName('declarator') ??
+				current.namedChildren.find(
+					c =>
+						c.type.endsWith('declarator') ||
+						C_DECLARATOR_NAME_NODE_TYPES.has(c.type) ||
+						c.type === 'qualified_identifier',
+				) ??
+				null;
+		}
+		return null;
+	}
+
+	/**
+	 * Scope segments of a qualified C++ function name, outermost first
+	 * (`void geo::Shape::area()` -> `['geo', 'Shape']`).
+	 */
+	private extractCppQualifiedScope(
+		node: Parser.SyntaxNode,
+		lang: SupportedLanguage,
+	): string[] {
+		if (lang !== 'cpp') return [];
+		const declarator = this.extractCFunctionDeclarator(node, lang);
+		let name = declarator?.childForFieldName('declarator') ?? null;
+		const scope: string[] = [];
+		while (name?.type === 'qualified_identifier') {
+			const segment = name.childForFieldName('scope');
+			const segmentName =
+				segment?.type === 'template_type'
+					? segment.childForFieldName('name')?.text
+					: segment?.text;
+			if (segmentName) scope.push(segmentName);
+			name = name.childForFieldName('name');
+		}
+		return scope;
+	}
+
+	/**
+	 * Owning class of a C++ method defined out of line (`void Foo::bar()`
+	 * -> `Foo`).
+	 */
+	private extractCppScopeOwner(
+		node: Parser.SyntaxNode,
+		lang: SupportedLanguage,
+	): string | null {
+		return this.extractCppQualifiedScope(node, lang).pop() ?? null;
+	}
+
+	/**
+	 * `::`-joined C++ namespaces enclosing a definition, plus the namespace
+	 * qualifiers of an out-of-line definition (`void geo::Shape::area()`
+	 * -> `geo`). Anonymous namespaces are skipped. Ruby and Elixir nest
+	 * modules instead; Lua functions are namespaced by their table.
+	 */
+	private extractNamespacePath(
+		node: Parser.SyntaxNode,
+		lang: SupportedLanguage,
+	): string | null {
+		if (lang === 'ruby' || lang === 'elixir') {
+			return this.extractEnclosingModulePath(node, lang);
+		}
+		if (lang === 'lua') {
+			const name = node.childForFieldName('name');
+			return name?.type === 'dot_index_expression'
+				? (name.childForFieldName('table')?.text ?? null)
+				: null;
+		}
+		if (lang !== 'cpp') return null;
+		const segments: string[] = [];
+		for (let p = node.parent; p; p = p.parent) {
+			if (p.type !== 'namespace_definition') continue;
+			const name = p.childForFieldName('name');
+			if (name) segments.unshift(name.text.replace(/\s+/g, ''));
+		}
+		segments.push(...this.extractCppQualifiedScope(node, lang).slice(0, -1));
+		return segments.length > 0 ? segments.join('::') : null;
+	}
+
+	/**
+	 * Ruby (`::`-joined) / Elixir (`.`-joined) modules and classes enclosing
+	 * a definition. A member's owner is its class, not part of its namespace.
+	 */
+	private extractEnclosingModulePath(
+		node: Parser.SyntaxNode,
+		lang: SupportedLanguage,
+	): string | null {
+		const isContainer = (n: Parser.SyntaxNode) =>
+			CLASS_NODE_TYPES[lang].includes(n.type) && this.isTypeDefinition(n, lang);
+		const segments: string[] = [];
+		for (let p = node.parent; p; p = p.parent) {
+			if (!isContainer(p)) continue;
+			const name = this.extractName(p, lang);
+			if (name) segments.unshift(name);
+		}
+		if (!isContainer(node)) segments.pop();
+		return segments.length > 0
+			? segments.join(lang === 'ruby' ? '::' : '.')
+			: null;
+	}
+
+	/**
+	 * Last row covered by a node. C preprocessor directives include their
+	 * trailing newline, so they end at column 0 of the following row.
+	 */
+	private lastRow(node: Parser.SyntaxNode): number {
+		const {row, column} = node.endPosition;
+		return column === 0 && row > node.startPosition.row ? row - 1 : row;
+	}
+
+	/**
+	 * Supertypes declared by a class-like node: `extends`/`implements`
+	 * clauses, base lists, Python bases, Swift conformances and Rust
+	 * supertraits. Names are reduced to their base identifier
+	 * (`pkg.Base<T>` -> `Base`).
+	 */
+	private extractSupertypes(
+		node: Parser.SyntaxNode,
+		lang: SupportedLanguage,
+	): string[] {
+		if (!CLASS_NODE_TYPES[lang].includes(node.type)) return [];
+
+		// Elixir: the protocol of a `defimpl`, `@behaviour`s of a module
+		if (lang === 'elixir') {
+			if (!this.isTypeDefinition(node, lang)) return [];
+			const names: string[] = [];
+			if (this.elixirCallName(node) === 'defimpl') {
+				const protocol = this.extractElixirNameNode(node)?.text;
+				if (protocol) names.push(protocol);
+			}
+			const block = node.namedChildren.find(c => c.type === 'do_block');
+			for (const child of block?.namedChildren ?? []) {
+				const attribute = this.extractElixirAttribute(child);
+				if (attribute?.name === 'behaviour' && attribute.value) {
+					names.push(attribute.value.text);
+				}
+			}
+			return this.uniqueStable(
+				names.map(n => n.split('.').pop() ?? n).filter(Boolean),
+			);
+		}
+
+		if (lang === 'rust') {
+			const bounds =
+				node.type === 'trait_item' ? node.childForFieldName('bounds') : null;
+			if (!bounds) return [];
+			const names = bounds.namedChildren
+				.filter(c => c.type !== 'lifetime')
+				.map(c => this.extractRustTypeBaseName(c))
+				.filter((n): n is string => !!n);
+			return this.uniqueStable(names);
+		}
+
+		const clauseTypes = SUPERTYPE_CLAUSE_NODE_TYPES[lang] ?? [];
+		const names: Parser.SyntaxNode[] = [];
+		for (const child of node.namedChildren) {
+			if (clauseTypes.includes(child.type)) {
+				this.collectSupertypeNameNodes(child, names);
+			}
+		}
+		return this.uniqueStable(names.map(n => n.text));
+	}
+
+	private collectSupertypeNameNodes(
+		clause: Parser.SyntaxNode,
+		out: Parser.SyntaxNode[],
+	): void {
+		for (const child of clause.namedChildren) {
+			if (SUPERTYPE_SKIP_NODE_TYPES.has(child.type)) continue;
+			if (SUPERTYPE_LIST_NODE_TYPES.has(child.type)) {
+				this.collectSupertypeNameNodes(child, out);
+				continue;
+			}
+			const name = this.supertypeBaseNameNode(child);
+			if (name) out.push(name);
+		}
+	}
+
+	/**
+	 * Rightmost identifier of a type expression, ignoring generic and
+	 * constructor arguments (`a.b.C<T>` -> `C`, `Base(1)` -> `Base`).
+	 */
+	private supertypeBaseNameNode(
+		node: Parser.SyntaxNode,
+	): Parser.SyntaxNode | null {
+		if (SUPERTYPE_NAME_NODE_TYPES.has(node.type)) return node;
+		// Python `Generic[T]`: the base is the subscripted value.
+		if (node.type === 'subscript') {
+			const value = node.childForFieldName('value');
+			return value ? this.supertypeBaseNameNode(value) : null;
+		}
+		let last: Parser.SyntaxNode | null = null;
+		for (const child of node.namedChildren) {
+			if (SUPERTYPE_SKIP_NODE_TYPES.has(child.type)) continue;
+			last = this.supertypeBaseNameNode(child) ?? last;
+		}
+		return last;
+	}
+
+	/**
+	 * Kind of a name ref from its place in the tree: `extends`/`implements`
+	 * for the base name of an inheritance clause entry, `type_ref` in type
+	 * positions (annotations, generic arguments, parameter and return
+	 * types), else `identifier`.
+	 */
+	private classifyNameRef(
+		node: Parser.SyntaxNode,
+		lang: SupportedLanguage,
+	): 'identifier' | 'type_ref' | 'extends' | 'implements' {
+		let typePosition = node.type === 'type_identifier';
+		const path: Parser.SyntaxNode[] = [];
+		let child = node;
+		for (let depth = 0; depth < MAX_REF_CONTEXT_DEPTH; depth++) {
+			const parent = child.parent;
+			if (!parent) break;
+			const bases = this.supertypeNameNodesAt(parent, child, lang);
+			if (bases) {
+				if (bases.some(b => b.equals(node))) {
+					return this.supertypeRefKind(parent, path, node.text, lang);
+				}
+				break;
+			}
+			const typeFields = TYPE_FIELD_NAMES.map(f =>
+				parent.childForFieldName(f),
+			);
+			if (
+				TYPE_CONTEXT_NODE_TYPES.has(parent.type) ||
+				typeFields.some(f => f?.equals(child))
+			) {
+				typePosition = true;
+			}
+			path.push(parent);
+			child = parent;
+		}
+		return typePosition ? 'type_ref' : 'identifier';
+	}
+
+	/**
+	 * Whether an identifier is read or written: the name (or the member
+	 * access or element it leads to) as an assignment or declaration
+	 * target, inside a destructuring pattern, `++`/`--` operand, Rust
+	 * `&mut` borrow or struct field initialiser.
+	 */
+	private classifyAccess(node: Parser.SyntaxNode): RefAccess {
+		let target = node;
+		for (let depth = 0; depth < MAX_REF_CONTEXT_DEPTH; depth++) {
+			const parent = target.parent;
+			if (!parent) break;
+			if (ASSIGNMENT_NODE_TYPES.has(parent.type)) {
+				return this.assignmentAccess(parent, target);
+			}
+			if (UPDATE_NODE_TYPES.has(parent.type)) {
+				const updates = parent.children.some(
+					c => c.text === '++' || c.text === '--',
+				);
+				return updates ? 'read_write' : 'read';
+			}
+			switch (parent.type) {
+				// Rust `&mut x`
+				case 'reference_expression':
+					return parent.children.some(c => c.type === 'mutable_specifier')
+						? 'read_write'
+						: 'read';
+				// Rust `Point { x: 1 }` and `Point { x }`
+				case 'field_initializer':
+					return parent.childForFieldName('field')?.equals(target)
+						? 'write'
+						: 'read';
+				case 'shorthand_field_initializer':
+					return 'read_write';
+				// Go `Point{X: 1}` (map literal keys are reads)
+				case 'keyed_element': {
+					const literalType =
+						parent.parent?.parent?.childForFieldName('type');
+					return parent.namedChild(0)?.equals(target) &&
+						literalType?.type !== 'map_type'
+						? 'write'
+						: 'read';
+				}
+			}
+			// Climb from a member to its access (`obj.count`) and from a
+			// container to its element (`counts[k]`), not from `obj` or `k`
+			const member =
+				parent.childForFieldName('property') ??
+				parent.childForFieldName('field') ??
+				parent.childForFieldName('attribute') ??
+				parent.childForFieldName('name');
+			const last = parent.namedChild(parent.namedChildCount - 1);
+			const assignable =
+				ASSIGNABLE_WRAPPER_NODE_TYPES.has(parent.type) ||
+				member?.equals(target) ||
+				// Kotlin `a.b = 1`: the assignable is the last part
+				(parent.type === 'directly_assignable_expression' &&
+					last?.equals(target)) ||
+				(SUBSCRIPT_NODE_TYPES.has(parent.type) &&
+					parent.namedChild(0)?.equals(target));
+			if (!assignable) break;
+			target = parent;
+		}
+		return 'read';
+	}
+
+	/**
+	 * The name of a class field declared with an initialiser; indexed as a
+	 * write even though it is also the field's definition.
+	 */
+	private isInitializedFieldName(node: Parser.SyntaxNode): boolean {
+		const declaration =
+			node.parent?.type === 'variable_declarator'
+				? node.parent.parent
+				: node.parent;
+		return (
+			!!declaration &&
+			FIELD_DECLARATION_NODE_TYPES.has(declaration.type) &&
+			this.classifyAccess(node) === 'write'
+		);
+	}
+
+	/**
+	 * Access of `target` under an assignment node: `write` for the target
+	 * of `=`/`:=`, `read_write` for compound operators, `read` for the
+	 * assigned value and declarations without one.
+	 */
+	private assignmentAccess(
+		assignment: Parser.SyntaxNode,
+		target: Parser.SyntaxNode,
+	): RefAccess {
+		const fieldTarget = ASSIGNMENT_TARGET_FIELDS.map(f =>
+			assignment.childForFieldName(f),
+		).find(n => n != null);
+		const lhs = fieldTarget ?? assignment.namedChild(0);
+		if (!lhs?.equals(target)) return 'read';
+		const operator = assignment.children.find(
+			c =>
+				!c.isNamed &&
+				c.type.endsWith('=') &&
+				!COMPARISON_OPERATORS.has(c.type),
+		);
+		if (!operator) {
+			// C# `int x = 1` keeps its `=` inside an equals_value_clause
+			const initialized = assignment.namedChildren.some(
+				c => c.type === 'equals_value_clause',
+			);
+			return initialized ? 'write' : 'read';
+		}
+		return operator.type === '=' || operator.type === ':='
+			? 'write'
+			: 'read_write';
+	}
+
+	/**
+	 * Base-name nodes of the supertypes that `child` declares for the
+	 * class-like `parent`; null when `child` is not a supertype clause.
+	 */
+	private supertypeNameNodesAt(
+		parent: Parser.SyntaxNode,
+		child: Parser.SyntaxNode,
+		lang: SupportedLanguage,
+	): Parser.SyntaxNode[] | null {
+		if (!CLASS_NODE_TYPES[lang].includes(parent.type)) return null;
+
+		// Rust: `impl Trait for Type` and supertraits (`trait A: B + C`)
+		if (lang === 'rust') {
+			const clause =
+				parent.type === 'impl_item'
+					? parent.childForFieldName('trait')
+					: parent.type === 'trait_item'
+						? parent.childForFieldName('bounds')
+						: null;
+			if (!clause?.equals(child)) return null;
+			const entries =
+				parent.type === 'trait_item'
+					? child.namedChildren.filter(c => c.type !== 'lifetime')
+					: [child];
+			return entries
+				.map(e => this.supertypeBaseNameNode(e))
+				.filter((n): n is Parser.SyntaxNode => n !== null);
+		}
+
+		if (!SUPERTYPE_CLAUSE_NODE_TYPES[lang]?.includes(child.type)) return null;
+		const names: Parser.SyntaxNode[] = [];
+		this.collectSupertypeNameNodes(child, names);
+		return names;
+	}
+
+	/**
+	 * Whether a supertype is implemented or extended: interface clauses
+	 * (`implements`), Rust trait impls, Kotlin supertypes without a
+	 * constructor call, C# `I`-prefixed bases and Swift struct conformances
+	 * are implemented; interfaces and traits extend their supertypes.
+	 */
+	private supertypeRefKind(
+		classNode: Parser.SyntaxNode,
+		path: Parser.SyntaxNode[],
+		name: string,
+		lang: SupportedLanguage,
+	): 'extends' | 'implements' {
+		if (path.some(n => IMPLEMENTS_CLAUSE_NODE_TYPES.has(n.type))) {
+			return 'implements';
+		}
+		if (INTERFACE_NODE_TYPES.has(classNode.type)) return 'extends';
+		switch (lang) {
+			case 'rust':
+				return 'implements';
+			case 'kotlin':
+				return path.some(n => n.type === 'constructor_invocation')
+					? 'extends'
+					: 'implements';
+			case 'csharp':
+				return /^I[A-Z]/.test(name) ? 'implements' : 'extends';
+			case 'swift':
+				return classNode.type === 'struct_declaration'
+					? 'implements'
+					: 'extends';
+			default:
+				return 'extends';
+		}
+	}
+
+	/**
+	 * Type named by an object construction: `new Foo()` (JS/TS, Java, C#,
+	 * PHP, Scala), Rust `Foo { .. }`, Go `Foo{...}`, Ruby `Foo.new` and
+	 * PascalCase calls where classes are called (`Foo(...)`). Returns the
+	 * written type expression and its base name node.
+	 */
+	private extractInstantiatedNode(
+		node: Parser.SyntaxNode,
+		lang: SupportedLanguage,
+	): {type: Parser.SyntaxNode; name: Parser.SyntaxNode} | null {
+		let type: Parser.SyntaxNode | null = null;
+		switch (node.type) {
+			case 'new_expression':
+				type = node.childForFieldName('constructor');
+				break;
+			case 'object_creation_expression':
+				type =
+					node.childForFieldName('type') ??
+					node.namedChildren.find(
+						c => c.type === 'name' || c.type === 'qualified_name',
+					) ??
+					null;
+				break;
+			case 'instance_creation_expression':
+				type = node.namedChild(0);
+				break;
+			case 'struct_expression':
+				type = lang === 'rust' ? node.childForFieldName('name') : null;
+				break;
+			case 'composite_literal': {
+				const literalType = node.childForFieldName('type');
+				type =
+					lang === 'go' && GO_NAMED_TYPE_NODE_TYPES.has(literalType?.type ?? '')
+						? literalType
+						: null;
+				break;
+			}
+			case 'call':
+			case 'call_expression':
+				if (lang === 'ruby') {
+					const isNew = node.childForFieldName('method')?.text === 'new';
+					type = isNew ? node.childForFieldName('receiver') : null;
+				} else if (CALL_CONSTRUCTOR_LANGUAGES.has(lang)) {
+					const called = this.extractCalledNameNode(node);
+					if (called && PASCAL_CASE_RE.test(called.text)) {
+						type = this.extractCalleeNode(node);
+					}
+				}
+				break;
+		}
+		if (!type) return null;
+		const name = this.supertypeBaseNameNode(type);
+		if (!name || !this.isIdentifierLike(name.text)) return null;
+		return {type, name};
+	}
+
+	/**
+	 * Traits named in `#[derive(...)]` attributes of a Rust struct/enum/union
+	 * (`serde::Serialize` -> `Serialize`).
+	 */
+	private extractRustDerives(
+		node: Parser.SyntaxNode,
+		lang: SupportedLanguage,
+	): string[] {
+		if (lang !== 'rust') return [];
+		if (!RUST_DERIVE_NODE_TYPES.has(node.type)) return [];
+
+		const derives: string[] = [];
+		let sibling = node.previousSibling;
+		while (sibling) {
+			if (sibling.type === 'attribute_item') {
+				const attr = this.findChildOfType(sibling, ['attribute']);
+				const path = attr
+					? this.findChildOfType(attr, ['identifier', 'scoped_identifier'])
+					: null;
+				const args = attr
+					? (attr.childForFieldName('arguments') ??
+						this.findChildOfType(attr, ['token_tree']))
+					: null;
+				if (path?.text === 'derive' && args) {
+					const names = args.text
+						.replace(/^\(|\)$/g, '')
+						.split(',')
+						.map(p => p.split('::').pop()?.trim() ?? '')
+						.filter(n => this.isIdentifierLike(n));
+					derives.unshift(...names);
+				}
+			} else if (
+				sibling.type !== 'line_comment' &&
+				sibling.type !== 'block_comment'
+			) {
+				break;
+			}
+			sibling = sibling.previousSibling;
+		}
+		return this.uniqueStable(derives);
+	}
+
+	/**
+	 * Analyze a Vue/Svelte/Astro single-file component. Scripts are parsed as
+	 * TypeScript/TSX in place (see sfc.ts), every section is also a block
+	 * chunk, and the component itself is a class named after the file.
+	 * Returns null when the script grammar is unavailable.
+	 */
+	private analyzeSfcFile(
+		filepath: string,
+		content: string,
+		ext: string,
+		options: {
+			chunkMaxSize: number;
+			definitionMaxChunkSize: number;
+			refs: RefExtractionOptions;
+		},
+	): AnalyzedFile | null {
+		const sections = splitSfc(content, ext);
+		const lang = sfcScriptLanguage(sections);
+		const language = this.languages.get(lang);
+		if (!language) return null;
+
+		const lines = content.split('\n');
+		const definition_chunks = [
+			this.createComponentChunk(filepath, content, lines),
+		];
+		let scriptChunks: Chunk[] = [];
+		let refs: ExtractedRef[] = [];
+
+		if (sections.some(s => s.kind === 'script')) {
+			const masked = maskToScripts(content, sections);
+			this.parser!.setLanguage(language);
+			const tree = this.parser!.parse(masked);
+			if (tree) {
+				definition_chunks.push(
+					...this.extractChunks(
+						tree.rootNode,
+						masked,
+						lang,
+						filepath,
+						options.definitionMaxChunkSize,
+					),
+				);
+				scriptChunks = this.extractChunks(
+					tree.rootNode,
+					masked,
+					lang,
+					filepath,
+					options.chunkMaxSize,
+				);
+				refs = this.extractRefsFromTree(tree.rootNode, lang, options.refs);
+			}
+		}
+
+		// Template usages of other components (`<CounterButton />`)
+		if ((options.refs.identifier_mode ?? 'symbolish') !== 'none') {
+			for (const tag of findComponentTags(content, sections)) {
+				const line = lineAt(content, tag.start) + 1;
+				refs.push({
+					ref_kind: 'identifier',
+					token_texts: [tag.name],
+					start_line: line,
+					end_line: line,
+					start_byte: tag.start,
+					end_byte: tag.end,
+					module_name: null,
+					imported_name: null,
+				});
+			}
+		}
+		const maxOccurrencesPerToken = options.refs.max_occurrences_per_token ?? 0;
+		refs = this.dedupeRefs(refs);
+		if (maxOccurrencesPerToken > 0) {
+			refs = this.limitRefsPerToken(refs, maxOccurrencesPerToken);
+		}
+
+		const sectionChunks = sections.map(section =>
+			this.createSectionChunk(
+				filepath,
+				lines,
+				lineAt(content, section.blockStart) + 1,
+				lineAt(content, section.blockEnd) + 1,
+			),
+		);
+
+		return {
+			language: lang,
+			parse_status: 'parsed',
+			definition_chunks,
+			chunks: [
+				...this.enforceSizeLimits(
+					sectionChunks,
+					options.chunkMaxSize,
+					content,
+					lang,
+					filepath,
+					DEFAULT_OVERLAP_LINES,
+				),
+				...this.enforceSizeLimits(
+					scriptChunks,
+					options.chunkMaxSize,
+					content,
+					lang,
+					filepath,
+				),
+			],
+			refs,
+		};
+	}
+
+	/**
+	 * Class chunk for a single-file component, spanning the whole file. A
+	 * leading HTML comment is its doc.
+	 */
+	private createComponentChunk(
+		filepath: string,
+		content: string,
+		lines: string[],
+	): Chunk {
+		const name = componentNameFromPath(filepath);
+		const contextHeader = this.buildContextHeader(filepath, null, name, false);
+		const docstring =
+			content.match(/^\s*<!--([\s\S]*?)-->/)?.[1]?.trim() || null;
+		const tokenFacts = this.extractFallbackTokenFacts(content);
+		return {
+			text: content,
+			contextHeader,
+			type: 'class',
+			name,
+			startLine: 1,
+			endLine: lines.length,
+			startByte: 0,
+			endByte: content.length,
+			contentHash: computeStringHash(`${contextHeader}\n${content}`),
+			signature: null,
+			docstring,
+			isExported: true, // The component is the file's default export
+			visibility: 'public',
+			decoratorNames: null,
+			implTypeName: null,
+			implTraitName: null,
+			supertypes: [],
+			derives: [],
+			isTest: isTestFilePath(filepath),
+			isDocTest: false,
+			identifiers: tokenFacts.identifiers,
+			identifierParts: tokenFacts.identifierParts,
+			calledNames: tokenFacts.calledNames,
+			stringLiterals: tokenFacts.stringLiterals,
+		};
+	}
+
+	/**
+	 * Module-level chunk for one section of a single-file component or one
+	 * notebook cell. `label` is appended to the context header.
+	 */
+	private createSectionChunk(
+		filepath: string,
+		lines: string[],
+		startLine: number,
+		endLine: number,
+		label: string | null = null,
+	): Chunk {
+		const text = lines.slice(startLine - 1, endLine).join('\n');
+		const header = this.buildContextHeader(filepath, null, null, false);
+		const contextHeader = label ? `${header}, ${label}` : header;
+		const tokenFacts = this.extractFallbackTokenFacts(text);
+		return {
+			text,
+			contextHeader,
+			type: 'module',
+			name: '',
+			startLine,
+			endLine,
+			startByte: null,
+			endByte: null,
+			contentHash: computeStringHash(`${contextHeader}\n${text}`),
+			signature: null,
+			docstring: null,
+			isExported: true,
+			visibility: 'public',
+			decoratorNames: null,
+			implTypeName: null,
+			implTraitName: null,
+			supertypes: [],
+			derives: [],
+			isTest: isTestFilePath(filepath),
+			isDocTest: false,
+			identifiers: tokenFacts.identifiers,
+			identifierParts: tokenFacts.identifierParts,
+			calledNames: tokenFacts.calledNames,
+			stringLiterals: tokenFacts.stringLiterals,
+		};
+	}
+
+	/**
+	 * Analyze a Jupyter notebook. Code cells are parsed together with the
+	 * kernel language's grammar; every code or raw cell becomes its own
+	 * chunk and markdown cells are split into heading-aware sections. Lines
+	 * are lines of the `.ipynb` file. Returns null when the notebook's cells
+	 * cannot be mapped to file lines.
+	 */
+	private analyzeNotebookFile(
+		filepath: string,
+		content: string,
+		options: {
+			chunkMaxSize: number;
+			definitionMaxChunkSize: number;
+			refs: RefExtractionOptions;
+		},
+	): AnalyzedFile | null {
+		const notebook = parseNotebook(content);
+		if (!notebook) return null;
+
+		const kernel = notebook.language ?? 'python';
+		const lang = this.languages.has(kernel as SupportedLanguage)
+			? (kernel as SupportedLanguage)
+			: null;
+		const view = notebookSourceView(notebook);
+		const lines = view.split('\n');
+
+		let definition_chunks: Chunk[] = [];
+		let refs: ExtractedRef[] = [];
+		if (lang) {
+			const code = notebookCodeView(notebook);
+			this.parser!.setLanguage(this.languages.get(lang)!);
+			const tree = this.parser!.parse(code);
+			if (tree) {
+				definition_chunks = this.extractChunks(
+					tree.rootNode,
+					code,
+					lang,
+					filepath,
+					options.definitionMaxChunkSize,
+				);
+				refs = this.extractRefsFromTree(tree.rootNode, lang, options.refs);
+				const maxOccurrencesPerToken =
+					options.refs.max_occurrences_per_token ?? 0;
+				refs = this.dedupeRefs(refs);
+				if (maxOccurrencesPerToken > 0) {
+					refs = this.limitRefsPerToken(refs, maxOccurrencesPerToken);
+				}
+			}
+		}
+
+		const cells = notebook.cells.filter(cell =>
+			cell.source.some(text => text.trim()),
+		);
+		const cellChunks = cells
+			.filter(cell => cell.cellType !== 'markdown')
+			.map(cell =>
+				this.createSectionChunk(
+					filepath,
+					lines,
+					cell.startLine,
+					cell.endLine,
+					`Cell: ${cell.index + 1}`,
+				),
+			);
+		const markdownChunks = cells
+			.filter(cell => cell.cellType === 'markdown')
+			.flatMap(cell =>
+				this.chunkNotebookMarkdownCell(filepath, cell, options.chunkMaxSize),
+			);
+
+		return {
+			language: lang,
+			parse_status: lang ? 'parsed' : 'unsupported',
+			definition_chunks,
+			chunks: [
+				...this.enforceSizeLimits(
+					cellChunks,
+					options.chunkMaxSize,
+					view,
+					lang ?? 'python',
+					filepath,
+					DEFAULT_OVERLAP_LINES,
+				),
+				...markdownChunks,
+			].sort((a, b) => a.startLine - b.startLine),
+			refs,
+		};
+	}
+
+	/**
+	 * Heading-aware sections of a notebook markdown cell, as `.md` files
+	 * get, at the cell's lines in the `.ipynb` file.
+	 */
+	private chunkNotebookMarkdownCell(
+		filepath: string,
+		cell: NotebookCell,
+		maxChunkSize: number,
+	): Chunk[] {
+		const offset = cell.startLine - 1;
+		const sections = this.chunkMarkdown(
+			filepath,
+			cell.source.join('\n'),
+			maxChunkSize,
+		);
+		return sections.map(section => {
+			const contextHeader = `${section.contextHeader}, Cell: ${cell.index + 1}`;
+			return {
+				...section,
+				contextHeader,
+				startLine: section.startLine + offset,
+				endLine: section.endLine + offset,
+				startByte: null,
+				endByte: null,
+				contentHash: computeStringHash(`${contextHeader}\n${section.text}`),
+			};
+		});
+	}
+
+	/**
+	 * Analyze a file from its outline (schema and infrastructure files, no
+	 * tree-sitter grammar).
+	 * Outline definitions become definition chunks; the file itself is
+	 * chunked as text.
+	 */
+	private analyzeOutline(
+		filepath: string,
+		content: string,
+		outline: FileOutline,
+		options: {chunkMaxSize: number; refs?: RefExtractionOptions},
+	): AnalyzedFile {
+		const lines = content.split('\n');
+		const index = new LineIndex(content);
+		const definition_chunks = outline.definitions.map(definition =>
+			this.outlineDefinitionToChunk(
+				filepath,
+				lines,
+				index,
+				definition,
+				outline.namespace,
+			),
+		);
+
+		const refsOptions = options.refs ?? {};
+		let refs: ExtractedRef[] = outline.refs
+			.filter(
+				ref =>
+					ref.kind !== 'identifier' || refsOptions.identifier_mode !== 'none',
+			)
+			.map(ref => {
+				const line = index.lineAt(ref.offset);
+				return {
+					ref_kind: ref.kind,
+					token_texts: [ref.token],
+					start_line: line,
+					end_line: line,
+					start_byte: ref.offset,
+					end_byte: ref.offset + ref.length,
+					module_name: ref.module_name,
+					imported_name: null,
+				};
+			});
+		refs = this.dedupeRefs(refs);
+		const maxOccurrencesPerToken = refsOptions.max_occurrences_per_token ?? 0;
+		if (maxOccurrencesPerToken > 0) {
+			refs = this.limitRefsPerToken(refs, maxOccurrencesPerToken);
+		}
+
+		return {
+			language: outline.language,
+			parse_status: 'parsed',
+			definition_chunks,
+			chunks: this.enforceSizeLimits(
+				[this.createModuleChunk(filepath, content)],
+				options.chunkMaxSize,
+				content,
+				'javascript', // Use any lang for splitting (line-based)
+				filepath,
+				DEFAULT_OVERLAP_LINES,
+			),
+			refs,
+		};
+	}
+
+	private outlineDefinitionToChunk(
+		filepath: string,
+		lines: string[],
+		index: LineIndex,
+		definition: OutlineDefinition,
+		namespace: string | null,
+	): Chunk {
+		const {startLine, endLine, label} = definition;
+		const text = lines.slice(startLine - 1, endLine).join('\n');
+		const header = this.buildContextHeader(
+			filepath,
+			definition.parent,
+			definition.parent || label ? null : definition.name,
+			false,
+			namespace,
+		);
+		const contextHeader = label ? `${header}, ${label}` : header;
+		const startByte = index.lineStart(startLine);
+		const tokenFacts = this.extractFallbackTokenFacts(text);
+
+		return {
+			text,
+			contextHeader,
+			type: definition.kind,
+			name: definition.name,
+			startLine,
+			endLine,
+			startByte,
+			endByte: startByte + text.length,
+			contentHash: computeStringHash(`${contextHeader}\n${text}`),
+			signature: definition.signature,
+			docstring: definition.docstring,
+			isExported: true,
+			visibility: 'public',
+			decoratorNames: null,
+			implTypeName: null,
+			implTraitName: null,
+			supertypes: [],
+			derives: [],
+			isTest: isTestFilePath(filepath),
+			isDocTest: false,
+			identifiers: tokenFacts.identifiers,
+			identifierParts: tokenFacts.identifierParts,
+			calledNames: tokenFacts.calledNames,
+			stringLiterals: tokenFacts.stringLiterals,
+		};
+	}
+
+	/**
+	 * Analyze a file of a language plugin. Definitions come from the
+	 * manifest's node types; names, signatures and docs are extracted
+	 * generically (no per-language metadata such as visibility).
+	 */
+	private analyzePluginFile(
+		filepath: string,
+		content: string,
+		{plugin, language}: PluginLanguage,
+		options: {chunkMaxSize: number; refs?: RefExtractionOptions},
+	): AnalyzedFile {
+		this.parser!.setLanguage(language);
+		const tree = this.parser!.parse(content);
+		if (!tree) {
+			const moduleChunk = this.createModuleChunk(filepath, content);
+			return {
+				language: plugin.name,
+				parse_status: 'parse_failed',
+				definition_chunks: [],
+				chunks: this.enforceSizeLimits(
+					[moduleChunk],
+					options.chunkMaxSize,
+					content,
+					'javascript', // Use any lang for splitting (line-based)
+					filepath,
+					DEFAULT_OVERLAP_LINES,
+				),
+				refs: [],
+			};
+		}
+
+		const lines = content.split('\n');
+		const definition_chunks: Chunk[] = [];
+		this.traversePluginNode(
+			tree.rootNode,
+			plugin,
+			lines,
+			definition_chunks,
+			filepath,
+			null,
+		);
+
+		const chunks =
+			definition_chunks.length > 0
+				? this.enforceSizeLimits(
+						definition_chunks,
+						options.chunkMaxSize,
+						content,
+						'javascript',
+						filepath,
+					)
+				: this.enforceSizeLimits(
+						[this.createModuleChunk(filepath, content)],
+						options.chunkMaxSize,
+						content,
+						'javascript',
+						filepath,
+						DEFAULT_OVERLAP_LINES,
+					);
+
+		return {
+			language: plugin.name,
+			parse_status: 'parsed',
+			definition_chunks,
+			chunks,
+			refs: this.extractPluginRefs(tree.rootNode, plugin, options.refs ?? {}),
+		};
+	}
+
+	/**
+	 * traverseNode for a language plugin's node-type mapping.
+	 */
+	private traversePluginNode(
+		node: Parser.SyntaxNode,
+		plugin: LanguagePlugin,
+		lines: string[],
+		chunks: Chunk[],
+		filepath: string,
+		parentClassName: string | null,
+	): void {
+		const nodeType = node.type;
+		let type: ChunkType | null = null;
+		if (plugin.classNodeTypes.includes(nodeType)) {
+			type = 'class';
+		} else if (parentClassName && plugin.methodNodeTypes.includes(nodeType)) {
+			type = 'method';
+		} else if (
+			!parentClassName &&
+			plugin.functionNodeTypes.includes(nodeType)
+		) {
+			type = 'function';
+		}
+
+		if (type) {
+			const chunk = this.pluginNodeToChunk(
+				node,
+				plugin,
+				lines,
+				type,
+				filepath,
+				type === 'method' ? parentClassName : null,
+			);
+			if (chunk) chunks.push(chunk);
+			if (type !== 'class') return;
+		}
+
+		const childClassName =
+			type === 'class'
+				? this.extractPluginNameNode(node, plugin)?.text.trim() || null
+				: parentClassName;
+		for (let i = 0; i < node.childCount; i++) {
+			const child = node.child(i);
+			if (child) {
+				this.traversePluginNode(
+					child,
+					plugin,
+					lines,
+					chunks,
+					filepath,
+					childClassName,
+				);
+			}
+		}
+	}
 
-		const mergeTokenTexts = (base: string[], extra: string[]): string[] => {
-			if (extra.length === 0) return base;
-			const seen = new Set(base);
-			const out = [...base];
-			for (const t of extra) {
-				if (!t) continue;
-				if (seen.has(t)) continue;
-				seen.add(t);
-				out.push(t);
-			}
-			return out;
-		};
+	private pluginNodeToChunk(
+		node: Parser.SyntaxNode,
+		plugin: LanguagePlugin,
+		lines: string[],
+		type: ChunkType,
+		filepath: string,
+		parentClassName: string | null,
+	): Chunk | null {
+		const name = this.extractPluginNameNode(node, plugin)?.text.trim() ?? '';
+		const startLine = node.startPosition.row + 1;
+		const endLine = this.lastRow(node) + 1;
+		const text = lines.slice(startLine - 1, endLine).join('\n');
+		if (!text.trim()) return null;
 
-		const byKey = new Map<string, {ref: ExtractedRef; order: number}>();
-		for (let i = 0; i < refs.length; i++) {
-			const ref = refs[i]!;
-			const primary = ref.token_texts[0] ?? '';
-			const key = `${ref.start_byte ?? ''}|${ref.end_byte ?? ''}|${primary}`;
-			const existing = byKey.get(key);
-			if (!existing) {
-				byKey.set(key, {ref, order: i});
-				continue;
-			}
+		const contextHeader = this.buildContextHeader(
+			filepath,
+			parentClassName,
+			parentClassName ? null : name,
+			false,
+		);
+		const fullText = `${contextHeader}\n${text}`;
+		// First line up to the body opener
+		const signature =
+			(lines[startLine - 1] ?? '').trim().replace(/\s*[{:=]?\s*$/, '') ||
+			null;
+		const tokenFacts = this.extractPluginTokenFacts(node, plugin);
 
-			const existingPriority = priority(existing.ref.ref_kind);
-			const nextPriority = priority(ref.ref_kind);
+		return {
+			text,
+			contextHeader,
+			type,
+			name,
+			startLine,
+			endLine,
+			startByte: node.startIndex,
+			endByte: node.endIndex,
+			contentHash: computeStringHash(fullText),
+			signature,
+			docstring: this.extractPluginDocstring(node, plugin),
+			isExported: true,
+			visibility: 'public',
+			decoratorNames: null,
+			implTypeName: null,
+			implTraitName: null,
+			supertypes: [],
+			derives: [],
+			isTest: isTestFilePath(filepath),
+			isDocTest: false,
+			identifiers: tokenFacts.identifiers,
+			identifierParts: tokenFacts.identifierParts,
+			calledNames: tokenFacts.calledNames,
+			stringLiterals: tokenFacts.stringLiterals,
+		};
+	}
 
-			if (nextPriority > existingPriority) {
-				byKey.set(key, {
-					ref: {
-						...ref,
-						token_texts: mergeTokenTexts(
-							ref.token_texts,
-							existing.ref.token_texts,
-						),
-					},
-					order: existing.order,
-				});
-			} else if (nextPriority === existingPriority) {
-				byKey.set(key, {
-					ref: {
-						...existing.ref,
-						token_texts: mergeTokenTexts(
-							existing.ref.token_texts,
-							ref.token_texts,
-						),
-					},
-					order: existing.order,
-				});
-			}
+	/**
+	 * The manifest's name field, else the definition's first identifier.
+	 */
+	private extractPluginNameNode(
+		node: Parser.SyntaxNode,
+		plugin: LanguagePlugin,
+	): Parser.SyntaxNode | null {
+		const field = node.childForFieldName(plugin.nameField);
+		if (field) {
+			return this.isIdentifierLike(field.text.trim())
+				? field
+				: this.findRightmostIdentifierNode(field);
 		}
-
-		return [...byKey.values()]
-			.sort((a, b) => a.order - b.order)
-			.map(v => v.ref);
+		return (
+			node.namedChildren.find(
+				c =>
+					this.isIdentifierNodeType(c.type) &&
+					this.isIdentifierLike(c.text.trim()),
+			) ?? null
+		);
 	}
 
-	private limitRefsPerToken(
-		refs: ExtractedRef[],
-		maxPerToken: number,
-	): ExtractedRef[] {
-		if (maxPerToken <= 0) return refs;
-		const counts = new Map<string, number>();
-		const out: ExtractedRef[] = [];
+	/**
+	 * Comments directly above a definition, limited to the manifest's doc
+	 * prefixes when it declares any.
+	 */
+	private extractPluginDocstring(
+		node: Parser.SyntaxNode,
+		plugin: LanguagePlugin,
+	): string | null {
+		const comments: string[] = [];
+		let nextRow = node.startPosition.row;
+		let sibling = node.previousNamedSibling;
+		while (
+			sibling &&
+			plugin.commentNodeTypes.includes(sibling.type) &&
+			sibling.endPosition.row >= nextRow - 1
+		) {
+			comments.unshift(sibling.text);
+			nextRow = sibling.startPosition.row;
+			sibling = sibling.previousNamedSibling;
+		}
 
-		for (const ref of refs) {
-			const primary = ref.token_texts[0] ?? '';
-			const key = `${ref.ref_kind}|${primary}`;
-			const prev = counts.get(key) ?? 0;
-			if (prev >= maxPerToken) continue;
-			counts.set(key, prev + 1);
-			out.push(ref);
+		const docLines: string[] = [];
+		for (const line of comments.join('\n').split('\n')) {
+			const trimmed = line.trim();
+			if (plugin.docCommentPrefixes.length > 0) {
+				const prefix = plugin.docCommentPrefixes.find(p =>
+					trimmed.startsWith(p),
+				);
+				if (prefix === undefined) continue;
+				docLines.push(trimmed.slice(prefix.length).trim());
+			} else {
+				docLines.push(
+					trimmed
+						.replace(/^(\/\/+|#+|--+|;+|\/\*+|\*+)\s?/, '')
+						.replace(/\s*\*+\/$/, ''),
+				);
+			}
 		}
 
-		return out;
+		const doc = docLines.join('\n').trim();
+		return doc || null;
 	}
 
-	private extractFallbackTokenFacts(text: string): TokenFacts {
+	private isPluginCallNode(
+		node: Parser.SyntaxNode,
+		plugin: LanguagePlugin,
+	): boolean {
+		return plugin.callNodeTypes.length > 0
+			? plugin.callNodeTypes.includes(node.type)
+			: this.isCallExpressionNodeType(node.type);
+	}
+
+	/**
+	 * extractAstTokenFacts with the plugin's call node types.
+	 */
+	private extractPluginTokenFacts(
+		node: Parser.SyntaxNode,
+		plugin: LanguagePlugin,
+	): TokenFacts {
 		const identifiers: string[] = [];
+		const calledNames: string[] = [];
 		const stringLiterals: string[] = [];
 
-		for (const match of text.matchAll(/[A-Za-z_][A-Za-z0-9_]*/g)) {
-			if (!match[0]) continue;
-			identifiers.push(match[0]);
-		}
-
-		for (const match of text.matchAll(/(['"])(?:(?=(\\?))\2.)*?\1/g)) {
-			if (!match[0]) continue;
-			const stripped = this.stripStringLiteral(match[0]);
-			if (stripped) stringLiterals.push(stripped.slice(0, 512));
-		}
+		const walk = (n: Parser.SyntaxNode) => {
+			if (plugin.commentNodeTypes.includes(n.type)) return;
+			if (this.isIdentifierNodeType(n.type)) {
+				const text = n.text.trim();
+				if (this.isIdentifierLike(text)) identifiers.push(text);
+			}
+			if (this.isStringLiteralNodeType(n.type)) {
+				const stripped = this.stripStringLiteral(n.text);
+				if (stripped) stringLiterals.push(stripped.slice(0, 512));
+			}
+			if (this.isPluginCallNode(n, plugin)) {
+				const callee = this.extractCalledNameNode(n)?.text.trim();
+				if (callee) calledNames.push(callee);
+			}
+			for (let i = 0; i < n.namedChildCount; i++) {
+				const child = n.namedChild(i);
+				if (child) walk(child);
+			}
+		};
+		walk(node);
 
 		const uniqueIdentifiers = this.uniqueStable(identifiers);
-		const identifierParts = this.uniqueStable(
-			uniqueIdentifiers.flatMap(id => this.splitIdentifierParts(id)),
-		);
-
 		return {
 			identifiers: uniqueIdentifiers,
-			identifierParts,
-			calledNames: [],
+			identifierParts: this.uniqueStable(
+				uniqueIdentifiers.flatMap(id => this.splitIdentifierParts(id)),
+			),
+			calledNames: this.uniqueStable(calledNames),
 			stringLiterals: this.uniqueStable(stringLiterals),
 		};
 	}
 
 	/**
-	 * Extract the name of a function/class/method from its node.
+	 * extractRefsFromTree for a language plugin. Import nodes name their
+	 * module by their first string literal, else by the text after the
+	 * keyword (`import geo.shapes` -> `geo.shapes`).
 	 */
-	private extractNameNode(
-		node: Parser.SyntaxNode,
-		_lang: SupportedLanguage,
-	): Parser.SyntaxNode | null {
-		// Try to get name via field first (works for many languages)
-		const nameField = node.childForFieldName('name');
-		if (nameField) {
-			return nameField;
-		}
+	private extractPluginRefs(
+		root: Parser.SyntaxNode,
+		plugin: LanguagePlugin,
+		options: RefExtractionOptions,
+	): ExtractedRef[] {
+		const identifierMode = options.identifier_mode ?? 'symbolish';
+		const includeStringLiterals = options.include_string_literals ?? false;
+		const includeKeyPathLiterals =
+			!includeStringLiterals && (options.include_key_path_literals ?? false);
+		const maxOccurrencesPerToken = options.max_occurrences_per_token ?? 0;
 
-		// Look for common identifier node types
-		for (let i = 0; i < node.childCount; i++) {
-			const child = node.child(i);
-			if (!child) continue;
+		const definitionNameRanges = new Set<string>();
+		const definitionTypes = new Set([
+			...plugin.functionNodeTypes,
+			...plugin.classNodeTypes,
+			...plugin.methodNodeTypes,
+		]);
 
-			// Common identifier types across languages
-			if (
-				child.type === 'identifier' ||
-				child.type === 'name' ||
-				child.type === 'simple_identifier' || // Kotlin, Swift
-				child.type === 'type_identifier' // Rust, Go struct types
-			) {
-				return child;
-			}
+		const refs: ExtractedRef[] = [];
+		const refAt = (
+			n: Parser.SyntaxNode,
+			ref_kind: ExtractedRef['ref_kind'],
+			token: string,
+			module_name: string | null = null,
+		): ExtractedRef => ({
+			ref_kind,
+			token_texts: [token],
+			start_line: n.startPosition.row + 1,
+			end_line: n.endPosition.row + 1,
+			start_byte: n.startIndex,
+			end_byte: n.endIndex,
+			module_name,
+			imported_name: null,
+		});
 
-			// JS/TS method names
-			if (child.type === 'property_identifier') {
-				return child;
+		const walk = (node: Parser.SyntaxNode) => {
+			if (plugin.commentNodeTypes.includes(node.type)) return;
+
+			if (definitionTypes.has(node.type)) {
+				const nameNode = this.extractPluginNameNode(node, plugin);
+				if (nameNode) {
+					definitionNameRanges.add(
+						`${nameNode.startIndex}|${nameNode.endIndex}`,
+					);
+				}
 			}
 
-			// Go type declarations (type Foo struct { })
-			if (child.type === 'type_spec') {
-				const specName = child.childForFieldName('name');
-				if (specName) {
-					return specName;
+			if (plugin.importNodeTypes.includes(node.type)) {
+				const literal = this.findFirstStringLiteralNode(node);
+				const moduleName = literal
+					? this.stripStringLiteral(literal.text)?.trim()
+					: node.text
+							.trim()
+							.replace(/^\S+\s+/, '')
+							.replace(/;$/, '')
+							.trim();
+				if (moduleName) {
+					// Paths name their file stem, dotted modules their last segment
+					const token = literal
+						? path.posix.basename(moduleName).replace(/\.[^.]*$/, '')
+						: moduleName.split(/[./\\:]+/).filter(Boolean).pop();
+					if (token) {
+						refs.push(refAt(node, 'import', token, moduleName));
+					}
 				}
+				return;
 			}
-		}
 
-		// For JS/TS variable declarations with arrow functions
-		if (node.parent?.type === 'variable_declarator') {
-			const varName = node.parent.childForFieldName('name');
-			if (varName) {
-				return varName;
+			if (this.isStringLiteralNodeType(node.type)) {
+				const stripped = this.stripStringLiteral(node.text);
+				if (includeStringLiterals && stripped?.trim()) {
+					refs.push(refAt(node, 'string_literal', stripped.slice(0, 512)));
+				}
+				const keyPath = includeKeyPathLiterals
+					? keyPathFromLiteral(stripped ?? '')
+					: null;
+				if (keyPath) refs.push(refAt(node, 'string_literal', keyPath));
+				return;
 			}
-		}
 
-		// For Rust impl blocks, try to get the type identifier node.
-		if (node.type === 'impl_item') {
-			const typeNode = node.childForFieldName('type');
-			if (typeNode) {
-				const typeId = this.findChildOfType(typeNode, [
-					'type_identifier',
-					'identifier',
-				]);
-				if (typeId) {
-					return typeId;
+			if (this.isPluginCallNode(node, plugin)) {
+				const calledNode = this.extractCalledNameNode(node);
+				if (calledNode) {
+					refs.push(refAt(calledNode, 'call', calledNode.text.trim()));
 				}
 			}
-		}
 
-		return null;
-	}
+			if (identifierMode !== 'none' && this.isIdentifierNodeType(node.type)) {
+				const text = node.text.trim();
+				if (
+					this.isIdentifierLike(text) &&
+					!definitionNameRanges.has(`${node.startIndex}|${node.endIndex}`) &&
+					(identifierMode === 'all' || this.isSymbolishIdentifier(text))
+				) {
+					refs.push(refAt(node, 'identifier', text));
+				}
+			}
 
-	private extractName(
-		node: Parser.SyntaxNode,
-		_lang: SupportedLanguage,
-	): string {
-		const nameNode = this.extractNameNode(node, _lang);
-		if (!nameNode) return '';
+			for (let i = 0; i < node.namedChildCount; i++) {
+				const child = node.namedChild(i);
+				if (child) walk(child);
+			}
+		};
 
-		if (node.type === 'impl_item') {
-			return `impl ${nameNode.text}`;
-		}
+		walk(root);
 
-		return nameNode.text;
+		const deduped = this.dedupeRefs(refs);
+		return maxOccurrencesPerToken > 0
+			? this.limitRefsPerToken(deduped, maxOccurrencesPerToken)
+			: deduped;
 	}
 
 	/**
@@ -3052,7 +6994,14 @@ export class Chunker {
 			signature: null,
 			docstring: null,
 			isExported: true, // Entire module is implicitly "exported"
+			visibility: 'public',
 			decoratorNames: null,
+			implTypeName: null,
+			implTraitName: null,
+			supertypes: [],
+			derives: [],
+			isTest: isTestFilePath(filepath),
+			isDocTest: false,
 			identifiers: tokenFacts.identifiers,
 			identifierParts: tokenFacts.identifierParts,
 			calledNames: tokenFacts.calledNames,
@@ -3245,7 +7194,14 @@ export class Chunker {
 			signature: null,
 			docstring: null,
 			isExported: true,
+			visibility: 'public',
 			decoratorNames: null,
+			implTypeName: null,
+			implTraitName: null,
+			supertypes: [],
+			derives: [],
+			isTest: false,
+			isDocTest: false,
 			identifiers: tokenFacts.identifiers,
 			identifierParts: tokenFacts.identifierParts,
 			calledNames: tokenFacts.calledNames,
@@ -3442,6 +7398,7 @@ export class Chunker {
 			parentClass,
 			functionName,
 			isContinuation,
+			this.extractNamespaceFromContext(original.contextHeader),
 		);
 
 		const fullText = `${contextHeader}\n${text}`;
@@ -3462,7 +7419,14 @@ export class Chunker {
 			signature: isContinuation ? null : original.signature,
 			docstring: isContinuation ? null : original.docstring,
 			isExported: original.isExported,
+			visibility: original.visibility,
 			decoratorNames: isContinuation ? null : original.decoratorNames,
+			implTypeName: original.implTypeName,
+			implTraitName: original.implTraitName,
+			supertypes: original.supertypes,
+			derives: original.derives,
+			isTest: original.isTest,
+			isDocTest: original.isDocTest,
 			identifiers: tokenFacts.identifiers,
 			identifierParts: tokenFacts.identifierParts,
 			calledNames: tokenFacts.calledNames,
@@ -3478,6 +7442,14 @@ export class Chunker {
 		return match ? match[1]! : null;
 	}
 
+	/**
+	 * Extract namespace path from a context header string.
+	 */
+	private extractNamespaceFromContext(contextHeader: string): string | null {
+		const match = contextHeader.match(/Namespace: ([^,)]+)/);
+		return match ? match[1]! : null;
+	}
+
 	/**
 	 * Merge small adjacent chunks of the same type to avoid fragment explosion.
 	 */
@@ -3554,6 +7526,7 @@ export class Chunker {
 		}
 		// Clear the language cache
 		this.languages.clear();
+		this.pluginLanguages.clear();
 		this.initialized = false;
 	}
 }
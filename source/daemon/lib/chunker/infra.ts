@@ -0,0 +1,456 @@
+/**
+ * Infrastructure-as-code files - Terraform, Dockerfiles and Kubernetes
+ * manifests. Resources, modules, variables, build stages and Kubernetes
+ * objects are outlined as symbols, labelled in each tool's own terms
+ * (`resource aws_elasticache_cluster.redis`, `stage builder`), with the
+ * references between them (`module.x`, `var.y`, `FROM <stage>`) as refs.
+ */
+
+import path from 'node:path';
+import {childValue, readKeyLines} from './keys.js';
+import {
+	blankRanges,
+	type FileOutline,
+	type LexicalSyntax,
+	maskLexical,
+	matchingBracket,
+	OutlineBuilder,
+	scanLexical,
+} from './outline.js';
+
+/**
+ * Outline an infrastructure file, or null when the file is not one.
+ */
+export function outlineInfraFile(
+	filepath: string,
+	content: string,
+): FileOutline | null {
+	const basename = path.basename(filepath);
+	if (DOCKERFILE_NAME.test(basename)) return outlineDockerfile(content);
+	switch (path.extname(basename).toLowerCase()) {
+		case '.tf':
+			return outlineTerraform(content);
+		case '.tfvars':
+			return outlineTfvars(content);
+		case '.yaml':
+		case '.yml':
+			return outlineKubernetes(content);
+		default:
+			return null;
+	}
+}
+
+// ============================================================================
+// Terraform
+// ============================================================================
+
+const TERRAFORM_SYNTAX: LexicalSyntax = {
+	lineComments: ['#', '//'],
+	blockComment: ['/*', '*/'],
+	quotes: ['"'],
+};
+
+const TERRAFORM_HEREDOC_PATTERN = /<<-?(\w+)[^\n]*\n[\s\S]*?^[ \t]*\1[ \t]*$/gm;
+
+const TERRAFORM_BLOCK_PATTERN =
+	/^[ \t]*(resource|data|module|variable|output|locals)(?:[ \t]+"([^"\n]*)")?(?:[ \t]+"([^"\n]*)")?[ \t]*\{/gm;
+
+const TERRAFORM_ATTRIBUTE_PATTERN = /^[ \t]*([A-Za-z_][\w-]*)[ \t]*=(?!=)/gm;
+
+const TERRAFORM_REF_PATTERN =
+	/(?<![\w.-])(?:(?:var|local|module)\.([A-Za-z_][\w-]*)|data\.[A-Za-z_][\w-]*\.([A-Za-z_][\w-]*)|([a-z][a-z0-9]*_[a-z0-9_]+)\.([A-Za-z_][\w-]*))/g;
+
+/**
+ * Terraform: resources and data sources (named by their address), module
+ * calls, variables, outputs and locals. `var.x`, `local.x`, `module.x`,
+ * `data.t.x` and resource references are refs; module sources are imports.
+ */
+export function outlineTerraform(content: string): FileOutline {
+	const {code, masked} = maskTerraform(content);
+	const outline = new OutlineBuilder(content, ['#', '//']);
+	const resourceTypes = new Set<string>();
+
+	TERRAFORM_BLOCK_PATTERN.lastIndex = 0;
+	for (
+		let m = TERRAFORM_BLOCK_PATTERN.exec(code);
+		m;
+		m = TERRAFORM_BLOCK_PATTERN.exec(code)
+	) {
+		const [text, block, first, second] = m;
+		const start = m.index + text.length - text.trimStart().length;
+		const open = m.index + text.length - 1;
+		const close = matchingBracket(masked, open);
+		TERRAFORM_BLOCK_PATTERN.lastIndex = close;
+		const signature = content.slice(start, open);
+		const description = terraformDescription(code, open + 1, close - 1);
+
+		switch (block) {
+			case 'resource':
+				if (!first || !second) break;
+				resourceTypes.add(first);
+				outline.define(
+					'resource',
+					second,
+					first,
+					start,
+					close,
+					signature,
+					description,
+					`resource ${first}.${second}`,
+				);
+				break;
+			case 'data':
+				if (!first || !second) break;
+				outline.define(
+					'resource',
+					second,
+					`data.${first}`,
+					start,
+					close,
+					signature,
+					description,
+					`data ${first}.${second}`,
+				);
+				break;
+			case 'module': {
+				if (!first) break;
+				outline.define(
+					'module_call',
+					first,
+					'module',
+					start,
+					close,
+					signature,
+					description,
+					`module ${first}`,
+				);
+				const source = code
+					.slice(open + 1, close - 1)
+					.match(/^[ \t]*source[ \t]*=[ \t]*"([^"\n]+)"/m);
+				if (source?.index !== undefined) {
+					const offset =
+						open + 1 + source.index + source[0].length - 1 - source[1]!.length;
+					const stem = moduleSourceStem(source[1]!);
+					outline.ref(
+						'import',
+						stem,
+						offset + source[1]!.lastIndexOf(stem),
+						source[1]!,
+					);
+				}
+				break;
+			}
+			case 'variable':
+			case 'output':
+				if (!first) break;
+				outline.define(
+					'variable',
+					first,
+					block === 'variable' ? 'var' : 'output',
+					start,
+					close,
+					signature,
+					description,
+					`${block} ${first}`,
+				);
+				break;
+			case 'locals': {
+				const locals = topLevelAttributes(masked, open + 1, close - 1);
+				for (const attribute of locals) {
+					outline.define(
+						'variable',
+						attribute.name,
+						'local',
+						attribute.start,
+						attribute.end,
+						content.slice(attribute.start, attribute.end),
+						null,
+						`local ${attribute.name}`,
+					);
+				}
+				break;
+			}
+		}
+	}
+
+	for (const m of code.matchAll(TERRAFORM_REF_PATTERN)) {
+		const resourceType = m[3];
+		const name = m[1] ?? m[2] ?? m[4]!;
+		// `type.name` is only a resource reference when followed by an
+		// attribute or index, or when the type is declared in this file
+		if (resourceType && !resourceTypes.has(resourceType)) {
+			const next = code[m.index + m[0].length];
+			if (next !== '.' && next !== '[') continue;
+		}
+		outline.ref('identifier', name, m.index + m[0].length - name.length);
+	}
+
+	return outline.build('terraform');
+}
+
+/**
+ * Terraform variable files: each assignment is a ref to its variable.
+ */
+export function outlineTfvars(content: string): FileOutline {
+	const {masked} = maskTerraform(content);
+	const outline = new OutlineBuilder(content, ['#', '//']);
+	for (const attribute of topLevelAttributes(masked, 0, masked.length)) {
+		outline.ref('identifier', attribute.name, attribute.start);
+	}
+	return outline.build('terraform');
+}
+
+/**
+ * `code`: comments and heredocs blanked, strings kept (interpolations hold
+ * references). `masked`: string contents blanked as well, for brackets.
+ */
+function maskTerraform(content: string): {code: string; masked: string} {
+	const heredocs: Array<[number, number]> = [];
+	for (const m of content.matchAll(TERRAFORM_HEREDOC_PATTERN)) {
+		const body = m.index + m[0].indexOf('\n');
+		heredocs.push([body, m.index + m[0].length]);
+	}
+	const withoutHeredocs = blankRanges(content, heredocs);
+	const ranges = scanLexical(withoutHeredocs, TERRAFORM_SYNTAX);
+	return {
+		code: blankRanges(withoutHeredocs, ranges.comments),
+		masked: maskLexical(withoutHeredocs, ranges),
+	};
+}
+
+/**
+ * `name = value` attributes directly inside `[start, end)` of masked text,
+ * each spanning to the end of its value.
+ */
+function topLevelAttributes(
+	masked: string,
+	start: number,
+	end: number,
+): Array<{name: string; start: number; end: number}> {
+	const attributes: Array<{name: string; start: number; end: number}> = [];
+	const pattern = new RegExp(TERRAFORM_ATTRIBUTE_PATTERN.source, 'gm');
+	pattern.lastIndex = start;
+	for (
+		let m = pattern.exec(masked);
+		m && m.index < end;
+		m = pattern.exec(masked)
+	) {
+		const attributeStart = m.index + m[0].length - m[0].trimStart().length;
+		let depth = 0;
+		let i = m.index + m[0].length;
+		for (; i < end; i++) {
+			const ch = masked[i];
+			if (ch === '{' || ch === '[' || ch === '(') depth++;
+			else if (ch === '}' || ch === ']' || ch === ')') depth--;
+			else if (ch === '\n' && depth <= 0) break;
+		}
+		attributes.push({name: m[1]!, start: attributeStart, end: i});
+		pattern.lastIndex = i;
+	}
+	return attributes;
+}
+
+function terraformDescription(
+	code: string,
+	start: number,
+	end: number,
+): string | null {
+	const match = code
+		.slice(start, end)
+		.match(/^[ \t]*description[ \t]*=[ \t]*("(?:[^"\\\n]|\\.)*")/m);
+	if (!match) return null;
+	try {
+		return (JSON.parse(match[1]!) as string).trim() || null;
+	} catch {
+		return match[1]!.slice(1, -1).trim() || null;
+	}
+}
+
+/**
+ * Module name of a module source: `./modules/redis`,
+ * `git::https://host/repo.git//modules/redis?ref=v1` and `org/redis/aws`
+ * (registry) are all `redis`.
+ */
+function moduleSourceStem(source: string): string {
+	const withoutQuery = source.replace(/\?.*$/, '');
+	const registry = withoutQuery.match(
+		/^(?:[\w.-]+\/)?[\w-]+\/([\w-]+)\/[\w-]+$/,
+	);
+	if (registry && !withoutQuery.startsWith('.')) return registry[1]!;
+	const subdir = withoutQuery.split('//').pop()!;
+	const segments = subdir.split('/').filter(s => s && s !== '.' && s !== '..');
+	return (segments.pop() ?? withoutQuery).replace(/\.git$/, '');
+}
+
+// ============================================================================
+// Dockerfile
+// ============================================================================
+
+const DOCKERFILE_NAME =
+	/^(?:Dockerfile|Containerfile)(?:\..+)?$|\.dockerfile$/i;
+
+const DOCKER_FROM_PATTERN =
+	/^[ \t]*FROM[ \t]+(?:--\S+[ \t]+)*(\S+)(?:[ \t]+AS[ \t]+(\S+))?[ \t]*$/i;
+
+const DOCKER_FROM_FLAG_PATTERN =
+	/--from=([^\s,]+)|--mount=\S*?\bfrom=([^\s,]+)/g;
+
+/**
+ * Dockerfile: named build stages (`FROM image AS name`). `FROM <stage>` and
+ * `--from=<stage>` are refs to stages; base images are imports.
+ */
+export function outlineDockerfile(content: string): FileOutline {
+	const outline = new OutlineBuilder(content, ['#']);
+	const lines = outline.lines;
+	const stages = new Set<string>();
+	const froms: Array<{line: number; name: string | null; signature: string}> =
+		[];
+
+	// Stages and flags may name an image instead of an earlier stage
+	const stageOrImage = (value: string, offset: number) => {
+		if (stages.has(value.toLowerCase())) {
+			outline.ref('identifier', value, offset);
+			return;
+		}
+		if (/^\d+$|\$|^scratch$/i.test(value)) return;
+		const repository = value.replace(/@.*$/, '').replace(/:[^/]*$/, '');
+		const image = repository.split('/').pop()!;
+		outline.ref('import', image, offset + repository.lastIndexOf(image), value);
+	};
+
+	let offset = 0;
+	for (const [i, text] of lines.entries()) {
+		const from = text.match(DOCKER_FROM_PATTERN);
+		if (from) {
+			const image = from[1]!;
+			const imageColumn = text.indexOf(image, text.search(/FROM/i) + 4);
+			stageOrImage(image, offset + imageColumn);
+			const name = from[2] ?? null;
+			froms.push({line: i, name, signature: text.trim()});
+			if (name) stages.add(name.toLowerCase());
+		} else if (/^[ \t]*(?:COPY|ADD|RUN)\b/i.test(text)) {
+			for (const flag of text.matchAll(DOCKER_FROM_FLAG_PATTERN)) {
+				const value = flag[1] ?? flag[2]!;
+				stageOrImage(
+					value,
+					offset + flag.index + flag[0].length - value.length,
+				);
+			}
+		}
+		offset += text.length + 1;
+	}
+
+	for (const [i, from] of froms.entries()) {
+		if (!from.name) continue;
+		let endLine = (froms[i + 1]?.line ?? lines.length) - 1;
+		while (endLine > from.line && !isDockerInstruction(lines[endLine]!)) {
+			endLine--;
+		}
+		outline.defineLines(
+			'stage',
+			from.name,
+			null,
+			from.line + 1,
+			endLine + 1,
+			from.signature,
+			null,
+			`stage ${from.name}`,
+		);
+	}
+
+	return outline.build('dockerfile');
+}
+
+function isDockerInstruction(text: string): boolean {
+	const trimmed = text.trim();
+	return trimmed.length > 0 && !trimmed.startsWith('#');
+}
+
+// ============================================================================
+// Kubernetes
+// ============================================================================
+
+/**
+ * Keys whose value names another object (`claimName: data`).
+ */
+const KUBERNETES_REF_KEYS = new Set([
+	'claimName',
+	'secretName',
+	'serviceName',
+	'serviceAccountName',
+	'priorityClassName',
+	'storageClassName',
+]);
+
+/**
+ * Keys whose `name` child names another object (`configMapRef: {name: x}`).
+ */
+const KUBERNETES_REF_PARENTS = new Set([
+	'configMapRef',
+	'configMapKeyRef',
+	'secretRef',
+	'secretKeyRef',
+	'configMap',
+	'service',
+	'scaleTargetRef',
+	'roleRef',
+	'subjects',
+]);
+
+/**
+ * Kubernetes manifests (one or more `---`-separated documents): each object
+ * is named by `metadata.name`, under its `kind`. Names of config maps,
+ * secrets, services, claims and roles used by other objects are refs.
+ * Returns null for other YAML files.
+ */
+export function outlineKubernetes(content: string): FileOutline | null {
+	if (!/^apiVersion:/m.test(content) || !/^kind:/m.test(content)) {
+		return null;
+	}
+	const keys = readKeyLines(content, 'yaml');
+	const outline = new OutlineBuilder(content, ['#']);
+
+	// Top-level keys, grouped into documents
+	const separators = outline.lines.flatMap((text, i) =>
+		/^---/.test(text) ? [i] : [],
+	);
+	const documents = new Map<number, number[]>();
+	keys.forEach((key, index) => {
+		if (key.parent !== -1) return;
+		const document = separators.filter(line => line < key.line).length;
+		documents.set(document, [...(documents.get(document) ?? []), index]);
+	});
+
+	for (const topLevel of documents.values()) {
+		const find = (key: string) => topLevel.find(i => keys[i]!.key === key);
+		const kind = keys[find('kind') ?? -1]?.value;
+		const metadata = find('metadata');
+		if (!kind || metadata === undefined) continue;
+		const name = childValue(keys, metadata, 'name');
+		if (!name) continue;
+		const namespace = childValue(keys, metadata, 'namespace');
+		outline.defineLines(
+			'resource',
+			name,
+			kind,
+			keys[topLevel[0]!]!.line + 1,
+			Math.max(...topLevel.map(i => keys[i]!.endLine)) + 1,
+			`${kind} ${namespace ? `${namespace}/` : ''}${name}`,
+			null,
+			`${kind} ${name}`,
+		);
+	}
+
+	for (const key of keys) {
+		if (!key.value) continue;
+		const parent = keys[key.parent];
+		if (
+			KUBERNETES_REF_KEYS.has(key.key) ||
+			(key.key === 'name' && parent && KUBERNETES_REF_PARENTS.has(parent.key))
+		) {
+			outline.ref('identifier', key.value, key.valueOffset);
+		}
+	}
+
+	return outline.build('kubernetes');
+}
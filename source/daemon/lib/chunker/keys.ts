@@ -0,0 +1,412 @@
+/**
+ * Key lines - the key structure of YAML, pretty-printed JSON and TOML
+ * documents, read line by line (no full parser). YAML and JSON nesting comes
+ * from indentation, TOML nesting from table headers and dotted keys, which
+ * is enough to walk config files and well-known documents such as OpenAPI
+ * specs.
+ */
+
+export type KeyLine = {
+	/** 0-based line of the key */
+	line: number;
+	/** Column of the key (after the `- ` of a list item) */
+	indent: number;
+	key: string;
+	/** Offset of the key text in the file */
+	offset: number;
+	/** Inline scalar value, unquoted; null when the value is a nested block */
+	value: string | null;
+	/** Offset of the value text in the file (-1 when null) */
+	valueOffset: number;
+	/** Index of the enclosing key, -1 at the top level */
+	parent: number;
+	/** 0-based last line of the key's block */
+	endLine: number;
+	/** Inside a list item (`- name: x`, `[[servers]]`): not a stable path */
+	inSequence: boolean;
+};
+
+export type KeyFormat = 'yaml' | 'json' | 'toml';
+
+const YAML_KEY_PATTERN =
+	/^(\s*(?:-\s+)?)(?:"((?:[^"\\]|\\.)*)"|'((?:[^']|'')*)'|([^\s#'"{}[\],&*!|>%@`][^#]*?))\s*:(?=\s|$)\s*(.*?)\s*$/;
+const JSON_KEY_PATTERN = /^(\s*)"((?:[^"\\]|\\.)*)"\s*:\s*(.*?),?\s*$/;
+const YAML_BLOCK_SCALAR = /^[|>][-+0-9]*$/;
+
+/**
+ * Read the keys of a YAML, pretty-printed JSON or TOML document, in file
+ * order.
+ */
+export function readKeyLines(content: string, format: KeyFormat): KeyLine[] {
+	if (format === 'toml') return readTomlKeys(content);
+	const lines = content.split('\n');
+	const keys: KeyLine[] = [];
+	let offset = 0;
+	let blockScalarIndent = -1;
+	let last = -1;
+	// Keys whose block is still open, outermost first
+	const open: number[] = [];
+	// JSON keys whose value is an array
+	const arrays = new Set<number>();
+
+	for (let i = 0; i < lines.length; offset += lines[i]!.length + 1, i++) {
+		const text = lines[i]!.replace(/\r$/, '');
+		const trimmed = text.trim();
+		if (!trimmed) continue;
+		const indent = text.length - text.trimStart().length;
+
+		// Block scalar (`description: |`) content is text, not keys
+		if (blockScalarIndent !== -1) {
+			if (indent > blockScalarIndent) {
+				last = i;
+				continue;
+			}
+			blockScalarIndent = -1;
+		}
+		if (
+			format === 'yaml' &&
+			(trimmed.startsWith('#') || trimmed === '---')
+		) {
+			continue;
+		}
+
+		const parsed = format === 'yaml' ? parseYamlKey(text) : parseJsonKey(text);
+		if (!parsed) {
+			// JSON `}`/`]` ends the key opened at its indent, and that key's
+			// children before it
+			if (format === 'json' && /^[}\]]/.test(trimmed)) {
+				while (
+					open.length > 0 &&
+					keys[open[open.length - 1]!]!.indent >= indent
+				) {
+					const closed = open.pop()!;
+					keys[closed]!.endLine = keys[closed]!.indent === indent ? i : last;
+				}
+			}
+			last = i;
+			continue;
+		}
+
+		// Open keys at this indent or deeper are siblings (or their
+		// children): they end before this line
+		let previous = -1;
+		while (
+			open.length > 0 &&
+			keys[open[open.length - 1]!]!.indent >= parsed.indent
+		) {
+			previous = open.pop()!;
+			keys[previous]!.endLine = last;
+		}
+		const parent = open.length > 0 ? open[open.length - 1]! : -1;
+		// Keys after the first of a list item (`- name: a` then `image: b`)
+		// are in the item too
+		const sibling =
+			previous !== -1 && keys[previous]!.parent === parent
+				? keys[previous]
+				: undefined;
+		const inSequence =
+			parsed.listItem ||
+			(parent !== -1 && (keys[parent]!.inSequence || arrays.has(parent))) ||
+			(sibling?.inSequence ?? false);
+
+		keys.push({
+			line: i,
+			indent: parsed.indent,
+			key: parsed.key,
+			offset: offset + parsed.keyColumn,
+			value: parsed.value,
+			valueOffset:
+				parsed.value === null ? -1 : offset + parsed.valueColumn,
+			parent,
+			endLine: -1,
+			inSequence,
+		});
+		open.push(keys.length - 1);
+		if (format === 'json' && parsed.rawValue === '[') {
+			arrays.add(keys.length - 1);
+		}
+		last = i;
+
+		if (format === 'yaml' && YAML_BLOCK_SCALAR.test(parsed.rawValue)) {
+			blockScalarIndent = parsed.indent;
+		}
+	}
+
+	for (const index of open) keys[index]!.endLine = last;
+	return keys;
+}
+
+type ParsedKey = {
+	indent: number;
+	key: string;
+	keyColumn: number;
+	value: string | null;
+	valueColumn: number;
+	rawValue: string;
+	listItem: boolean;
+};
+
+function parseYamlKey(text: string): ParsedKey | null {
+	const match = text.match(YAML_KEY_PATTERN);
+	if (!match) return null;
+	const prefix = match[1]!;
+	const key = match[2] ?? match[3] ?? match[4]!;
+	const rawValue = stripYamlComment(match[5] ?? '');
+	const keyColumn = prefix.length + (match[4] === undefined ? 1 : 0);
+	const nested =
+		!rawValue || YAML_BLOCK_SCALAR.test(rawValue) || rawValue.startsWith('&');
+	const quoted = /^["']/.test(rawValue);
+	return {
+		indent: prefix.length,
+		key,
+		keyColumn,
+		value: nested ? null : unquote(rawValue),
+		valueColumn: nested ? -1 : text.lastIndexOf(rawValue) + (quoted ? 1 : 0),
+		rawValue,
+		listItem: prefix.includes('-'),
+	};
+}
+
+function parseJsonKey(text: string): ParsedKey | null {
+	const match = text.match(JSON_KEY_PATTERN);
+	if (!match) return null;
+	const indent = match[1]!.length;
+	const rawValue = match[3]!;
+	const nested =
+		(rawValue.startsWith('{') && rawValue !== '{}') ||
+		(rawValue.startsWith('[') && rawValue !== '[]');
+	let key = match[2]!;
+	try {
+		key = JSON.parse(`"${key}"`) as string;
+	} catch {
+		// Keep the raw key text
+	}
+	const quoted = rawValue.startsWith('"');
+	return {
+		indent,
+		key,
+		keyColumn: indent + 1,
+		value: nested ? null : unquote(rawValue),
+		valueColumn: nested ? -1 : text.lastIndexOf(rawValue) + (quoted ? 1 : 0),
+		rawValue,
+		listItem: false,
+	};
+}
+
+function stripYamlComment(value: string): string {
+	if (value.startsWith('"') || value.startsWith("'")) return value;
+	const comment = value.search(/\s#/);
+	return (comment === -1 ? value : value.slice(0, comment)).trim();
+}
+
+function unquote(value: string): string {
+	if (value.length >= 2 && value.startsWith('"') && value.endsWith('"')) {
+		try {
+			return JSON.parse(value) as string;
+		} catch {
+			return value.slice(1, -1);
+		}
+	}
+	if (value.length >= 2 && value.startsWith("'") && value.endsWith("'")) {
+		return value.slice(1, -1).replace(/''/g, "'");
+	}
+	return value;
+}
+
+const TOML_TABLE_PATTERN = /^\s*(\[\[?)\s*([^[\]]+?)\s*\]\]?\s*(?:#.*)?$/;
+const TOML_KEY_SEGMENT = String.raw`(?:[\w-]+|"(?:[^"\\]|\\.)*"|'[^']*')`;
+const TOML_KEY_PATTERN = new RegExp(
+	String.raw`^\s*(${TOML_KEY_SEGMENT}(?:\s*\.\s*${TOML_KEY_SEGMENT})*)\s*=\s*`,
+);
+const TOML_KEY_SEGMENTS = new RegExp(TOML_KEY_SEGMENT, 'g');
+
+/**
+ * TOML: `[table]` and `[[array]]` headers and (dotted) keys. Tables span to
+ * the next header; implicit parents (`a` of `[a.b]`) span their children.
+ */
+function readTomlKeys(content: string): KeyLine[] {
+	const lines = content.split('\n');
+	const starts: number[] = [];
+	for (let i = 0, offset = 0; i < lines.length; i++) {
+		starts.push(offset);
+		offset += lines[i]!.length + 1;
+	}
+
+	const keys: KeyLine[] = [];
+	// `${parent index}/${key}` -> index of the latest such key
+	const children = new Map<string, number>();
+	const child = (
+		parent: number,
+		key: string,
+		line: number,
+		column: number,
+		isArray = false,
+	): number => {
+		const existing = children.get(`${parent}/${key}`);
+		if (existing !== undefined && !isArray) return existing;
+		keys.push({
+			line,
+			indent: column,
+			key,
+			offset: starts[line]! + column,
+			value: null,
+			valueOffset: -1,
+			parent,
+			endLine: line,
+			inSequence: isArray || (parent !== -1 && keys[parent]!.inSequence),
+		});
+		children.set(`${parent}/${key}`, keys.length - 1);
+		return keys.length - 1;
+	};
+
+	let table = -1;
+	let lastContent = -1;
+	for (let i = 0; i < lines.length; i++) {
+		const text = lines[i]!.replace(/\r$/, '');
+		const trimmed = text.trim();
+		if (!trimmed || trimmed.startsWith('#')) continue;
+
+		const header = text.match(TOML_TABLE_PATTERN);
+		if (header) {
+			if (table !== -1) keys[table]!.endLine = lastContent;
+			const column = text.indexOf(header[2]!);
+			const segments = tomlKeySegments(header[2]!);
+			table = -1;
+			segments.forEach((segment, s) => {
+				table = child(
+					table,
+					segment.key,
+					i,
+					column + segment.column,
+					header[1] === '[[' && s === segments.length - 1,
+				);
+			});
+			lastContent = i;
+			continue;
+		}
+
+		const match = text.match(TOML_KEY_PATTERN);
+		if (!match) {
+			lastContent = i;
+			continue;
+		}
+		const column = text.indexOf(match[1]!);
+		const segments = tomlKeySegments(match[1]!);
+		let parent = table;
+		for (const segment of segments.slice(0, -1)) {
+			parent = child(parent, segment.key, i, column + segment.column);
+		}
+		const leaf = segments[segments.length - 1]!;
+		const index = child(parent, leaf.key, i, column + leaf.column);
+
+		// Multi-line strings and arrays continue on the following lines
+		const valueColumn = match[0].length;
+		let valueText = text.slice(valueColumn);
+		let endLine = i;
+		const multiline = valueText.match(/^("""|''')/)?.[1];
+		if (multiline) {
+			while (
+				valueText.indexOf(multiline, 3) === -1 &&
+				endLine + 1 < lines.length
+			) {
+				valueText += `\n${lines[++endLine]!}`;
+			}
+		} else {
+			while (tomlBracketDepth(valueText) > 0 && endLine + 1 < lines.length) {
+				valueText += `\n${lines[++endLine]!}`;
+			}
+		}
+		const value = stripTomlComment(valueText).replace(/\s+/g, ' ').trim();
+		const quoted = /^["']/.test(value) && !multiline;
+		Object.assign(keys[index]!, {
+			value: quoted ? unquote(value) : value,
+			valueOffset: starts[i]! + valueColumn + (quoted ? 1 : 0),
+			endLine,
+		});
+		lastContent = endLine;
+		i = endLine;
+	}
+	if (table !== -1) keys[table]!.endLine = lastContent;
+
+	// Implicit parents span their children
+	for (let i = keys.length - 1; i >= 0; i--) {
+		const key = keys[i]!;
+		if (key.parent !== -1) {
+			const parent = keys[key.parent]!;
+			parent.endLine = Math.max(parent.endLine, key.endLine);
+		}
+	}
+	return keys;
+}
+
+function tomlKeySegments(text: string): Array<{key: string; column: number}> {
+	return [...text.matchAll(TOML_KEY_SEGMENTS)].map(m => ({
+		key: unquote(m[0]),
+		column: m.index,
+	}));
+}
+
+/**
+ * Open `[`/`{` minus closed ones, outside strings and comments.
+ */
+function tomlBracketDepth(text: string): number {
+	let depth = 0;
+	let quote: string | null = null;
+	for (let i = 0; i < text.length; i++) {
+		const ch = text[i]!;
+		if (quote) {
+			if (ch === '\\' && quote === '"') i++;
+			else if (ch === quote) quote = null;
+		} else if (ch === '"' || ch === "'") {
+			quote = ch;
+		} else if (ch === '#') {
+			const newline = text.indexOf('\n', i);
+			if (newline === -1) break;
+			i = newline;
+		} else if (ch === '[' || ch === '{') {
+			depth++;
+		} else if (ch === ']' || ch === '}') {
+			depth--;
+		}
+	}
+	return depth;
+}
+
+function stripTomlComment(text: string): string {
+	return text
+		.split('\n')
+		.map(line => {
+			let quote: string | null = null;
+			for (let i = 0; i < line.length; i++) {
+				const ch = line[i]!;
+				if (quote) {
+					if (ch === '\\' && quote === '"') i++;
+					else if (ch === quote) quote = null;
+				} else if (ch === '"' || ch === "'") {
+					quote = ch;
+				} else if (ch === '#') {
+					return line.slice(0, i);
+				}
+			}
+			return line;
+		})
+		.join('\n');
+}
+
+/**
+ * Children of the key at `index` (-1 for top-level keys).
+ */
+export function childKeys(keys: KeyLine[], index: number): KeyLine[] {
+	return keys.filter(k => k.parent === index);
+}
+
+/**
+ * Inline value of the child `key` of the key at `index`, if any.
+ */
+export function childValue(
+	keys: KeyLine[],
+	index: number,
+	key: string,
+): string | null {
+	return keys.find(k => k.parent === index && k.key === key)?.value ?? null;
+}
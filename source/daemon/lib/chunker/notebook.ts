@@ -0,0 +1,159 @@
+/**
+ * Jupyter notebooks (`.ipynb`).
+ *
+ * Notebooks are JSON, with each cell's source stored as an array of lines,
+ * one JSON string per file line (as Jupyter writes them). Cells are mapped
+ * back to those file lines, so everything extracted from a notebook reports
+ * line numbers in the `.ipynb` file itself.
+ *
+ * The "source view" of a notebook has the same number of lines as the file:
+ * cell source lines are decoded in place and the JSON structure around them
+ * is blank.
+ */
+
+export type NotebookCellType = 'code' | 'markdown' | 'raw';
+
+export type NotebookCell = {
+	/** 0-based position in the notebook's `cells` array */
+	index: number;
+	cellType: NotebookCellType;
+	/** 1-based file lines of the first and last source line */
+	startLine: number;
+	endLine: number;
+	/** Decoded source lines, without line terminators */
+	source: string[];
+};
+
+export type Notebook = {
+	/** Kernel language (`python`, `r`, ...), lowercase; null when unset */
+	language: string | null;
+	cells: NotebookCell[];
+	/** Number of lines in the `.ipynb` file */
+	lineCount: number;
+};
+
+const SOURCE_KEY_PATTERN = /(?<!\\)"source"\s*:\s*/g;
+
+/**
+ * Parse a notebook and locate each cell's source lines in the file. Returns
+ * null when the file is not a notebook or was written without one source
+ * line per file line (e.g. minified), in which case it is indexed as text.
+ */
+export function parseNotebook(content: string): Notebook | null {
+	let raw: unknown;
+	try {
+		raw = JSON.parse(content);
+	} catch {
+		return null;
+	}
+	if (typeof raw !== 'object' || raw === null) return null;
+	const rawCells = (raw as Record<string, unknown>)['cells'];
+	if (!Array.isArray(rawCells)) return null;
+
+	// Offsets only move forward, so lines are counted incrementally
+	let scanned = 0;
+	let line = 1;
+	const lineAt = (offset: number): number => {
+		for (; scanned < offset; scanned++) {
+			if (content.charCodeAt(scanned) === 10) line++;
+		}
+		return line;
+	};
+
+	const cells: NotebookCell[] = [];
+	let cursor = 0;
+	let lastLine = 0;
+	for (const [index, rawCell] of rawCells.entries()) {
+		if (typeof rawCell !== 'object' || rawCell === null) return null;
+		const cell = rawCell as Record<string, unknown>;
+		const cellType = cell['cell_type'];
+		if (cellType !== 'code' && cellType !== 'markdown' && cellType !== 'raw') {
+			return null;
+		}
+		const source =
+			typeof cell['source'] === 'string' ? [cell['source']] : cell['source'];
+		if (!Array.isArray(source)) return null;
+
+		// Outputs come before `source` in Jupyter's key order; skip past them
+		// so output text never matches a source line.
+		SOURCE_KEY_PATTERN.lastIndex = cursor;
+		const key = SOURCE_KEY_PATTERN.exec(content);
+		if (!key) return null;
+		cursor = key.index + key[0].length;
+
+		const decoded: string[] = [];
+		let startLine = lineAt(cursor);
+		let endLine = startLine;
+		for (const element of source) {
+			if (typeof element !== 'string') return null;
+			const text = element.endsWith('\n') ? element.slice(0, -1) : element;
+			if (text.includes('\n')) return null;
+			const offset = content.indexOf(JSON.stringify(element), cursor);
+			if (offset === -1) return null;
+			const elementLine = lineAt(offset);
+			// One source line per file line, consecutively
+			if (decoded.length === 0) {
+				if (elementLine <= lastLine) return null;
+				startLine = elementLine;
+			} else if (elementLine !== endLine + 1) {
+				return null;
+			}
+			endLine = elementLine;
+			lastLine = elementLine;
+			decoded.push(text.replace(/\r$/, ''));
+			cursor = offset + JSON.stringify(element).length;
+		}
+		cells.push({index, cellType, startLine, endLine, source: decoded});
+	}
+
+	return {
+		language: notebookLanguage(raw as Record<string, unknown>),
+		cells,
+		lineCount: content.split('\n').length,
+	};
+}
+
+function notebookLanguage(raw: Record<string, unknown>): string | null {
+	const metadata = raw['metadata'] as Record<string, unknown> | undefined;
+	const kernelspec = metadata?.['kernelspec'] as
+		| Record<string, unknown>
+		| undefined;
+	const languageInfo = metadata?.['language_info'] as
+		| Record<string, unknown>
+		| undefined;
+	const language = kernelspec?.['language'] ?? languageInfo?.['name'];
+	return typeof language === 'string' && language
+		? language.toLowerCase()
+		: null;
+}
+
+/**
+ * The notebook's source view: decoded cell lines at their file lines, all
+ * other lines blank. `include` selects which cells are kept.
+ */
+export function notebookSourceView(
+	notebook: Notebook,
+	include: (cell: NotebookCell) => boolean = () => true,
+): string {
+	const lines = new Array<string>(notebook.lineCount).fill('');
+	for (const cell of notebook.cells) {
+		if (cell.source.length === 0 || !include(cell)) continue;
+		cell.source.forEach((text, i) => {
+			lines[cell.startLine - 1 + i] = text;
+		});
+	}
+	return lines.join('\n');
+}
+
+/**
+ * Source view of the code cells only, for parsing with the kernel
+ * language's grammar. IPython cell magics (`%%bash`) drop the whole cell;
+ * line magics and shell escapes (`%time`, `!pip`) are blanked.
+ */
+export function notebookCodeView(notebook: Notebook): string {
+	const lines = notebookSourceView(
+		notebook,
+		cell => cell.cellType === 'code' && !cell.source[0]?.startsWith('%%'),
+	).split('\n');
+	return lines.map(text => (/^\s*[%!]/.test(text) ? '' : text)).join('\n');
+}
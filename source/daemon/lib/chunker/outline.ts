@@ -0,0 +1,330 @@
+/**
+ * Outlines - definitions and refs of files without a tree-sitter grammar
+ * (schema, config and build files), found by small format-specific scanners.
+ *
+ * Scanners work on a masked copy of the file (comments and strings blanked
+ * to spaces, same length), so regex offsets are offsets in the file.
+ */
+
+import type {ChunkType, RefKind} from './types.js';
+
+export type OutlineDefinition = {
+	kind: ChunkType;
+	name: string;
+	/** Dotted path of the enclosing definitions (`Outer.Inner`), if any */
+	parent: string | null;
+	/** 1-based, inclusive */
+	startLine: number;
+	endLine: number;
+	signature: string | null;
+	docstring: string | null;
+	/**
+	 * Context header label in place of the Function part
+	 * (`resource aws_elasticache_cluster.redis`)
+	 */
+	label: string | null;
+};
+
+export type OutlineRef = {
+	kind: RefKind;
+	token: string;
+	/** Offset of the referenced text in the file */
+	offset: number;
+	length: number;
+	module_name: string | null;
+};
+
+export type FileOutline = {
+	/** Format name reported as the file's language (`protobuf`, `sql`) */
+	language: string;
+	/** Package/schema prefix for qualnames (protobuf `package`) */
+	namespace: string | null;
+	definitions: OutlineDefinition[];
+	refs: OutlineRef[];
+};
+
+/**
+ * Maps string offsets to 1-based lines.
+ */
+export class LineIndex {
+	private readonly starts: number[] = [0];
+
+	constructor(content: string) {
+		for (let i = content.indexOf('\n'); i !== -1; ) {
+			this.starts.push(i + 1);
+			i = content.indexOf('\n', i + 1);
+		}
+	}
+
+	lineAt(offset: number): number {
+		let low = 0;
+		let high = this.starts.length - 1;
+		while (low < high) {
+			const mid = (low + high + 1) >> 1;
+			if (this.starts[mid]! <= offset) low = mid;
+			else high = mid - 1;
+		}
+		return low + 1;
+	}
+
+	/** Offset of the first character of a 1-based line */
+	lineStart(line: number): number {
+		return this.starts[line - 1] ?? 0;
+	}
+}
+
+/**
+ * Blank `[start, end)` ranges of `content` to spaces, keeping newlines.
+ */
+export function blankRanges(
+	content: string,
+	ranges: Array<[number, number]>,
+): string {
+	const parts: string[] = [];
+	let cursor = 0;
+	for (const [start, end] of ranges) {
+		if (start < cursor) continue;
+		parts.push(
+			content.slice(cursor, start),
+			content.slice(start, end).replace(/[^\r\n]/g, ' '),
+		);
+		cursor = end;
+	}
+	parts.push(content.slice(cursor));
+	return parts.join('');
+}
+
+/**
+ * Offset just past the bracket matching the one at `open` (`{`/`(`), or the
+ * end of the text when unbalanced. Expects masked text.
+ */
+export function matchingBracket(masked: string, open: number): number {
+	const openChar = masked[open]!;
+	const closeChar = openChar === '(' ? ')' : openChar === '[' ? ']' : '}';
+	let depth = 0;
+	for (let i = open; i < masked.length; i++) {
+		const ch = masked[i];
+		if (ch === openChar) depth++;
+		else if (ch === closeChar && --depth === 0) return i + 1;
+	}
+	return masked.length;
+}
+
+/**
+ * Comment lines directly above a 0-based line, with their markers
+ * stripped. Handles line comments (`//`, `#`, `--`) and `/* *\/` blocks.
+ */
+export function commentAbove(
+	lines: string[],
+	index: number,
+	markers: string[],
+): string | null {
+	const out: string[] = [];
+	let i = index - 1;
+	if (lines[i]?.trim().endsWith('*/')) {
+		for (; i >= 0; i--) {
+			const text = lines[i]!.trim();
+			out.unshift(
+				text
+					.replace(/^\/\*+/, '')
+					.replace(/\*+\/$/, '')
+					.replace(/^\*\s?/, '')
+					.trim(),
+			);
+			if (text.startsWith('/*')) break;
+		}
+	} else {
+		for (; i >= 0; i--) {
+			const text = lines[i]!.trim();
+			const marker = markers.find(m => text.startsWith(m));
+			if (!marker) break;
+			out.unshift(text.slice(marker.length).replace(/^[/!]?\s?/, ''));
+		}
+	}
+	const doc = out.join('\n').trim();
+	return doc || null;
+}
+
+export type LexicalSyntax = {
+	lineComments: string[];
+	blockComment?: [string, string];
+	/** Single-line string delimiters (backslash escapes) */
+	quotes: string[];
+	/** Multi-line string delimiters (GraphQL `"""`) */
+	blockQuotes?: string[];
+};
+
+export type LexicalRanges = {
+	comments: Array<[number, number]>;
+	strings: Array<[number, number]>;
+};
+
+/**
+ * Find comment and string ranges, in file order.
+ */
+export function scanLexical(
+	content: string,
+	syntax: LexicalSyntax,
+): LexicalRanges {
+	const comments: Array<[number, number]> = [];
+	const strings: Array<[number, number]> = [];
+	let i = 0;
+	while (i < content.length) {
+		const blockQuote = syntax.blockQuotes?.find(q => content.startsWith(q, i));
+		if (blockQuote) {
+			const close = content.indexOf(blockQuote, i + blockQuote.length);
+			const end = close === -1 ? content.length : close + blockQuote.length;
+			strings.push([i, end]);
+			i = end;
+			continue;
+		}
+		const block = syntax.blockComment;
+		if (block && content.startsWith(block[0], i)) {
+			const close = content.indexOf(block[1], i + block[0].length);
+			const end = close === -1 ? content.length : close + block[1].length;
+			comments.push([i, end]);
+			i = end;
+			continue;
+		}
+		if (syntax.lineComments.some(m => content.startsWith(m, i))) {
+			const newline = content.indexOf('\n', i);
+			const end = newline === -1 ? content.length : newline;
+			comments.push([i, end]);
+			i = end;
+			continue;
+		}
+		const quote = content[i]!;
+		if (syntax.quotes.includes(quote)) {
+			let j = i + 1;
+			while (j < content.length && content[j] !== quote) {
+				if (content[j] === '\n') break;
+				j += content[j] === '\\' ? 2 : 1;
+			}
+			const end = Math.min(j + 1, content.length);
+			strings.push([i, end]);
+			i = end;
+			continue;
+		}
+		i++;
+	}
+	return {comments, strings};
+}
+
+/**
+ * `content` with comments blanked and string contents blanked (quotes
+ * kept, so scanners can still see where strings are).
+ */
+export function maskLexical(content: string, ranges: LexicalRanges): string {
+	const blanks: Array<[number, number]> = [
+		...ranges.comments,
+		...ranges.strings.map(([start, end]): [number, number] => [
+			start + 1,
+			Math.max(start + 1, end - 1),
+		]),
+	];
+	return blankRanges(content, blanks.sort((a, b) => a[0] - b[0]));
+}
+
+/**
+ * Collects an outline's definitions and refs, mapping offsets to lines.
+ */
+export class OutlineBuilder {
+	readonly definitions: OutlineDefinition[] = [];
+	readonly refs: OutlineRef[] = [];
+	readonly lines: string[];
+	readonly index: LineIndex;
+
+	constructor(
+		readonly content: string,
+		/** Line comment markers for docs above definitions */
+		private readonly commentMarkers: string[],
+	) {
+		this.lines = content.split('\n');
+		this.index = new LineIndex(content);
+	}
+
+	/**
+	 * Add a definition spanning `[start, end)`. Without an explicit
+	 * docstring, comments directly above it are its doc.
+	 */
+	define(
+		kind: ChunkType,
+		name: string,
+		parent: string | null,
+		start: number,
+		end: number,
+		signature: string | null,
+		docstring?: string | null,
+		label?: string,
+	): void {
+		const startLine = this.index.lineAt(start);
+		this.defineLines(
+			kind,
+			name,
+			parent,
+			startLine,
+			this.index.lineAt(Math.max(start, end - 1)),
+			signature,
+			docstring,
+			label,
+		);
+	}
+
+	/**
+	 * Add a definition spanning 1-based lines `startLine..endLine`.
+	 */
+	defineLines(
+		kind: ChunkType,
+		name: string,
+		parent: string | null,
+		startLine: number,
+		endLine: number,
+		signature: string | null,
+		docstring?: string | null,
+		label?: string,
+	): void {
+		if (!name) return;
+		this.definitions.push({
+			kind,
+			name,
+			parent,
+			startLine,
+			endLine,
+			signature: signature ? collapseWhitespace(signature) : null,
+			docstring:
+				docstring ??
+				commentAbove(this.lines, startLine - 1, this.commentMarkers),
+			label: label ?? null,
+		});
+	}
+
+	ref(
+		kind: RefKind,
+		token: string,
+		offset: number,
+		module_name: string | null = null,
+	): void {
+		if (!token) return;
+		this.refs.push({kind, token, offset, length: token.length, module_name});
+	}
+
+	build(language: string, namespace: string | null = null): FileOutline {
+		return {
+			language,
+			namespace,
+			definitions: this.definitions,
+			refs: this.refs,
+		};
+	}
+}
+
+export function collapseWhitespace(text: string): string {
+	return text.replace(/\s+/g, ' ').trim();
+}
+
+/**
+ * `Outer.Inner` from a parent path and a name.
+ */
+export function qualify(parent: string | null, name: string): string {
+	return parent ? `${parent}.${name}` : name;
+}
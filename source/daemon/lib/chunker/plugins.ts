@@ -0,0 +1,206 @@
+/**
+ * Language plugins - tree-sitter grammars for languages viberag does not
+ * ship, described by a declarative JSON manifest.
+ *
+ * Manifests are listed in the project config (`languagePlugins`, paths
+ * relative to the project root) and discovered in the per-machine
+ * `{VIBERAG_HOME}/languages/` directory. Project manifests win over
+ * per-machine ones with the same name.
+ *
+ * Example manifest (`grammar` is relative to the manifest):
+ *
+ * ```json
+ * {
+ *   "name": "mydsl",
+ *   "displayName": "My DSL",
+ *   "grammar": "tree-sitter-mydsl.wasm",
+ *   "extensions": [".mydsl"],
+ *   "functionNodeTypes": ["function_definition"],
+ *   "classNodeTypes": ["record_definition"],
+ *   "methodNodeTypes": ["function_definition"],
+ *   "importNodeTypes": ["import_statement"],
+ *   "callNodeTypes": ["call"],
+ *   "commentNodeTypes": ["comment"],
+ *   "docCommentPrefixes": ["///"]
+ * }
+ * ```
+ */
+
+import fs from 'node:fs/promises';
+import path from 'node:path';
+import {getUserLanguagesDir} from '../constants.js';
+import {EXTENSION_TO_LANGUAGE} from './types.js';
+
+/**
+ * A validated language plugin (paths absolute, defaults applied).
+ */
+export type LanguagePlugin = {
+	name: string;
+	displayName: string;
+	/** Manifest the plugin was loaded from */
+	manifestPath: string;
+	/** Grammar WASM (must target web-tree-sitter's ABI, 13–14) */
+	grammarPath: string;
+	/** Lowercase extensions with a leading dot */
+	extensions: string[];
+	functionNodeTypes: string[];
+	classNodeTypes: string[];
+	methodNodeTypes: string[];
+	importNodeTypes: string[];
+	callNodeTypes: string[];
+	/** Field holding a definition's name (default `name`) */
+	nameField: string;
+	commentNodeTypes: string[];
+	/**
+	 * Prefixes that mark a comment as documentation (`///`, `--|`). When empty,
+	 * any comment directly above a definition is its doc.
+	 */
+	docCommentPrefixes: string[];
+};
+
+export type LanguagePluginError = {
+	manifestPath: string;
+	error: string;
+};
+
+export type LanguagePluginLoadResult = {
+	plugins: LanguagePlugin[];
+	errors: LanguagePluginError[];
+};
+
+const PLUGIN_NAME_PATTERN = /^[a-z][a-z0-9_-]*$/;
+
+const BUILTIN_LANGUAGES = new Set<string>(Object.values(EXTENSION_TO_LANGUAGE));
+
+/**
+ * Load language plugins from the project config and the per-machine
+ * languages directory. Invalid manifests are reported, not thrown.
+ */
+export async function loadLanguagePlugins(
+	projectRoot: string,
+	manifestPaths: string[] | undefined,
+): Promise<LanguagePluginLoadResult> {
+	const candidates = [
+		...(manifestPaths ?? []).map(p => path.resolve(projectRoot, p)),
+		...(await listUserManifests()),
+	];
+
+	const plugins: LanguagePlugin[] = [];
+	const errors: LanguagePluginError[] = [];
+	const claimedExtensions = new Map<string, string>();
+
+	for (const manifestPath of candidates) {
+		try {
+			const raw = JSON.parse(await fs.readFile(manifestPath, 'utf-8'));
+			const plugin = parseLanguagePluginManifest(manifestPath, raw);
+			// Project manifests come first; a per-machine plugin of the same
+			// name is shadowed rather than reported.
+			if (plugins.some(p => p.name === plugin.name)) continue;
+			for (const ext of plugin.extensions) {
+				const owner = claimedExtensions.get(ext);
+				if (owner) {
+					throw new Error(`extension ${ext} is already claimed by ${owner}`);
+				}
+			}
+			for (const ext of plugin.extensions) {
+				claimedExtensions.set(ext, plugin.name);
+			}
+			plugins.push(plugin);
+		} catch (error) {
+			errors.push({
+				manifestPath,
+				error: error instanceof Error ? error.message : String(error),
+			});
+		}
+	}
+
+	return {plugins, errors};
+}
+
+/**
+ * Validate a manifest and apply defaults. Throws with a readable message.
+ */
+export function parseLanguagePluginManifest(
+	manifestPath: string,
+	raw: unknown,
+): LanguagePlugin {
+	if (typeof raw !== 'object' || raw === null || Array.isArray(raw)) {
+		throw new Error('manifest must be a JSON object');
+	}
+	const manifest = raw as Record<string, unknown>;
+
+	const name = manifest['name'];
+	if (typeof name !== 'string' || !PLUGIN_NAME_PATTERN.test(name)) {
+		throw new Error('"name" must be a lowercase identifier (e.g. "mydsl")');
+	}
+	if (BUILTIN_LANGUAGES.has(name)) {
+		throw new Error(
+			`"${name}" is a built-in language; use "grammars" in config to replace its grammar`,
+		);
+	}
+
+	const grammar = manifest['grammar'];
+	if (typeof grammar !== 'string' || !grammar.trim()) {
+		throw new Error('"grammar" must be a path to a .wasm file');
+	}
+
+	const extensions = stringList(manifest, 'extensions').map(ext =>
+		(ext.startsWith('.') ? ext : `.${ext}`).toLowerCase(),
+	);
+	if (extensions.length === 0) {
+		throw new Error('"extensions" must list at least one extension');
+	}
+	for (const ext of extensions) {
+		if (ext in EXTENSION_TO_LANGUAGE) {
+			throw new Error(
+				`extension ${ext} belongs to built-in language ${EXTENSION_TO_LANGUAGE[ext]}`,
+			);
+		}
+	}
+
+	const displayName = manifest['displayName'];
+	const nameField = manifest['nameField'];
+	const commentNodeTypes = stringList(manifest, 'commentNodeTypes');
+
+	return {
+		name,
+		displayName:
+			typeof displayName === 'string' && displayName.trim()
+				? displayName
+				: name,
+		manifestPath,
+		grammarPath: path.resolve(path.dirname(manifestPath), grammar),
+		extensions,
+		functionNodeTypes: stringList(manifest, 'functionNodeTypes'),
+		classNodeTypes: stringList(manifest, 'classNodeTypes'),
+		methodNodeTypes: stringList(manifest, 'methodNodeTypes'),
+		importNodeTypes: stringList(manifest, 'importNodeTypes'),
+		callNodeTypes: stringList(manifest, 'callNodeTypes'),
+		nameField: typeof nameField === 'string' && nameField ? nameField : 'name',
+		commentNodeTypes:
+			commentNodeTypes.length > 0 ? commentNodeTypes : ['comment'],
+		docCommentPrefixes: stringList(manifest, 'docCommentPrefixes'),
+	};
+}
+
+function stringList(manifest: Record<string, unknown>, key: string): string[] {
+	const value = manifest[key];
+	if (value === undefined) return [];
+	if (!Array.isArray(value) || value.some(v => typeof v !== 'string')) {
+		throw new Error(`"${key}" must be an array of strings`);
+	}
+	return (value as string[]).filter(v => v.length > 0);
+}
+
+async function listUserManifests(): Promise<string[]> {
+	const dir = getUserLanguagesDir();
+	try {
+		const entries = await fs.readdir(dir);
+		return entries
+			.filter(entry => entry.endsWith('.json'))
+			.sort()
+			.map(entry => path.join(dir, entry));
+	} catch {
+		return [];
+	}
+}
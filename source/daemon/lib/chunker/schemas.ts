@@ -0,0 +1,741 @@
+/**
+ * Schema and interface-definition files - protobuf, GraphQL, OpenAPI and SQL
+ * DDL. Each scanner outlines the contract's definitions (messages, services,
+ * RPCs, types, operations, tables and columns) and its references to other
+ * definitions, so contract names are searchable like code symbols.
+ */
+
+import path from 'node:path';
+import {childKeys, childValue, readKeyLines, type KeyFormat} from './keys.js';
+import {
+	type FileOutline,
+	type LexicalSyntax,
+	maskLexical,
+	matchingBracket,
+	OutlineBuilder,
+	qualify,
+	scanLexical,
+} from './outline.js';
+
+/**
+ * Outline a schema file, or null when the file is not one. YAML and JSON
+ * files are only outlined when they are OpenAPI/Swagger documents.
+ */
+export function outlineSchemaFile(
+	filepath: string,
+	content: string,
+): FileOutline | null {
+	switch (path.extname(filepath).toLowerCase()) {
+		case '.proto':
+			return outlineProtobuf(content);
+		case '.graphql':
+		case '.gql':
+			return outlineGraphql(content);
+		case '.sql':
+			return outlineSql(content);
+		case '.yaml':
+		case '.yml':
+			return outlineOpenApi(content, 'yaml');
+		case '.json':
+			return outlineOpenApi(content, 'json');
+		default:
+			return null;
+	}
+}
+
+// ============================================================================
+// Protobuf
+// ============================================================================
+
+const PROTO_SYNTAX: LexicalSyntax = {
+	lineComments: ['//'],
+	blockComment: ['/*', '*/'],
+	quotes: ['"', "'"],
+};
+
+const PROTO_SCALARS = new Set([
+	'double',
+	'float',
+	'int32',
+	'int64',
+	'uint32',
+	'uint64',
+	'sint32',
+	'sint64',
+	'fixed32',
+	'fixed64',
+	'sfixed32',
+	'sfixed64',
+	'bool',
+	'string',
+	'bytes',
+]);
+
+type ProtoScope = 'file' | 'message' | 'enum' | 'service';
+
+const PROTO_PATTERNS: Record<ProtoScope, string> = {
+	file: String.raw`\b(message|enum|service|extend)\s+([\w.]+)\s*\{|\bimport\s+(?:(?:public|weak)\s+)?"|\bpackage\s+([\w.]+)\s*;`,
+	message: String.raw`\b(message|enum|oneof|extend)\s+([\w.]+)\s*\{|\b(?:(?:repeated|optional|required)\s+)?(map\s*<[^>]*>|[\w.]+)\s+(\w+)\s*=\s*\d+[^;]*;`,
+	enum: String.raw`\b(\w+)\s*=\s*-?\d+[^;]*;`,
+	service: String.raw`\brpc\s+(\w+)\s*\(\s*(?:stream\s+)?([\w.]+)\s*\)\s*returns\s*\(\s*(?:stream\s+)?([\w.]+)\s*\)\s*([;{])`,
+};
+
+/**
+ * Protobuf: messages (fields), enums (values), services (RPCs), imports and
+ * references to message types.
+ */
+export function outlineProtobuf(content: string): FileOutline {
+	const masked = maskLexical(content, scanLexical(content, PROTO_SYNTAX));
+	const outline = new OutlineBuilder(content, ['//']);
+	let namespace: string | null = null;
+
+	const typeRef = (type: string, offset: number) => {
+		const name = type.split('.').pop()!;
+		if (!PROTO_SCALARS.has(name)) {
+			outline.ref('type_ref', name, offset + type.length - name.length);
+		}
+	};
+
+	const scan = (
+		scope: ProtoScope,
+		start: number,
+		end: number,
+		parent: string | null,
+	) => {
+		const pattern = new RegExp(PROTO_PATTERNS[scope], 'g');
+		pattern.lastIndex = start;
+		for (
+			let m = pattern.exec(masked);
+			m && m.index < end;
+			m = pattern.exec(masked)
+		) {
+			const text = m[0];
+			if (scope === 'enum') {
+				outline.define(
+					'variant',
+					m[1]!,
+					parent,
+					m.index,
+					m.index + text.length,
+					text.slice(0, -1),
+				);
+				continue;
+			}
+			if (scope === 'service') {
+				let rpcEnd = m.index + text.length;
+				if (m[4] === '{') rpcEnd = matchingBracket(masked, rpcEnd - 1);
+				outline.define(
+					'rpc',
+					m[1]!,
+					parent,
+					m.index,
+					rpcEnd,
+					content.slice(m.index, m.index + text.length - 1),
+				);
+				typeRef(m[2]!, m.index + text.indexOf(m[2]!, text.indexOf('(')));
+				typeRef(m[3]!, m.index + text.lastIndexOf(m[3]!));
+				pattern.lastIndex = rpcEnd;
+				continue;
+			}
+
+			const keyword = m[1];
+			if (keyword) {
+				const open = m.index + text.length - 1;
+				const close = matchingBracket(masked, open);
+				const name = m[2]!;
+				if (keyword === 'extend') {
+					typeRef(name, m.index + text.indexOf(name));
+				} else if (keyword === 'oneof') {
+					// oneof members are fields of the enclosing message
+					scan('message', open + 1, close - 1, parent);
+				} else {
+					const blockScope = keyword as 'message' | 'enum' | 'service';
+					outline.define(
+						blockScope,
+						name,
+						parent,
+						m.index,
+						close,
+						`${keyword} ${name}`,
+					);
+					scan(blockScope, open + 1, close - 1, qualify(parent, name));
+				}
+				pattern.lastIndex = close;
+				continue;
+			}
+
+			if (scope === 'file') {
+				if (m[3]) {
+					namespace = m[3];
+					continue;
+				}
+				// import "path/to/file.proto";
+				const pathStart = m.index + text.length;
+				const pathEnd = content.indexOf('"', pathStart);
+				if (pathEnd === -1) continue;
+				const modulePath = content.slice(pathStart, pathEnd);
+				const stem = path.basename(modulePath).replace(/\.proto$/, '');
+				outline.ref(
+					'import',
+					stem,
+					pathStart + modulePath.lastIndexOf(stem),
+					modulePath,
+				);
+				continue;
+			}
+
+			// Field: [label] type name = number [options];
+			const type = m[3]!;
+			outline.define(
+				'field',
+				m[4]!,
+				parent,
+				m.index,
+				m.index + text.length,
+				content.slice(m.index, m.index + text.length - 1),
+			);
+			const valueType = type.startsWith('map')
+				? type.match(/,\s*([\w.]+)\s*>$/)?.[1]
+				: type;
+			if (valueType) {
+				typeRef(valueType, m.index + text.indexOf(valueType));
+			}
+		}
+	};
+
+	scan('file', 0, masked.length, null);
+	return outline.build('protobuf', namespace);
+}
+
+// ============================================================================
+// GraphQL
+// ============================================================================
+
+const GRAPHQL_SYNTAX: LexicalSyntax = {
+	lineComments: ['#'],
+	quotes: ['"'],
+	blockQuotes: ['"""'],
+};
+
+const GRAPHQL_SCALARS = new Set(['ID', 'String', 'Int', 'Float', 'Boolean']);
+
+const GRAPHQL_ROOT_TYPES = new Set(['Query', 'Mutation', 'Subscription']);
+
+const GRAPHQL_DEFINITION_PATTERN =
+	/\b(?:extend\s+)?(type|input|interface|enum)\s+(\w+)[^{}]*?\{|\bunion\s+(\w+)[^=]*=\s*\|?\s*\w+(?:\s*\|\s*\w+)*|\bscalar\s+(\w+)|\b(query|mutation|subscription|fragment)\s+(\w+)[^{]*\{/g;
+
+const GRAPHQL_FIELD_PATTERN = /\b(\w+)\s*(\([^)]*\))?\s*:\s*([\w[\]!]+)/g;
+
+/**
+ * GraphQL: object, input and interface types with their fields (root
+ * `Query`/`Mutation`/`Subscription` types as services of RPCs), enums,
+ * unions, scalars and named operations/fragments. Descriptions are docs.
+ */
+export function outlineGraphql(content: string): FileOutline {
+	const ranges = scanLexical(content, GRAPHQL_SYNTAX);
+	const masked = maskLexical(content, ranges);
+	const outline = new OutlineBuilder(content, ['#']);
+
+	// Descriptions precede what they describe
+	const descriptions = new Map<number, string>();
+	const nonSpace = /\S/g;
+	for (const [start, end] of ranges.strings) {
+		nonSpace.lastIndex = end;
+		const next = nonSpace.exec(content);
+		if (!next) continue;
+		const quote = content.startsWith('"""', start) ? 3 : 1;
+		const text = dedent(content.slice(start + quote, end - quote));
+		if (text) descriptions.set(next.index, text);
+	}
+	// Without a description, `#` comments above are the doc
+	const docAt = (offset: number) => descriptions.get(offset);
+
+	const typeRef = (type: string, offset: number) => {
+		const name = type.replace(/[[\]!]/g, '');
+		if (!GRAPHQL_SCALARS.has(name)) {
+			outline.ref('type_ref', name, offset + type.indexOf(name));
+		}
+	};
+
+	GRAPHQL_DEFINITION_PATTERN.lastIndex = 0;
+	for (
+		let m = GRAPHQL_DEFINITION_PATTERN.exec(masked);
+		m;
+		m = GRAPHQL_DEFINITION_PATTERN.exec(masked)
+	) {
+		const text = m[0];
+		const start = m.index;
+		if (m[3]) {
+			outline.define(
+				'type_alias',
+				m[3],
+				null,
+				start,
+				start + text.length,
+				content.slice(start, start + text.length),
+				docAt(start),
+			);
+			const members = text.slice(text.indexOf('=') + 1);
+			for (const member of members.matchAll(/\w+/g)) {
+				typeRef(member[0], start + text.indexOf('=') + 1 + member.index);
+			}
+			continue;
+		}
+		if (m[4]) {
+			outline.define(
+				'type_alias',
+				m[4],
+				null,
+				start,
+				start + text.length,
+				text,
+				docAt(start),
+			);
+			continue;
+		}
+
+		const open = start + text.length - 1;
+		const close = matchingBracket(masked, open);
+		GRAPHQL_DEFINITION_PATTERN.lastIndex = close;
+		const header = content.slice(start, open);
+
+		if (m[5]) {
+			outline.define(
+				'operation',
+				m[6]!,
+				null,
+				start,
+				close,
+				header,
+				docAt(start),
+			);
+			continue;
+		}
+
+		const keyword = m[1]!;
+		const name = m[2]!;
+		const isRoot = keyword === 'type' && GRAPHQL_ROOT_TYPES.has(name);
+		outline.define(
+			keyword === 'enum' ? 'enum' : isRoot ? 'service' : 'message',
+			name,
+			null,
+			start,
+			close,
+			header,
+			docAt(start),
+		);
+		for (const supertype of header.matchAll(/(?:implements|&)\s*(\w+)/g)) {
+			const typeName = supertype[1]!;
+			typeRef(
+				typeName,
+				start + supertype.index + supertype[0].lastIndexOf(typeName),
+			);
+		}
+
+		// Directive arguments (`@cost(weight: 5)`) are not fields
+		const body = masked
+			.slice(open + 1, close - 1)
+			.replace(/@\w+(?:\s*\([^)]*\))?/g, d => ' '.repeat(d.length));
+		if (keyword === 'enum') {
+			for (const value of body.matchAll(/\b[_A-Za-z]\w*\b/g)) {
+				const offset = open + 1 + value.index;
+				outline.define(
+					'variant',
+					value[0],
+					name,
+					offset,
+					offset + value[0].length,
+					value[0],
+					docAt(offset),
+				);
+			}
+			continue;
+		}
+
+		for (const field of body.matchAll(GRAPHQL_FIELD_PATTERN)) {
+			const offset = open + 1 + field.index;
+			outline.define(
+				isRoot ? 'rpc' : 'field',
+				field[1]!,
+				name,
+				offset,
+				offset + field[0].length,
+				content.slice(offset, offset + field[0].length),
+				docAt(offset),
+			);
+			const args = field[2];
+			if (args) {
+				const argsOffset = offset + field[0].indexOf(args);
+				for (const arg of args.matchAll(/:\s*([\w[\]!]+)/g)) {
+					typeRef(arg[1]!, argsOffset + arg.index + arg[0].indexOf(arg[1]!));
+				}
+			}
+			typeRef(field[3]!, offset + field[0].length - field[3]!.length);
+		}
+	}
+
+	return outline.build('graphql');
+}
+
+function dedent(text: string): string {
+	const lines = text.split('\n');
+	const indents = lines
+		.slice(1)
+		.filter(l => l.trim())
+		.map(l => l.length - l.trimStart().length);
+	const indent = indents.length > 0 ? Math.min(...indents) : 0;
+	return [lines[0]!, ...lines.slice(1).map(l => l.slice(indent))]
+		.join('\n')
+		.trim();
+}
+
+// ============================================================================
+// SQL DDL
+// ============================================================================
+
+const SQL_SYNTAX: LexicalSyntax = {
+	lineComments: ['--'],
+	blockComment: ['/*', '*/'],
+	quotes: ["'"],
+	// Function bodies
+	blockQuotes: ['$$'],
+};
+
+const SQL_IDENT = String.raw`(?:"[^"]+"|\x60[^\x60]+\x60|\[[^\]]+\]|[\w$]+)`;
+const SQL_NAME = String.raw`(${SQL_IDENT}(?:\s*\.\s*${SQL_IDENT})*)`;
+
+const SQL_STATEMENT_PATTERN = new RegExp(
+	[
+		String.raw`\bcreate\s+(?:or\s+replace\s+)?(?:(?:global\s+|local\s+)?(?:temp|temporary)\s+|unlogged\s+)?table\s+(?:if\s+not\s+exists\s+)?${SQL_NAME}\s*\(`,
+		String.raw`\bcreate\s+(?:or\s+replace\s+)?(?:materialized\s+)?view\s+(?:if\s+not\s+exists\s+)?${SQL_NAME}`,
+		String.raw`\bcreate\s+type\s+${SQL_NAME}\s+as\s+enum\s*\(`,
+		String.raw`\bcreate\s+(?:or\s+replace\s+)?(?:function|procedure)\s+${SQL_NAME}\s*\(`,
+		String.raw`\balter\s+table\s+(?:if\s+exists\s+)?(?:only\s+)?${SQL_NAME}\s+add\s+(?:column\s+)?(?:if\s+not\s+exists\s+)?(${SQL_IDENT})`,
+		String.raw`\b(?:references|create\s+(?:unique\s+)?index\b[^;]*?\bon)\s+(?:only\s+)?${SQL_NAME}`,
+	].join('|'),
+	'gi',
+);
+
+const SQL_LEADING_IDENT = new RegExp(`^${SQL_IDENT}`);
+const SQL_IDENTS = new RegExp(SQL_IDENT, 'g');
+
+const SQL_CONSTRAINT_START =
+	/^(?:constraint|primary|foreign|unique|check|exclude|index|key|fulltext|spatial|period)\b/i;
+
+/**
+ * SQL DDL: tables and views with their columns (including `ALTER TABLE ...
+ * ADD COLUMN`), enum types and functions. `REFERENCES` and index targets
+ * are refs to tables.
+ */
+export function outlineSql(content: string): FileOutline {
+	const masked = maskLexical(content, scanLexical(content, SQL_SYNTAX));
+	const outline = new OutlineBuilder(content, ['--']);
+
+	const statementEnd = (from: number) => {
+		const semicolon = masked.indexOf(';', from);
+		return semicolon === -1 ? masked.length : semicolon + 1;
+	};
+	const tableRef = (qualified: string, offset: number) => {
+		const name = sqlName(qualified);
+		outline.ref('identifier', name, offset + qualified.lastIndexOf(name));
+	};
+
+	SQL_STATEMENT_PATTERN.lastIndex = 0;
+	for (
+		let m = SQL_STATEMENT_PATTERN.exec(masked);
+		m;
+		m = SQL_STATEMENT_PATTERN.exec(masked)
+	) {
+		const text = m[0];
+		const start = m.index;
+		const [, table, view, enumType, fn, alteredTable, column, target] = m;
+
+		if (table) {
+			const name = sqlName(table);
+			const open = start + text.length - 1;
+			const close = matchingBracket(masked, open);
+			const end = statementEnd(close);
+			outline.define(
+				'table',
+				name,
+				null,
+				start,
+				end,
+				content.slice(start, open),
+			);
+			for (const item of splitTopLevel(masked, open + 1, close - 1)) {
+				const definition = masked.slice(item.start, item.end);
+				if (SQL_CONSTRAINT_START.test(definition)) continue;
+				const columnName = definition.match(SQL_LEADING_IDENT)?.[0];
+				if (!columnName) continue;
+				outline.define(
+					'column',
+					sqlName(columnName),
+					name,
+					item.start,
+					item.end,
+					content.slice(item.start, item.end),
+					trailingComment(content, masked, item.end),
+				);
+			}
+			SQL_STATEMENT_PATTERN.lastIndex = open + 1;
+			continue;
+		}
+		if (view) {
+			const end = statementEnd(start);
+			outline.define('table', sqlName(view), null, start, end, text);
+			continue;
+		}
+		if (enumType) {
+			const name = sqlName(enumType);
+			const open = start + text.length - 1;
+			const close = matchingBracket(masked, open);
+			outline.define(
+				'enum',
+				name,
+				null,
+				start,
+				statementEnd(close),
+				content.slice(start, open),
+			);
+			const values = content.slice(open, close);
+			for (const value of values.matchAll(/'((?:[^']|'')*)'/g)) {
+				const offset = open + value.index;
+				outline.define(
+					'variant',
+					value[1]!.replace(/''/g, "'"),
+					name,
+					offset,
+					offset + value[0].length,
+					value[0],
+				);
+			}
+			SQL_STATEMENT_PATTERN.lastIndex = close;
+			continue;
+		}
+		if (fn) {
+			const open = start + text.length - 1;
+			outline.define(
+				'function',
+				sqlName(fn),
+				null,
+				start,
+				statementEnd(open),
+				content.slice(start, matchingBracket(masked, open)),
+			);
+			continue;
+		}
+		if (alteredTable && column) {
+			const name = sqlName(alteredTable);
+			const columnStart = start + text.lastIndexOf(column);
+			const end = Math.min(
+				statementEnd(columnStart),
+				nextComma(masked, columnStart),
+			);
+			outline.define(
+				'column',
+				sqlName(column),
+				name,
+				start,
+				end,
+				content.slice(columnStart, end).replace(/[;,]$/, ''),
+			);
+			tableRef(alteredTable, start + text.indexOf(alteredTable));
+			continue;
+		}
+		if (target) {
+			tableRef(target, start + text.length - target.length);
+		}
+	}
+
+	return outline.build('sql');
+}
+
+/**
+ * Unquoted last segment of a (possibly schema-qualified) SQL name.
+ */
+function sqlName(qualified: string): string {
+	const last = qualified.match(SQL_IDENTS)?.pop() ?? qualified;
+	return last.replace(/^["`[]|["`\]]$/g, '');
+}
+
+function splitTopLevel(
+	masked: string,
+	start: number,
+	end: number,
+): Array<{start: number; end: number}> {
+	const items: Array<{start: number; end: number}> = [];
+	let depth = 0;
+	let itemStart = start;
+	const push = (itemEnd: number) => {
+		const text = masked.slice(itemStart, itemEnd);
+		const leading = text.length - text.trimStart().length;
+		const trailing = text.length - text.trimEnd().length;
+		if (text.trim()) {
+			items.push({start: itemStart + leading, end: itemEnd - trailing});
+		}
+	};
+	for (let i = start; i < end; i++) {
+		const ch = masked[i];
+		if (ch === '(') depth++;
+		else if (ch === ')') depth--;
+		else if (ch === ',' && depth === 0) {
+			push(i);
+			itemStart = i + 1;
+		}
+	}
+	push(end);
+	return items;
+}
+
+function nextComma(masked: string, from: number): number {
+	const comma = masked.indexOf(',', from);
+	return comma === -1 ? masked.length : comma;
+}
+
+/**
+ * A `-- comment` on the same line after a column definition.
+ */
+function trailingComment(
+	content: string,
+	masked: string,
+	from: number,
+): string | null {
+	const lineEnd = content.indexOf('\n', from);
+	const rest = content.slice(from, lineEnd === -1 ? content.length : lineEnd);
+	const restMasked = masked.slice(from, from + rest.length);
+	const comment = rest.indexOf('--');
+	if (comment === -1 || restMasked.slice(0, comment).replace(/[,\s]/g, '')) {
+		return null;
+	}
+	return rest.slice(comment + 2).trim() || null;
+}
+
+// ============================================================================
+// OpenAPI / Swagger
+// ============================================================================
+
+const HTTP_METHODS = new Set([
+	'get',
+	'put',
+	'post',
+	'delete',
+	'options',
+	'head',
+	'patch',
+	'trace',
+]);
+
+const SCHEMA_REF_PATTERN = /^#\/(?:components\/schemas|definitions)\/(.+)$/;
+
+/**
+ * A top-level `openapi`/`swagger` key, checked before reading the keys of
+ * every YAML/JSON file.
+ */
+const OPENAPI_VERSION_KEY =
+	/^(?:["']?(?:openapi|swagger)["']?|\s+"(?:openapi|swagger)")\s*:/m;
+
+/**
+ * OpenAPI 3 / Swagger 2: paths as endpoints, operations (named by
+ * `operationId`), and schemas with their properties. Internal `$ref`s are
+ * refs to schemas. Returns null for other YAML/JSON documents.
+ */
+export function outlineOpenApi(
+	content: string,
+	format: KeyFormat,
+): FileOutline | null {
+	if (!OPENAPI_VERSION_KEY.test(content)) return null;
+	const keys = readKeyLines(content, format);
+	const topLevel = childKeys(keys, -1);
+	if (!topLevel.some(k => k.key === 'openapi' || k.key === 'swagger')) {
+		return null;
+	}
+
+	const outline = new OutlineBuilder(content, ['#']);
+	const indexOf = new Map(keys.map((key, i) => [key, i]));
+	const define = (
+		kind: 'endpoint' | 'operation' | 'message' | 'field',
+		name: string,
+		parent: string | null,
+		index: number,
+		signature: string,
+	) => {
+		const key = keys[index]!;
+		outline.defineLines(
+			kind,
+			name,
+			parent,
+			key.line + 1,
+			key.endLine + 1,
+			signature,
+			childValue(keys, index, 'summary') ??
+				childValue(keys, index, 'description'),
+		);
+	};
+
+	const paths = topLevel.find(k => k.key === 'paths');
+	for (const pathKey of paths ? childKeys(keys, indexOf.get(paths)!) : []) {
+		const pathIndex = indexOf.get(pathKey)!;
+		define('endpoint', pathKey.key, null, pathIndex, pathKey.key);
+		for (const methodKey of childKeys(keys, pathIndex)) {
+			if (!HTTP_METHODS.has(methodKey.key.toLowerCase())) continue;
+			const methodIndex = indexOf.get(methodKey)!;
+			const route = `${methodKey.key.toUpperCase()} ${pathKey.key}`;
+			define(
+				'operation',
+				childValue(keys, methodIndex, 'operationId') ?? route,
+				null,
+				methodIndex,
+				route,
+			);
+		}
+	}
+
+	// OpenAPI 3 `components.schemas`, Swagger 2 `definitions`
+	const components = topLevel.find(k => k.key === 'components');
+	const schemas =
+		(components &&
+			childKeys(keys, indexOf.get(components)!).find(
+				k => k.key === 'schemas',
+			)) ??
+		topLevel.find(k => k.key === 'definitions');
+	const schemaKeys = schemas ? childKeys(keys, indexOf.get(schemas)!) : [];
+	for (const schemaKey of schemaKeys) {
+		const schemaIndex = indexOf.get(schemaKey)!;
+		const name = schemaKey.key;
+		define('message', name, null, schemaIndex, `schema ${name}`);
+		const properties = childKeys(keys, schemaIndex).find(
+			k => k.key === 'properties',
+		);
+		if (!properties) continue;
+		for (const property of childKeys(keys, indexOf.get(properties)!)) {
+			const propertyIndex = indexOf.get(property)!;
+			const ref = childValue(keys, propertyIndex, '$ref');
+			const type =
+				ref?.match(SCHEMA_REF_PATTERN)?.[1] ??
+				childValue(keys, propertyIndex, 'type') ??
+				'object';
+			define(
+				'field',
+				property.key,
+				name,
+				propertyIndex,
+				`${property.key}: ${type}`,
+			);
+		}
+	}
+
+	for (const key of keys) {
+		if (key.key !== '$ref' || key.value === null) continue;
+		const target = key.value.match(SCHEMA_REF_PATTERN)?.[1];
+		if (target) {
+			outline.ref(
+				'type_ref',
+				target,
+				key.valueOffset + key.value.length - target.length,
+			);
+		}
+	}
+
+	return outline.build('openapi');
+}
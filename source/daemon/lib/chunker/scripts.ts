@@ -0,0 +1,562 @@
+/**
+ * Build and shell scripts - shell, Makefiles and justfiles. Shell functions,
+ * Make targets and just recipes are outlined as symbols; calls between them
+ * (commands, prerequisites, dependencies, `make x`/`just x`) are call refs,
+ * and `source`/`include`/`import` statements are imports.
+ */
+
+import path from 'node:path';
+import {
+	blankRanges,
+	commentAbove,
+	type FileOutline,
+	matchingBracket,
+	OutlineBuilder,
+} from './outline.js';
+
+/**
+ * Outline a build or shell script, or null when the file is not one.
+ */
+export function outlineScriptFile(
+	filepath: string,
+	content: string,
+): FileOutline | null {
+	const basename = path.basename(filepath);
+	if (MAKEFILE_NAME.test(basename)) return outlineMakefile(content);
+	if (JUSTFILE_NAME.test(basename)) return outlineJustfile(content);
+	const extension = path.extname(basename).toLowerCase();
+	if (
+		SHELL_EXTENSIONS.has(extension) ||
+		(!extension && SHELL_SHEBANG.test(content))
+	) {
+		return outlineShell(content);
+	}
+	return null;
+}
+
+/**
+ * Stem of an included file (`"$DIR/lib/common.sh"` -> `common`), or null
+ * when it is computed (`$LIB`).
+ */
+function includedStem(file: string): string | null {
+	const stem = file
+		.replace(/["']/g, '')
+		.split('/')
+		.pop()!
+		.replace(/\.[^.]*$/, '');
+	return /^[\w-]+$/.test(stem) ? stem : null;
+}
+
+// ============================================================================
+// Shell
+// ============================================================================
+
+const SHELL_EXTENSIONS = new Set(['.sh', '.bash', '.zsh', '.ksh']);
+
+const SHELL_SHEBANG = /^#!.*\b(?:ba|z|k|da)?sh\b/;
+
+const SHELL_FUNCTION_PATTERN =
+	/^[ \t]*(?:function[ \t]+([\w.:-]+)(?:[ \t]*\(\))?|([\w.:-]+)[ \t]*\(\))[ \t]*(?:\n[ \t]*)?([{(])/gm;
+
+/**
+ * A word in command position: at the start of a line, after a separator
+ * or subshell, or after a keyword that takes a command.
+ */
+const SHELL_COMMAND_PATTERN =
+	/(^|[;&|`{]|(?<!=)\(|\b(?:then|do|else|elif|if|while|until|time|!)[ \t])[ \t]*([A-Za-z_][\w.:-]*)(?=[ \t;&|`)]|$)/gm;
+
+const SHELL_SOURCE_PATTERN =
+	/(?:^|[;&|]|\b(?:then|do|else)[ \t])[ \t]*(?:source|\.)[ \t]+(?=\S)/gm;
+
+const SHELL_HEREDOC_START = /<<(-?)[ \t]*(["']?)([A-Za-z_][\w.-]*)\2/y;
+
+/**
+ * Keywords and builtins: not calls worth linking.
+ */
+const SHELL_BUILTINS = new Set([
+	'if',
+	'then',
+	'else',
+	'elif',
+	'fi',
+	'for',
+	'while',
+	'until',
+	'do',
+	'done',
+	'case',
+	'esac',
+	'in',
+	'function',
+	'select',
+	'time',
+	'return',
+	'exit',
+	'local',
+	'export',
+	'declare',
+	'typeset',
+	'readonly',
+	'unset',
+	'shift',
+	'set',
+	'source',
+	'eval',
+	'exec',
+	'trap',
+	'wait',
+	'break',
+	'continue',
+	'true',
+	'false',
+	'echo',
+	'printf',
+	'read',
+	'cd',
+	'pwd',
+	'test',
+	'let',
+	'shopt',
+	'builtin',
+	'command',
+	'type',
+	'getopts',
+]);
+
+/**
+ * Shell scripts: functions (`name() {` and `function name {`). Commands
+ * are calls; `source`/`.` of a file is an import of its stem.
+ */
+export function outlineShell(content: string): FileOutline {
+	const masked = blankRanges(content, shellLiteralRanges(content));
+	const outline = new OutlineBuilder(content, ['#']);
+
+	for (const m of masked.matchAll(SHELL_FUNCTION_PATTERN)) {
+		const name = m[1] ?? m[2]!;
+		const open = m.index + m[0].length - 1;
+		const start = m.index + m[0].length - m[0].trimStart().length;
+		outline.define(
+			'function',
+			name,
+			null,
+			start,
+			matchingBracket(masked, open),
+			masked.slice(start, open),
+		);
+	}
+
+	for (const m of masked.matchAll(SHELL_COMMAND_PATTERN)) {
+		const command = m[2]!;
+		const end = m.index + m[0].length;
+		// `start)` is a case pattern, not a command
+		if (!m[1]!.trim() && masked[end] === ')') continue;
+		if (SHELL_BUILTINS.has(command)) continue;
+		outline.ref('call', command, end - command.length);
+	}
+
+	for (const m of masked.matchAll(SHELL_SOURCE_PATTERN)) {
+		const start = m.index + m[0].length;
+		const file = content.slice(start, shellWordEnd(content, start));
+		const stem = includedStem(file);
+		if (stem) {
+			outline.ref(
+				'import',
+				stem,
+				start + file.lastIndexOf(stem),
+				file.replace(/["']/g, ''),
+			);
+		}
+	}
+
+	return outline.build('shell');
+}
+
+/**
+ * Comment, string and heredoc ranges of a shell script, to blank. Quotes
+ * are kept, and command substitutions inside double quotes
+ * (`"$(dirname "$0")"`) stay code.
+ */
+function shellLiteralRanges(content: string): Array<[number, number]> {
+	const ranges: Array<[number, number]> = [];
+	let heredocs: Array<{word: string; stripTabs: boolean}> = [];
+
+	// Bodies and terminators of the heredocs opened on the line before `start`
+	const heredocBodies = (start: number): number => {
+		let i = start;
+		for (const {word, stripTabs} of heredocs) {
+			const bodyStart = i;
+			while (i < content.length) {
+				const newline = content.indexOf('\n', i);
+				const end = newline === -1 ? content.length : newline;
+				const line = content.slice(i, end).replace(/\r$/, '');
+				if ((stripTabs ? line.replace(/^\t+/, '') : line) === word) {
+					ranges.push([bodyStart, end]);
+					i = end;
+					break;
+				}
+				i = end + 1;
+			}
+			if (i >= content.length) ranges.push([bodyStart, content.length]);
+		}
+		heredocs = [];
+		return i;
+	};
+
+	const doubleQuoted = (start: number): number => {
+		let segment = start;
+		let i = start;
+		while (i < content.length && content[i] !== '"') {
+			if (content[i] === '\\') {
+				i += 2;
+			} else if (content.startsWith('$(', i)) {
+				ranges.push([segment, i]);
+				i = code(i + 2, true);
+				segment = i;
+			} else {
+				i++;
+			}
+		}
+		ranges.push([segment, Math.min(i, content.length)]);
+		return i + 1;
+	};
+
+	// Offset past the `)` closing a command substitution, or the end
+	const code = (start: number, substitution: boolean): number => {
+		let depth = 0;
+		let i = start;
+		while (i < content.length) {
+			const ch = content[i]!;
+			if (ch === '\\') {
+				i += 2;
+			} else if (ch === '\n' && heredocs.length > 0) {
+				i = heredocBodies(i + 1);
+			} else if (ch === '#' && /[\s;&|()]/.test(content[i - 1] ?? ' ')) {
+				const newline = content.indexOf('\n', i);
+				const end = newline === -1 ? content.length : newline;
+				ranges.push([i, end]);
+				i = end;
+			} else if (ch === "'") {
+				const close = content.indexOf("'", i + 1);
+				const end = close === -1 ? content.length : close;
+				ranges.push([i + 1, end]);
+				i = end + 1;
+			} else if (ch === '"') {
+				i = doubleQuoted(i + 1);
+			} else if (content.startsWith('<<<', i)) {
+				i += 3;
+			} else if (content.startsWith('<<', i)) {
+				SHELL_HEREDOC_START.lastIndex = i;
+				const heredoc = SHELL_HEREDOC_START.exec(content);
+				if (heredoc) {
+					const [, dash, , word] = heredoc;
+					heredocs.push({word: word!, stripTabs: dash === '-'});
+					i += heredoc[0].length;
+				} else {
+					i += 2;
+				}
+			} else {
+				if (substitution && ch === '(') depth++;
+				if (substitution && ch === ')' && depth-- === 0) return i + 1;
+				i++;
+			}
+		}
+		return i;
+	};
+
+	code(0, false);
+	return ranges.sort((a, b) => a[0] - b[0]);
+}
+
+/**
+ * End of the shell word starting at `start` (quotes and `$(...)` included).
+ */
+function shellWordEnd(content: string, start: number): number {
+	let quote: string | null = null;
+	let depth = 0;
+	let i = start;
+	for (; i < content.length; i++) {
+		const ch = content[i]!;
+		if (ch === '\\') {
+			i++;
+		} else if (quote) {
+			if (ch === quote) quote = null;
+		} else if (ch === '"' || ch === "'") {
+			quote = ch;
+		} else if (ch === '(') {
+			depth++;
+		} else if (ch === ')' && depth > 0) {
+			depth--;
+		} else if (depth === 0 && /[\s;&|)]/.test(ch)) {
+			break;
+		}
+	}
+	return i;
+}
+
+// ============================================================================
+// Makefile
+// ============================================================================
+
+const MAKEFILE_NAME = /^(?:GNUmakefile|[Mm]akefile)$|\.mk$/;
+
+/**
+ * `targets: prerequisites ## help text`, excluding assignments (`x := y`)
+ * and target-specific variables (`x: VAR = y`).
+ */
+const MAKE_RULE_PATTERN =
+	/^([^\t#:=\s][^#:=]*?)[ \t]*::?(?!=)[ \t]*([^=#\n]*?)[ \t]*(?:##?[ \t]*(.*?))?[ \t]*$/;
+
+const MAKE_INCLUDE_PATTERN = /^[ \t]*-?s?include[ \t]+(.+?)[ \t]*$/;
+
+const MAKE_INVOCATION_PATTERN = /(?:\$[({]MAKE[)}]|\bmake\b)([^;&|\n]*)/g;
+
+/**
+ * Makefiles: explicit targets (not special or pattern targets), with
+ * comments above or a trailing `## help` as docs. Prerequisites and
+ * `$(MAKE) target` in recipes are calls; `include`d files are imports.
+ */
+export function outlineMakefile(content: string): FileOutline {
+	const outline = new OutlineBuilder(content, ['#']);
+	const lines = outline.lines;
+	let offset = 0;
+	let inDefine = false;
+
+	for (let i = 0; i < lines.length; offset += lines[i]!.length + 1, i++) {
+		const text = lines[i]!.replace(/\r$/, '');
+		if (/^[ \t]*define\b/.test(text)) inDefine = true;
+		if (inDefine) {
+			if (/^[ \t]*endef\b/.test(text)) inDefine = false;
+			continue;
+		}
+
+		const include = text.match(MAKE_INCLUDE_PATTERN);
+		if (include) {
+			const column = text.indexOf(include[1]!);
+			for (const file of include[1]!.matchAll(/\S+/g)) {
+				const stem = includedStem(file[0]);
+				if (!stem) continue;
+				outline.ref(
+					'import',
+					stem,
+					offset + column + file.index + file[0].lastIndexOf(stem),
+					file[0],
+				);
+			}
+			continue;
+		}
+
+		// Recipe lines run make on other targets
+		if (text.startsWith('\t')) {
+			for (const m of text.matchAll(MAKE_INVOCATION_PATTERN)) {
+				const argsColumn = m.index + m[0].length - m[1]!.length;
+				for (const goal of makeGoals(m[1]!)) {
+					outline.ref('call', goal.name, offset + argsColumn + goal.column);
+				}
+			}
+			continue;
+		}
+
+		const rule = text.match(MAKE_RULE_PATTERN);
+		if (!rule) continue;
+		const targets = rule[1]!.split(/\s+/).filter(isMakeTarget);
+		// `.PHONY: build test` declares targets, it does not depend on them
+		if (targets.length === 0) continue;
+		const prerequisites = rule[2]!;
+
+		let endLine = i;
+		for (let j = i + 1; j < lines.length; j++) {
+			if (lines[j]!.startsWith('\t')) endLine = j;
+			else if (lines[j]!.trim() && !lines[j]!.trim().startsWith('#')) break;
+		}
+		const docstring =
+			commentAbove(lines, i, ['#']) ?? (rule[3]?.trim() || null);
+		for (const target of targets) {
+			outline.defineLines(
+				'target',
+				target,
+				null,
+				i + 1,
+				endLine + 1,
+				text.replace(/[ \t]*##?.*$/, ''),
+				docstring,
+				`target ${target}`,
+			);
+		}
+
+		const prerequisitesColumn = text.indexOf(
+			prerequisites,
+			text.search(/:/) + 1,
+		);
+		for (const m of prerequisites.matchAll(/\S+/g)) {
+			if (!isMakeTarget(m[0])) continue;
+			outline.ref('call', m[0], offset + prerequisitesColumn + m.index);
+		}
+	}
+
+	return outline.build('make');
+}
+
+/**
+ * Named targets, not special (`.PHONY`), pattern (`%.o`) or computed
+ * (`$(BIN)`) ones, nor the `|` of order-only prerequisites.
+ */
+function isMakeTarget(name: string): boolean {
+	return /^\w[\w.-]*$/.test(name);
+}
+
+/**
+ * Goals of a `make` command line: arguments that are not options, option
+ * values (`-C dir`) or variable assignments (`VAR=x`).
+ */
+function makeGoals(args: string): Array<{name: string; column: number}> {
+	const goals: Array<{name: string; column: number}> = [];
+	let skipValue = false;
+	for (const m of args.matchAll(/\S+/g)) {
+		const word = m[0];
+		if (skipValue) {
+			skipValue = false;
+		} else if (word.startsWith('-')) {
+			skipValue = /^-[CfIjoW]$/.test(word);
+		} else if (!word.includes('=') && isMakeTarget(word)) {
+			goals.push({name: word, column: m.index});
+		}
+	}
+	return goals;
+}
+
+// ============================================================================
+// justfile
+// ============================================================================
+
+const JUSTFILE_NAME = /^\.?[Jj]ustfile$|\.just$/;
+
+/**
+ * `[@]name params: dependencies`, excluding assignments and settings
+ * (`x := y`, `set shell := [...]`, `alias b := build`).
+ */
+const JUST_RECIPE_PATTERN =
+	/^@?([A-Za-z_][\w-]*)((?:[ \t]+(?:[^:\n"']|"[^"\n]*"|'[^'\n]*')*?)?)[ \t]*:(?!=)[ \t]*(.*?)[ \t]*(?:#.*)?$/;
+
+const JUST_ALIAS_PATTERN = /^alias[ \t]+[\w-]+[ \t]*:=[ \t]*([\w-]+)/;
+
+const JUST_IMPORT_PATTERN =
+	/^(?:import\??[ \t]+(["'])(.+?)\1|mod\??[ \t]+([\w-]+)(?:[ \t]+(["'])(.+?)\4)?)/;
+
+const JUST_ATTRIBUTE_PATTERN = /^\[.*\][ \t]*$/;
+
+/**
+ * justfiles: recipes, with comments above or a `[doc(...)]` attribute as
+ * docs. Dependencies, aliases and `just recipe` in bodies are calls;
+ * `import`ed files and `mod`ules are imports.
+ */
+export function outlineJustfile(content: string): FileOutline {
+	const outline = new OutlineBuilder(content, ['#']);
+	const lines = outline.lines;
+	let offset = 0;
+
+	for (let i = 0; i < lines.length; offset += lines[i]!.length + 1, i++) {
+		const text = lines[i]!.replace(/\r$/, '');
+
+		// Recipe bodies run just on other recipes
+		if (/^[ \t]/.test(text)) {
+			for (const m of text.matchAll(/\bjust\b((?:[ \t]+[\w-]+)+)/g)) {
+				const argsColumn = m.index + m[0].length - m[1]!.length;
+				for (const arg of m[1]!.matchAll(/[\w-]+/g)) {
+					outline.ref('call', arg[0], offset + argsColumn + arg.index);
+				}
+			}
+			continue;
+		}
+
+		const alias = text.match(JUST_ALIAS_PATTERN);
+		if (alias) {
+			outline.ref('call', alias[1]!, offset + text.lastIndexOf(alias[1]!));
+			continue;
+		}
+
+		const imported = text.match(JUST_IMPORT_PATTERN);
+		if (imported) {
+			const file = imported[2] ?? imported[5];
+			const stem = imported[3] ?? includedStem(file!);
+			if (stem) {
+				outline.ref(
+					'import',
+					stem,
+					offset + text.indexOf(stem),
+					file ?? stem,
+				);
+			}
+			continue;
+		}
+
+		const recipe = text.match(JUST_RECIPE_PATTERN);
+		if (!recipe || /^(?:set|export)\b/.test(text)) continue;
+		const name = recipe[1]!;
+
+		let endLine = i;
+		for (let j = i + 1; j < lines.length; j++) {
+			if (/^[ \t]+\S/.test(lines[j]!)) endLine = j;
+			else if (lines[j]!.trim()) break;
+		}
+
+		// Attributes (`[private]`, `[doc('...')]`) sit between the comment
+		// and the recipe
+		let above = i;
+		let docAttribute: string | null = null;
+		while (above > 0 && JUST_ATTRIBUTE_PATTERN.test(lines[above - 1]!)) {
+			above--;
+			docAttribute ??=
+				lines[above]!.match(/\bdoc\(\s*(["'])(.*?)\1\s*\)/)?.[2] ?? null;
+		}
+
+		outline.defineLines(
+			'target',
+			name,
+			null,
+			i + 1,
+			endLine + 1,
+			text.replace(/[ \t]*#.*$/, ''),
+			docAttribute ?? commentAbove(lines, above, ['#']),
+			`recipe ${name}`,
+		);
+
+		const dependencies = recipe[3]!;
+		const dependenciesColumn = text.lastIndexOf(dependencies);
+		for (const dependency of justDependencies(dependencies)) {
+			outline.ref(
+				'call',
+				dependency.name,
+				offset + dependenciesColumn + dependency.column,
+			);
+		}
+	}
+
+	return outline.build('just');
+}
+
+/**
+ * Recipe names in a dependency list: `build (test "unit") && deploy`.
+ * Arguments of parenthesised dependencies are not recipes.
+ */
+function justDependencies(
+	text: string,
+): Array<{name: string; column: number}> {
+	const names: Array<{name: string; column: number}> = [];
+	const unquoted = text.replace(/"[^"]*"|'[^']*'/g, m => ' '.repeat(m.length));
+	let depth = 0;
+	let firstInGroup = false;
+	for (const m of unquoted.matchAll(/[()]|[A-Za-z_][\w-]*/g)) {
+		if (m[0] === '(') {
+			depth++;
+			firstInGroup = true;
+		} else if (m[0] === ')') {
+			depth--;
+		} else {
+			if (depth === 0 || firstInGroup) {
+				names.push({name: m[0], column: m.index});
+			}
+			firstInGroup = false;
+		}
+	}
+	return names;
+}
@@ -0,0 +1,190 @@
+/**
+ * Single-file components (Vue, Svelte, Astro).
+ *
+ * An SFC is split into sections: scripts (`<script>` blocks and Astro
+ * frontmatter), template markup and styles. Scripts are parsed with the
+ * TypeScript/TSX grammar *in place*: everything outside them is blanked to
+ * spaces (newlines and length preserved), so tree positions are positions in
+ * the original file.
+ */
+
+import path from 'node:path';
+
+export const SFC_EXTENSIONS = new Set(['.vue', '.svelte', '.astro']);
+
+export type SfcSectionKind = 'script' | 'template' | 'style';
+
+export type SfcSection = {
+	kind: SfcSectionKind;
+	/** Offsets of the whole block, including its tags/fences */
+	blockStart: number;
+	blockEnd: number;
+	/** Offsets of the content inside the tags/fences */
+	contentStart: number;
+	contentEnd: number;
+	/** `lang` attribute (`ts`, `tsx`, `scss`), null when absent */
+	lang: string | null;
+};
+
+/**
+ * A PascalCase component tag in template markup (`<CounterButton`).
+ */
+export type SfcComponentTag = {
+	name: string;
+	/** Offsets of the tag name */
+	start: number;
+	end: number;
+};
+
+const BLOCK_PATTERN = /<(script|style)(\s[^>]*)?>([\s\S]*?)<\/\1\s*>/gi;
+const ASTRO_FRONTMATTER_PATTERN = /^(\s*---\r?\n)([\s\S]*?)\r?\n---/;
+const LANG_ATTRIBUTE_PATTERN = /\blang\s*=\s*["']?([\w-]+)/i;
+const COMPONENT_TAG_PATTERN = /<([A-Z][A-Za-z0-9]*)(?=[\s/>.])/g;
+
+/**
+ * Split an SFC into its sections, in file order. Markup between script and
+ * style blocks becomes template sections.
+ */
+export function splitSfc(content: string, ext: string): SfcSection[] {
+	const blocks: SfcSection[] = [];
+	let searchFrom = 0;
+
+	if (ext.toLowerCase() === '.astro') {
+		const frontmatter = content.match(ASTRO_FRONTMATTER_PATTERN);
+		if (frontmatter) {
+			const contentStart = frontmatter[1]!.length;
+			blocks.push({
+				kind: 'script',
+				blockStart: 0,
+				blockEnd: frontmatter[0].length,
+				contentStart,
+				contentEnd: contentStart + frontmatter[2]!.length,
+				lang: 'ts',
+			});
+			searchFrom = frontmatter[0].length;
+		}
+	}
+
+	for (const match of content.matchAll(BLOCK_PATTERN)) {
+		if (match.index < searchFrom) continue;
+		const tag = match[1]!;
+		const attributes = match[2] ?? '';
+		// `<` + tag + attributes + `>`
+		const contentStart = match.index + tag.length + attributes.length + 2;
+		const lang = attributes.match(LANG_ATTRIBUTE_PATTERN)?.[1];
+		blocks.push({
+			kind: tag.toLowerCase() === 'script' ? 'script' : 'style',
+			blockStart: match.index,
+			blockEnd: match.index + match[0].length,
+			contentStart,
+			contentEnd: contentStart + match[3]!.length,
+			lang: lang?.toLowerCase() ?? null,
+		});
+	}
+
+	// Whatever is left between blocks is markup
+	const sections: SfcSection[] = [];
+	let cursor = 0;
+	for (const block of [...blocks, null]) {
+		const gapEnd = block ? block.blockStart : content.length;
+		const gap = content.slice(cursor, gapEnd);
+		const leading = gap.length - gap.trimStart().length;
+		const trailing = gap.length - gap.trimEnd().length;
+		if (gap.trim()) {
+			sections.push({
+				kind: 'template',
+				blockStart: cursor + leading,
+				blockEnd: gapEnd - trailing,
+				contentStart: cursor + leading,
+				contentEnd: gapEnd - trailing,
+				lang: null,
+			});
+		}
+		if (block) {
+			sections.push(block);
+			cursor = block.blockEnd;
+		}
+	}
+	return sections;
+}
+
+/**
+ * Grammar for an SFC's scripts: TSX when any script uses JSX, else
+ * TypeScript (which also parses plain JavaScript).
+ */
+export function sfcScriptLanguage(
+	sections: SfcSection[],
+): 'typescript' | 'tsx' {
+	const usesJsx = sections.some(
+		s => s.kind === 'script' && (s.lang === 'tsx' || s.lang === 'jsx'),
+	);
+	return usesJsx ? 'tsx' : 'typescript';
+}
+
+/**
+ * The file with everything outside script content blanked to spaces.
+ * Replaces UTF-16 code units one for one, so offsets (and tree-sitter
+ * indices) are unchanged.
+ */
+export function maskToScripts(
+	content: string,
+	sections: SfcSection[],
+): string {
+	const blank = (text: string) => text.replace(/[^\r\n]/g, ' ');
+	const parts: string[] = [];
+	let cursor = 0;
+	for (const section of sections) {
+		if (section.kind !== 'script') continue;
+		parts.push(
+			blank(content.slice(cursor, section.contentStart)),
+			content.slice(section.contentStart, section.contentEnd),
+		);
+		cursor = section.contentEnd;
+	}
+	parts.push(blank(content.slice(cursor)));
+	return parts.join('');
+}
+
+/**
+ * PascalCase component tags used in the template sections.
+ */
+export function findComponentTags(
+	content: string,
+	sections: SfcSection[],
+): SfcComponentTag[] {
+	const tags: SfcComponentTag[] = [];
+	for (const section of sections) {
+		if (section.kind !== 'template') continue;
+		const markup = content.slice(section.contentStart, section.contentEnd);
+		for (const match of markup.matchAll(COMPONENT_TAG_PATTERN)) {
+			const start = section.contentStart + match.index + 1;
+			tags.push({name: match[1]!, start, end: start + match[1]!.length});
+		}
+	}
+	return tags;
+}
+
+/**
+ * Component name from the file name: `counter-badge.svelte` ->
+ * `CounterBadge`, SvelteKit `+page.svelte` -> `Page`.
+ */
+export function componentNameFromPath(filepath: string): string {
+	const stem = path.basename(filepath).replace(/\.[^.]*$/, '');
+	return stem
+		.split(/[^A-Za-z0-9]+/)
+		.filter(Boolean)
+		.map(part => part[0]!.toUpperCase() + part.slice(1))
+		.join('');
+}
+
+/**
+ * 0-based line of a string offset.
+ */
+export function lineAt(content: string, offset: number): number {
+	let line = 0;
+	for (let i = content.indexOf('\n'); i !== -1 && i < offset; ) {
+		line++;
+		i = content.indexOf('\n', i + 1);
+	}
+	return line;
+}
@@ -2,15 +2,85 @@
  * Chunker Types - Types for tree-sitter code chunking.
  */
 
+/**
+ * Declared visibility of a definition, normalized across languages.
+ * - public: part of the package's public API
+ * - crate: visible inside its crate/package/assembly/module only (Rust
+ *   `pub(crate)`, Java package-private, C#/Kotlin/Swift `internal`, Go
+ *   unexported)
+ * - restricted: visible to a narrower named scope (Rust `pub(super)`,
+ *   `pub(in path)`, `pub` inside a private module; Swift `fileprivate`)
+ * - protected: visible to subclasses
+ * - private: visible to the enclosing type/module only
+ */
+export type Visibility =
+	| 'public'
+	| 'crate'
+	| 'restricted'
+	| 'protected'
+	| 'private';
+
 /**
  * Types of code chunks extracted by tree-sitter.
  */
-export type ChunkType = 'function' | 'class' | 'method' | 'module';
+export type ChunkType =
+	| 'function'
+	| 'class'
+	| 'interface'
+	| 'struct'
+	| 'trait'
+	| 'method'
+	| 'macro'
+	| 'constant'
+	| 'variable'
+	| 'type_alias'
+	| 'variant'
+	| 'field'
+	| 'module'
+	// Schema files (protobuf, GraphQL, SQL DDL, OpenAPI)
+	| 'message'
+	| 'service'
+	| 'rpc'
+	| 'enum'
+	| 'table'
+	| 'column'
+	| 'endpoint'
+	| 'operation'
+	// Infrastructure files (Terraform, Dockerfile, Kubernetes)
+	| 'resource'
+	| 'module_call'
+	| 'stage'
+	// Config files (JSON, YAML, TOML)
+	| 'key'
+	// Build files (Make targets, just recipes)
+	| 'target';
 
 /**
  * Ref kinds extracted from the AST for usage navigation.
+ * `doc_link` refs come from documentation (Rust intra-doc links, JSDoc and
+ * Javadoc `{@link}`, Sphinx roles, C# `cref`).
+ * `type_ref` marks names in type positions (annotations, generic arguments,
+ * parameter and return types); `extends`/`implements` the supertypes of a
+ * class-like definition; `instantiation` object construction (`new Foo()`,
+ * `Foo {..}`, Python `Foo()`).
+ */
+export type RefKind =
+	| 'import'
+	| 'call'
+	| 'instantiation'
+	| 'identifier'
+	| 'type_ref'
+	| 'extends'
+	| 'implements'
+	| 'string_literal'
+	| 'doc_link';
+
+/**
+ * How an identifier ref uses its name: `write` for assignment targets and
+ * field initialisers, `read_write` for compound assignments (`+=`, `++`)
+ * and Rust `&mut` borrows, `read` otherwise.
  */
-export type RefKind = 'import' | 'call' | 'identifier' | 'string_literal';
+export type RefAccess = 'read' | 'write' | 'read_write';
 
 export type ExtractedRef = {
 	ref_kind: RefKind;
@@ -21,6 +91,13 @@ export type ExtractedRef = {
 	end_byte: number | null;
 	module_name: string | null;
 	imported_name: string | null;
+	/**
+	 * Calls and identifiers: reached through a receiver (`obj.run()`,
+	 * `Foo::new()`, `config.Config`) rather than by bare name
+	 */
+	has_receiver?: boolean;
+	/** Identifiers: read, written or both */
+	access?: RefAccess;
 };
 
 export type RefExtractionOptions = {
@@ -28,7 +105,8 @@ export type RefExtractionOptions = {
 	 * Whether to emit identifier refs (best-effort) in addition to calls/imports.
 	 *
 	 * - all: emit all identifier nodes (can be large)
-	 * - symbolish: only emit PascalCase / ALL_CAPS identifiers
+	 * - symbolish: only emit PascalCase / ALL_CAPS identifiers, plus
+	 *   identifiers that are written (assignment targets, `+=`, ...)
 	 * - none: do not emit identifier refs
 	 */
 	identifier_mode?: 'all' | 'symbolish' | 'none';
@@ -42,10 +120,17 @@ export type RefExtractionOptions = {
 	 * If true, emit string_literal refs. (Typically redundant with symbol/chunk token facts.)
 	 */
 	include_string_literals?: boolean;
+	/**
+	 * If true, emit string_literal refs for literals naming a config key path
+	 * (`"database.pool.max_size"`), so config keys link to the code reading
+	 * them. Implied by include_string_literals.
+	 */
+	include_key_path_literals?: boolean;
 };
 
 export type AnalyzedFile = {
-	language: SupportedLanguage | null;
+	/** Built-in language, or the name of the language plugin that parsed it */
+	language: string | null;
 	parse_status: 'markdown' | 'unsupported' | 'parse_failed' | 'parsed';
 	definition_chunks: Chunk[];
 	chunks: Chunk[];
@@ -81,8 +166,28 @@ export interface Chunk {
 	docstring: string | null;
 	/** Whether symbol has export modifier */
 	isExported: boolean;
+	/** Declared visibility (public for module chunks) */
+	visibility: Visibility;
 	/** Comma-separated decorator/annotation names (null if none) */
 	decoratorNames: string | null;
+	/** Implementing type of a Rust impl block (null for other chunks) */
+	implTypeName: string | null;
+	/** Implemented trait of a Rust trait impl block (null for inherent impls) */
+	implTraitName: string | null;
+	/**
+	 * Declared supertypes of a class-like chunk: `extends`/`implements`,
+	 * base classes, protocol conformances, Rust supertraits
+	 */
+	supertypes: string[];
+	/** Traits named in Rust `#[derive(...)]` attributes */
+	derives: string[];
+	/** Test code: test file, test function, or inside a test module/block */
+	isTest: boolean;
+	/**
+	 * Code example from a definition's documentation (Rust doc test, JSDoc
+	 * `@example`, Python `>>>` doctest). Name/type/header match the owner.
+	 */
+	isDocTest: boolean;
 	// Deterministic token facts (AST-derived when available)
 	identifiers: string[];
 	identifierParts: string[];
@@ -105,7 +210,13 @@ export type SupportedLanguage =
 	| 'dart'
 	| 'swift'
 	| 'kotlin'
-	| 'php';
+	| 'php'
+	| 'c'
+	| 'cpp'
+	| 'ruby'
+	| 'scala'
+	| 'elixir'
+	| 'lua';
 
 /**
  * Map of file extensions to languages.
@@ -138,8 +249,64 @@ export const EXTENSION_TO_LANGUAGE: Record<string, SupportedLanguage> = {
 	'.kts': 'kotlin',
 	// PHP
 	'.php': 'php',
+	// C / C++ (headers are parsed as C++, which also covers C headers)
+	'.c': 'c',
+	'.h': 'cpp',
+	'.cc': 'cpp',
+	'.cpp': 'cpp',
+	'.cxx': 'cpp',
+	'.hh': 'cpp',
+	'.hpp': 'cpp',
+	'.hxx': 'cpp',
+	// Ruby
+	'.rb': 'ruby',
+	'.rake': 'ruby',
+	// Scala
+	'.scala': 'scala',
+	'.sc': 'scala',
+	// Elixir
+	'.ex': 'elixir',
+	'.exs': 'elixir',
+	// Lua
+	'.lua': 'lua',
 };
 
+/**
+ * Directory names that hold test code by convention.
+ */
+const TEST_DIR_NAMES = new Set(['__tests__', 'test', 'tests', 'spec']);
+
+/**
+ * File name conventions for test files across supported languages.
+ */
+const TEST_FILE_PATTERNS: RegExp[] = [
+	// JS/TS: foo.test.ts, foo.spec.tsx
+	/\.(test|spec)\.[cm]?[jt]sx?$/,
+	// Go / Python / Dart / Elixir: foo_test.go, foo_test.py, foo_test.exs
+	/_test\.(go|py|dart|exs)$/,
+	// Ruby / Lua: foo_spec.rb, foo_test.rb, foo_spec.lua
+	/_(test|spec)\.(rb|lua)$/,
+	// pytest: test_foo.py, conftest.py
+	/^test_.*\.py$/,
+	/^conftest\.py$/,
+	// JVM / .NET / Swift / PHP: FooTest.java, FooTests.swift, FooTest.php
+	/[a-z0-9_](Test|Tests|IT)\.(java|kt|kts|cs|swift|php|scala)$/,
+	// Scala: FooSpec.scala, FooSuite.scala
+	/[a-z0-9_](Spec|Suite)\.scala$/,
+	// C / C++: foo_test.cc, foo_unittest.cpp
+	/_(test|unittest)\.(c|cc|cpp|cxx)$/,
+];
+
+/**
+ * Whether a file is test code by path convention alone.
+ */
+export function isTestFilePath(filepath: string): boolean {
+	const segments = filepath.replace(/\\/g, '/').split('/');
+	const base = segments.pop() ?? '';
+	if (segments.some(s => TEST_DIR_NAMES.has(s))) return true;
+	return TEST_FILE_PATTERNS.some(p => p.test(base));
+}
+
 /**
  * Statistics from indexing operations.
  */
@@ -57,6 +57,16 @@ export interface ViberagConfig {
 	watchDebounceMs: number;
 	/** File watcher configuration */
 	watch: WatchConfig;
+	/**
+	 * Tree-sitter grammar overrides: language -> local `.wasm` path (relative
+	 * to the project root). Grammars must target web-tree-sitter's ABI (13–14).
+	 */
+	grammars?: Record<string, string>;
+	/**
+	 * Language plugin manifests (relative to the project root) adding
+	 * languages viberag does not ship. See lib/chunker/plugins.ts.
+	 */
+	languagePlugins?: string[];
 }
 
 // ============================================================================
@@ -193,6 +193,15 @@ export function getUserSettingsPath(): string {
 	return path.join(getViberagHomeDir(), 'settings.json');
 }
 
+/**
+ * Get the per-machine language plugin directory (manifests for every project).
+ *
+ * Path: {VIBERAG_HOME}/languages/
+ */
+export function getUserLanguagesDir(): string {
+	return path.join(getViberagHomeDir(), 'languages');
+}
+
 /**
  * Get the global secrets directory.
  */
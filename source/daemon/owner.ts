@@ -19,6 +19,10 @@ import {isAbortError, throwIfAborted} from './lib/abort.js';
 import {daemonState, type IndexingStatus} from './state.js';
 import {SearchEngineV2} from './services/v2/search/engine.js';
 import type {
+	V2CallGraphOptions,
+	V2CallGraphResponse,
+	V2FindImplementationsOptions,
+	V2FindImplementationsResponse,
 	V2FindUsagesOptions,
 	V2FindUsagesResponse,
 	V2SearchResponse,
@@ -33,6 +37,7 @@ import type {IndexingPhase, IndexingUnit} from './services/types.js';
 import {FileWatcher, type WatcherStatus} from './services/watcher.js';
 import {StorageV2} from './services/v2/storage/index.js';
 import {loadV2Manifest, v2ManifestExists} from './services/v2/manifest.js';
+import type {V2CrateCounts} from './services/v2/storage/types.js';
 import type {
 	V2SearchIntent,
 	V2SearchScope,
@@ -114,6 +119,7 @@ export interface DaemonStatus {
 	totalSymbols?: number;
 	totalChunks?: number;
 	totalRefs?: number;
+	crates?: Record<string, V2CrateCounts>;
 	embeddingProvider?: string;
 	embeddingModel?: string;
 	warmupStatus: string;
@@ -712,6 +718,26 @@ export class DaemonOwner {
 		return engine.findUsages(options);
 	}
 
+	/**
+	 * Find types implementing a trait/interface by name or symbol_id.
+	 */
+	async findImplementations(
+		options: V2FindImplementationsOptions,
+	): Promise<V2FindImplementationsResponse> {
+		const engine = await this.getSearchEngine();
+		return engine.findImplementations(options);
+	}
+
+	/**
+	 * Callers and callees of a symbol, up to a number of hops.
+	 */
+	async getCallGraph(
+		options: V2CallGraphOptions,
+	): Promise<V2CallGraphResponse> {
+		const engine = await this.getSearchEngine();
+		return engine.getCallGraph(options);
+	}
+
 	/**
 	 * Run the v2 eval harness (quality + latency).
 	 */
@@ -988,6 +1014,7 @@ export class DaemonOwner {
 			status.totalSymbols = manifest.stats.totalSymbols;
 			status.totalChunks = manifest.stats.totalChunks;
 			status.totalRefs = manifest.stats.totalRefs;
+			status.crates = manifest.stats.crates;
 		}
 
 		return status;
@@ -73,6 +73,8 @@ export type DaemonMethod =
 	| 'search'
 	| 'getSymbol'
 	| 'findUsages'
+	| 'findImplementations'
+	| 'getCallGraph'
 	| 'expandContext'
 	| 'index'
 	| 'indexAsync'
@@ -8,8 +8,23 @@
 import path from 'node:path';
 import {computeStringHash} from '../../../lib/merkle/hash.js';
 import type {Chunker} from '../../../lib/chunker/index.js';
-import type {Chunk} from '../../../lib/chunker/types.js';
-import type {V2ChunkKind, V2SymbolKind} from '../storage/types.js';
+import {
+	notebookSourceView,
+	parseNotebook,
+	type Notebook,
+} from '../../../lib/chunker/notebook.js';
+import {isTestFilePath, type Chunk} from '../../../lib/chunker/types.js';
+import type {
+	V2ChunkKind,
+	V2RefAccess,
+	V2RefKind,
+	V2SymbolKind,
+	V2Visibility,
+} from '../storage/types.js';
+import {
+	resolveImportTarget,
+	type ImportResolutionContext,
+} from '../resolve/index.js';
 
 export type V2ExtractedSymbol = {
 	symbol_id: string;
@@ -17,6 +32,7 @@ export type V2ExtractedSymbol = {
 	revision: string;
 	file_path: string;
 	extension: string;
+	crate_name: string | null;
 	language_hint: string | null;
 	start_line: number;
 	end_line: number;
@@ -32,7 +48,15 @@ export type V2ExtractedSymbol = {
 	signature: string | null;
 	docstring: string | null;
 	is_exported: boolean;
+	visibility: V2Visibility;
 	decorator_names: string[];
+	impl_type_name: string | null;
+	impl_trait_name: string | null;
+	impl_type_symbol_id: string | null;
+	impl_trait_symbol_id: string | null;
+	supertypes: string[];
+	derives: string[];
+	is_test: boolean;
 
 	context_header: string;
 	code_text: string;
@@ -57,6 +81,7 @@ export type V2ExtractedChunk = {
 	revision: string;
 	file_path: string;
 	extension: string;
+	crate_name: string | null;
 	start_line: number;
 	end_line: number;
 	start_byte: number | null;
@@ -64,6 +89,7 @@ export type V2ExtractedChunk = {
 
 	owner_symbol_id: string | null;
 	chunk_kind: V2ChunkKind | string;
+	is_test: boolean;
 
 	context_header: string;
 	code_text: string;
@@ -88,7 +114,9 @@ export type V2ExtractedFile = {
 	revision: string;
 	file_path: string;
 	extension: string;
+	crate_name: string | null;
 	file_hash: string;
+	is_test: boolean;
 
 	imports: string[];
 	exports: string[];
@@ -106,15 +134,27 @@ export type V2ExtractedRef = {
 	revision: string;
 	file_path: string;
 	extension: string;
+	crate_name: string | null;
 	start_line: number;
 	end_line: number;
 	start_byte: number | null;
 	end_byte: number | null;
-	ref_kind: 'import' | 'call' | 'identifier' | 'string_literal';
+	ref_kind: V2RefKind;
+	/** Identifier refs: read, write or read_write */
+	access: V2RefAccess | null;
 	token_texts: string[];
 	context_snippet: string;
+	/** Ref sits in a test file or inside a test symbol */
+	is_test: boolean;
 	module_name: string | null;
+	/** Calls/identifiers bound through an import: name in the target file */
 	imported_name: string | null;
+	/** File that defines the import target (null if unresolved/external) */
+	target_file_path: string | null;
+	/** Resolved target symbol, filled in by linkImportTargets */
+	target_symbol_id: string | null;
+	/** Call/member access through a receiver (`a.b()`) */
+	has_receiver: boolean;
 };
 
 export type V2ExtractedArtifacts = {
@@ -130,10 +170,33 @@ export type V2ExtractOptions = {
 	chunkMaxSize: number;
 	// If a symbol is smaller than this, we don't emit sub-chunks for it.
 	minSymbolCharsForChunks?: number;
+	// Repo-relative paths of all indexed files (enables import resolution).
+	projectFiles?: ReadonlySet<string>;
+	// Manifests import resolution reads (tsconfig paths, packages, go.mod).
+	importContext?: ImportResolutionContext;
+	// Workspace member (crate/package/module) that owns the file.
+	crateName?: string | null;
 };
 
 const DEFAULT_MIN_SYMBOL_CHARS_FOR_CHUNKS = 1200;
 
+/**
+ * Symbol kinds that own members: classes and other type definitions, the
+ * containers of schema files (protobuf messages and services, SQL tables)
+ * and config keys.
+ */
+const CONTAINER_KINDS = new Set([
+	'class',
+	'interface',
+	'struct',
+	'trait',
+	'message',
+	'service',
+	'enum',
+	'table',
+	'key',
+]);
+
 export async function extractV2FromFile(
 	chunker: Chunker,
 	filePath: string,
@@ -143,7 +206,13 @@ export async function extractV2FromFile(
 	const extension = path.extname(filePath);
 	const language_hint = languageHintFromExtension(extension);
 	const file_hash = computeStringHash(content);
-	const contentLines = content.split('\n');
+	// Notebooks: snippets and docs come from cell sources, not the JSON
+	const notebook =
+		extension.toLowerCase() === '.ipynb' ? parseNotebook(content) : null;
+	const contentLines = (
+		notebook ? notebookSourceView(notebook) : content
+	).split('\n');
+	const fileIsTest = isTestFilePath(filePath);
 
 	// Parse once: extract definition spans, size-constrained chunks, and AST refs.
 	const analysis = chunker.analyzeFile(filePath, content, {
@@ -153,6 +222,7 @@ export async function extractV2FromFile(
 			identifier_mode: 'symbolish',
 			max_occurrences_per_token: 0,
 			include_string_literals: false,
+			include_key_path_literals: true,
 		},
 	});
 
@@ -176,19 +246,31 @@ export async function extractV2FromFile(
 			revision: options.revision,
 			file_path: filePath,
 			extension,
+			crate_name: options.crateName ?? null,
 			start_line: r.start_line,
 			end_line: r.end_line,
 			start_byte: r.start_byte,
 			end_byte: r.end_byte,
 			ref_kind: r.ref_kind,
+			access: r.access ?? null,
 			token_texts,
 			context_snippet: buildContextSnippetFromPreSplitLines(
 				contentLines,
 				r.start_line,
 				r.end_line,
 			),
+			is_test: fileIsTest,
 			module_name: r.module_name,
 			imported_name: r.imported_name,
+			target_file_path: resolveImportRefFile(
+				language_hint,
+				filePath,
+				r,
+				options.projectFiles,
+				options.importContext,
+			),
+			target_symbol_id: null,
+			has_receiver: r.has_receiver ?? false,
 		};
 	});
 
@@ -206,7 +288,9 @@ export async function extractV2FromFile(
 			.map(m => m.trim())
 			.filter(Boolean),
 	);
-	const top_level_doc = extractTopLevelDoc(content, extension);
+	const top_level_doc = notebook
+		? extractNotebookDoc(notebook)
+		: extractTopLevelDoc(content, extension);
 
 	const file_summary_text = buildFileSummaryText({
 		file_path: filePath,
@@ -225,7 +309,9 @@ export async function extractV2FromFile(
 		revision: options.revision,
 		file_path: filePath,
 		extension,
+		crate_name: options.crateName ?? null,
 		file_hash,
+		is_test: fileIsTest,
 		imports,
 		exports: exportedNames,
 		top_level_doc,
@@ -236,8 +322,9 @@ export async function extractV2FromFile(
 
 	// Build class name -> class symbol_id map for parent relationships
 	const classIdByName = new Map<string, string>();
+	const separator = qualnameSeparator(language_hint);
 
-	// First pass: build symbols (class/function/method)
+	// First pass: build symbols (classes, functions and their members)
 	const symbols: V2ExtractedSymbol[] = [];
 	for (const chunk of definitionChunks) {
 		if (chunk.type === 'module') continue;
@@ -245,10 +332,10 @@ export async function extractV2FromFile(
 		const symbol_kind = chunk.type;
 		const symbol_name = chunk.name?.trim() ?? '';
 		const parentClassName = extractClassFromContextHeader(chunk.contextHeader);
-		const qualname =
-			symbol_kind === 'method' && parentClassName
-				? `${parentClassName}.${symbol_name}`
-				: symbol_name;
+		const namespace = extractNamespaceFromContextHeader(chunk.contextHeader);
+		const qualname = [namespace, parentClassName, symbol_name]
+			.filter(Boolean)
+			.join(separator);
 
 		const normalizedSignature = normalizeSignature(chunk.signature);
 		const identityPart =
@@ -260,7 +347,7 @@ export async function extractV2FromFile(
 			`${options.repoId}|${filePath}|${symbol_kind}|${qualname}|${identityPart}`,
 		);
 
-		if (symbol_kind === 'class' && symbol_name.length > 0) {
+		if (CONTAINER_KINDS.has(symbol_kind) && symbol_name.length > 0) {
 			classIdByName.set(symbol_name, symbol_id);
 		}
 
@@ -299,6 +386,7 @@ export async function extractV2FromFile(
 			revision: options.revision,
 			file_path: filePath,
 			extension,
+			crate_name: options.crateName ?? null,
 			language_hint,
 			start_line: chunk.startLine,
 			end_line: chunk.endLine,
@@ -314,7 +402,15 @@ export async function extractV2FromFile(
 			signature: chunk.signature,
 			docstring: chunk.docstring,
 			is_exported: chunk.isExported,
+			visibility: chunk.visibility,
 			decorator_names,
+			impl_type_name: chunk.implTypeName,
+			impl_trait_name: chunk.implTraitName,
+			impl_type_symbol_id: null,
+			impl_trait_symbol_id: null,
+			supertypes: chunk.supertypes,
+			derives: chunk.derives,
+			is_test: chunk.isTest,
 
 			context_header: chunk.contextHeader,
 			code_text: chunk.text,
@@ -334,19 +430,43 @@ export async function extractV2FromFile(
 		});
 	}
 
-	// Second pass: attach parent_symbol_id for methods where we can find the class.
+	// Second pass: attach parent_symbol_id for members (methods, fields,
+	// variants, associated items) and nested containers of any kind. Prefer
+	// the enclosing class-like span (a Rust type may have several impl
+	// blocks), then fall back to the class name. A container's header may
+	// name the container itself, which is never its own parent.
+	const classSymbols = symbols.filter(s => CONTAINER_KINDS.has(s.symbol_kind));
 	for (const symbol of symbols) {
-		if (symbol.symbol_kind !== 'method') continue;
-		const parentClassName = symbol.qualname.includes('.')
-			? symbol.qualname.split('.')[0]
-			: null;
+		const parentClassName = extractClassFromContextHeader(
+			symbol.context_header,
+		);
 		if (!parentClassName) continue;
+		const enclosing = findEnclosingSymbol(symbol, classSymbols);
+		if (enclosing) {
+			symbol.parent_symbol_id = enclosing.symbol_id;
+			continue;
+		}
 		const parentId = classIdByName.get(parentClassName);
-		if (parentId) {
+		if (parentId && parentId !== symbol.symbol_id) {
 			symbol.parent_symbol_id = parentId;
 		}
 	}
 
+	// Refs inside test functions/modules are test refs too.
+	const testSymbols = symbols.filter(s => s.is_test);
+	if (!fileIsTest && testSymbols.length > 0) {
+		for (const ref of refs) {
+			if (ref.start_byte == null || ref.end_byte == null) continue;
+			ref.is_test = testSymbols.some(
+				s =>
+					s.start_byte != null &&
+					s.end_byte != null &&
+					s.start_byte <= ref.start_byte! &&
+					s.end_byte >= ref.end_byte!,
+			);
+		}
+	}
+
 	// Build chunks table rows (blocks)
 	const minSymbolCharsForChunks =
 		options.minSymbolCharsForChunks ?? DEFAULT_MIN_SYMBOL_CHARS_FOR_CHUNKS;
@@ -399,6 +519,7 @@ export async function extractV2FromFile(
 			revision: options.revision,
 			file_path: filePath,
 			extension,
+			crate_name: options.crateName ?? null,
 			start_line: chunk.startLine,
 			end_line: chunk.endLine,
 			start_byte: chunk.startByte,
@@ -406,6 +527,7 @@ export async function extractV2FromFile(
 
 			owner_symbol_id,
 			chunk_kind,
+			is_test: chunk.isTest,
 
 			context_header: chunk.contextHeader,
 			code_text: chunk.text,
@@ -433,7 +555,32 @@ export async function extractV2FromFile(
 	};
 }
 
-function languageHintFromExtension(extension: string): string | null {
+function resolveImportRefFile(
+	languageHint: string | null,
+	filePath: string,
+	ref: {
+		ref_kind: string;
+		module_name: string | null;
+		imported_name: string | null;
+	},
+	projectFiles: ReadonlySet<string> | undefined,
+	context: ImportResolutionContext | undefined,
+): string | null {
+	if (ref.ref_kind !== 'import' || !ref.module_name || !projectFiles) {
+		return null;
+	}
+	const target = resolveImportTarget({
+		languageHint,
+		filePath,
+		moduleName: ref.module_name,
+		importedName: ref.imported_name,
+		files: projectFiles,
+		context,
+	});
+	return target?.file_path ?? null;
+}
+
+export function languageHintFromExtension(extension: string): string | null {
 	switch (extension.toLowerCase()) {
 		case '.ts':
 		case '.mts':
@@ -462,6 +609,61 @@ function languageHintFromExtension(extension: string): string | null {
 			return 'swift';
 		case '.php':
 			return 'php';
+		case '.c':
+			return 'c';
+		case '.h':
+		case '.cc':
+		case '.cpp':
+		case '.cxx':
+		case '.hh':
+		case '.hpp':
+		case '.hxx':
+			return 'cpp';
+		case '.rb':
+		case '.rake':
+			return 'ruby';
+		case '.scala':
+		case '.sc':
+			return 'scala';
+		case '.ex':
+		case '.exs':
+			return 'elixir';
+		case '.lua':
+			return 'lua';
+		case '.vue':
+			return 'vue';
+		case '.svelte':
+			return 'svelte';
+		case '.astro':
+			return 'astro';
+		case '.ipynb':
+			return 'python';
+		case '.proto':
+			return 'protobuf';
+		case '.graphql':
+		case '.gql':
+			return 'graphql';
+		case '.sql':
+			return 'sql';
+		case '.tf':
+		case '.tfvars':
+			return 'terraform';
+		case '.json':
+			return 'json';
+		case '.yaml':
+		case '.yml':
+			return 'yaml';
+		case '.toml':
+			return 'toml';
+		case '.sh':
+		case '.bash':
+		case '.zsh':
+		case '.ksh':
+			return 'shell';
+		case '.mk':
+			return 'make';
+		case '.just':
+			return 'just';
 		case '.md':
 		case '.mdx':
 		case '.markdown':
@@ -471,6 +673,48 @@ function languageHintFromExtension(extension: string): string | null {
 	}
 }
 
+/**
+ * Separator between owner and member in qualnames (`Greeter::new` in Rust,
+ * `geo::Shape::area` in C++, `Billing::Invoice::total` in Ruby,
+ * `Greeter.greet` elsewhere).
+ */
+function qualnameSeparator(languageHint: string | null): string {
+	return languageHint === 'rust' ||
+		languageHint === 'cpp' ||
+		languageHint === 'ruby'
+		? '::'
+		: '.';
+}
+
+/**
+ * Find the innermost candidate whose byte span encloses the symbol.
+ */
+function findEnclosingSymbol(
+	symbol: V2ExtractedSymbol,
+	candidates: V2ExtractedSymbol[],
+): V2ExtractedSymbol | null {
+	if (symbol.start_byte == null || symbol.end_byte == null) return null;
+	let best: V2ExtractedSymbol | null = null;
+	for (const candidate of candidates) {
+		if (candidate.symbol_id === symbol.symbol_id) continue;
+		if (candidate.start_byte == null || candidate.end_byte == null) continue;
+		if (
+			candidate.start_byte > symbol.start_byte ||
+			candidate.end_byte < symbol.end_byte
+		) {
+			continue;
+		}
+		if (
+			!best ||
+			candidate.end_byte - candidate.start_byte <
+				best.end_byte! - best.start_byte!
+		) {
+			best = candidate;
+		}
+	}
+	return best;
+}
+
 function normalizeSignature(signature: string | null): string {
 	if (!signature) return '';
 	return signature.trim().replace(/\s+/g, ' ');
@@ -481,6 +725,13 @@ function extractClassFromContextHeader(contextHeader: string): string | null {
 	return match ? match[1]!.trim() : null;
 }
 
+function extractNamespaceFromContextHeader(
+	contextHeader: string,
+): string | null {
+	const match = contextHeader.match(/Namespace: ([^,)]+)/);
+	return match ? match[1]!.trim() : null;
+}
+
 function buildSymbolEmbedInput(args: {
 	signature: string | null;
 	docstring: string | null;
@@ -548,13 +799,38 @@ function inferChunkKind(chunk: Chunk, extension: string): V2ChunkKind {
 	if (lowerExt === '.md' || lowerExt === '.mdx' || lowerExt === '.markdown') {
 		return 'markdown_section';
 	}
+	if (chunk.isDocTest) {
+		return 'doc_test';
+	}
 	if (chunk.type === 'module') {
 		return 'block';
 	}
 	if (
 		chunk.type === 'function' ||
 		chunk.type === 'class' ||
-		chunk.type === 'method'
+		chunk.type === 'interface' ||
+		chunk.type === 'struct' ||
+		chunk.type === 'trait' ||
+		chunk.type === 'method' ||
+		chunk.type === 'macro' ||
+		chunk.type === 'constant' ||
+		chunk.type === 'variable' ||
+		chunk.type === 'type_alias' ||
+		chunk.type === 'variant' ||
+		chunk.type === 'field' ||
+		chunk.type === 'message' ||
+		chunk.type === 'service' ||
+		chunk.type === 'rpc' ||
+		chunk.type === 'enum' ||
+		chunk.type === 'table' ||
+		chunk.type === 'column' ||
+		chunk.type === 'endpoint' ||
+		chunk.type === 'operation' ||
+		chunk.type === 'resource' ||
+		chunk.type === 'module_call' ||
+		chunk.type === 'stage' ||
+		chunk.type === 'key' ||
+		chunk.type === 'target'
 	) {
 		return 'statement_group';
 	}
@@ -575,6 +851,8 @@ function inferOwnerSymbolIdForChunk(
 		const key = buildSymbolLookupKeyFromChunk(chunk);
 		const symbol = symbolByKey.get(key);
 		if (!symbol) return null;
+		// Doc tests are kept even for small symbols (they are not duplicates).
+		if (chunk.isDocTest) return symbol.symbol_id;
 		if (symbol.code_text.length < minSymbolCharsForChunks) return null;
 		return symbol.symbol_id;
 	}
@@ -582,19 +860,17 @@ function inferOwnerSymbolIdForChunk(
 }
 
 function buildSymbolLookupKey(symbol: V2ExtractedSymbol): string {
-	if (symbol.symbol_kind === 'method') {
-		const className = symbol.qualname.includes('.')
-			? symbol.qualname.split('.')[0]!
-			: '';
-		return `method|${className}|${symbol.symbol_name}`;
+	const className = extractClassFromContextHeader(symbol.context_header);
+	if (symbol.symbol_kind === 'method' || className) {
+		return `${symbol.symbol_kind}|${className ?? ''}|${symbol.symbol_name}`;
 	}
 	return `${symbol.symbol_kind}|${symbol.symbol_name}`;
 }
 
 function buildSymbolLookupKeyFromChunk(chunk: Chunk): string {
-	if (chunk.type === 'method') {
-		const className = extractClassFromContextHeader(chunk.contextHeader) ?? '';
-		return `method|${className}|${chunk.name ?? ''}`;
+	const className = extractClassFromContextHeader(chunk.contextHeader);
+	if (chunk.type === 'method' || className) {
+		return `${chunk.type}|${className ?? ''}|${chunk.name ?? ''}`;
 	}
 	return `${chunk.type}|${chunk.name ?? ''}`;
 }
@@ -630,6 +906,15 @@ function buildContextSnippetFromPreSplitLines(
 		.trim();
 }
 
+/**
+ * A notebook's doc is its leading markdown cell, if any.
+ */
+function extractNotebookDoc(notebook: Notebook): string | null {
+	const first = notebook.cells.find(c => c.source.some(t => t.trim()));
+	if (first?.cellType !== 'markdown') return null;
+	return extractTopLevelDoc(first.source.join('\n'), '.md');
+}
+
 function extractTopLevelDoc(content: string, extension: string): string | null {
 	const ext = extension.toLowerCase();
 
@@ -664,6 +949,18 @@ function extractTopLevelDoc(content: string, extension: string): string | null {
 		return null;
 	}
 
+	// Vue/Svelte/Astro: leading HTML comment
+	if (ext === '.vue' || ext === '.svelte' || ext === '.astro') {
+		const trimmed = content.trimStart();
+		if (trimmed.startsWith('<!--')) {
+			const end = trimmed.indexOf('-->');
+			if (end !== -1) {
+				return trimmed.slice(0, end + 3).trim();
+			}
+		}
+		return null;
+	}
+
 	// Markdown: first heading + paragraph
 	if (ext === '.md' || ext === '.mdx' || ext === '.markdown') {
 		const lines = content.split('\n');
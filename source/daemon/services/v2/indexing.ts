@@ -21,6 +21,11 @@ import {MerkleTree} from '../../lib/merkle/index.js';
 import type {SerializedNode} from '../../lib/merkle/node.js';
 import {computeStringHash} from '../../lib/merkle/hash.js';
 import {Chunker} from '../../lib/chunker/index.js';
+import {
+	changedGrammarExtensions,
+	computeGrammarFingerprints,
+	loadGrammarOptions,
+} from '../../lib/chunker/grammars.js';
 import {resolveApiKey} from '../../lib/secrets.js';
 import {GeminiEmbeddingProvider} from '../../providers/gemini.js';
 import {LocalEmbeddingProvider} from '../../providers/local.js';
@@ -32,6 +37,17 @@ import {
 	type V2ExtractedArtifacts,
 } from './extract/extract.js';
 import {StorageV2} from './storage/index.js';
+import {
+	getStoredDefinitions,
+	linkImportTargets,
+	loadImportResolutionContext,
+	relinkImportTargets,
+} from './resolve/index.js';
+import {
+	crateForFile,
+	loadWorkspacePackages,
+	sameWorkspacePackages,
+} from './workspace.js';
 import {
 	checkV2IndexCompatibility,
 	loadV2Manifest,
@@ -89,6 +105,7 @@ export class IndexingServiceV2 extends TypedEmitter<V2IndexingServiceEvents> {
 	private config: ViberagConfig | null = null;
 	private storage: StorageV2 | null = null;
 	private chunker: Chunker | null = null;
+	private grammarFingerprints: Record<string, string> = {};
 	private embeddings: EmbeddingProvider | null = null;
 	private logger: Logger | null = null;
 	private debugLogger: Logger;
@@ -253,15 +270,61 @@ export class IndexingServiceV2 extends TypedEmitter<V2IndexingServiceEvents> {
 			stats.filesScanned = currentTree.buildStats.filesScanned;
 			stats.filesIndexed = currentTree.fileCount;
 
+			const projectFilePaths: string[] = [];
+			this.collectAllFilesFromSerialized(
+				currentTree.toJSON(),
+				projectFilePaths,
+			);
+			const projectFiles = new Set(projectFilePaths);
+			const importContext = await loadImportResolutionContext(
+				this.projectRoot,
+				projectFiles,
+			);
+			const workspacePackages = await loadWorkspacePackages(this.projectRoot);
+
 			// Diff
 			const diff = force
 				? this.createForceDiff(currentTree)
 				: previousTree.compare(currentTree);
 
+			// Workspace membership changed: every file's crate tag may be stale.
+			if (
+				!force &&
+				!sameWorkspacePackages(manifest.workspace ?? [], workspacePackages)
+			) {
+				const changed = new Set([...diff.new, ...diff.modified]);
+				for (const filePath of projectFilePaths) {
+					if (!changed.has(filePath)) diff.modified.push(filePath);
+				}
+			}
+
+			// Grammar overrides or language plugins changed: files they parse
+			// were extracted with the old grammar.
+			const regrammared = changedGrammarExtensions(
+				manifest.grammars ?? {},
+				this.grammarFingerprints,
+			);
+			if (!force && regrammared.length > 0) {
+				const changed = new Set([...diff.new, ...diff.modified]);
+				for (const filePath of projectFilePaths) {
+					const lower = filePath.toLowerCase();
+					if (changed.has(filePath)) continue;
+					if (regrammared.some(ext => lower.endsWith(ext))) {
+						diff.modified.push(filePath);
+					}
+				}
+			}
+
 			stats.filesNew = diff.new.length;
 			stats.filesModified = diff.modified.length;
 			stats.filesDeleted = diff.deleted.length;
 
+			// What the changed files define before their rows are replaced
+			const previousDefinitions = await getStoredDefinitions(
+				storage,
+				force ? [] : [...diff.modified, ...diff.deleted],
+			);
+
 			if (force) {
 				this.emitIndexProgress('persist', 'Resetting tables', 0, 0, null);
 				await storage.resetEntityTables();
@@ -299,20 +362,33 @@ export class IndexingServiceV2 extends TypedEmitter<V2IndexingServiceEvents> {
 				new Set([...diff.new, ...diff.modified]),
 			);
 			if (filesToProcess.length === 0 && !force) {
+				await relinkImportTargets(
+					diff.deleted,
+					[],
+					storage,
+					previousDefinitions,
+					importContext,
+				);
 				// Still update manifest revision/tree/stats.
 				const totalSymbols = await storage.getSymbolsTable().countRows();
 				const totalChunks = await storage.getChunksTable().countRows();
 				const totalRefs = await storage.getRefsTable().countRows();
+				const crates = await storage.countRowsByCrate(
+					workspacePackages.map(p => p.name),
+				);
 				manifest = {
 					...manifest,
 					repoId,
 					revision,
 					tree: currentTree.toJSON(),
+					workspace: workspacePackages,
+					grammars: this.grammarFingerprints,
 					stats: {
 						totalFiles: currentTree.fileCount,
 						totalSymbols,
 						totalChunks,
 						totalRefs,
+						crates,
 					},
 				};
 				await saveV2Manifest(this.projectRoot, manifest);
@@ -380,6 +456,9 @@ export class IndexingServiceV2 extends TypedEmitter<V2IndexingServiceEvents> {
 							repoId,
 							revision,
 							chunkMaxSize: config.chunkMaxSize,
+							projectFiles,
+							importContext,
+							crateName: crateForFile(workspacePackages, filePath),
 						},
 					);
 					extracted.push(artifacts);
@@ -401,6 +480,9 @@ export class IndexingServiceV2 extends TypedEmitter<V2IndexingServiceEvents> {
 
 			throwIfAborted(this.abortSignal, 'Indexing cancelled');
 
+			// Link import refs to the symbols they bind (cross-file)
+			await linkImportTargets(extracted, storage, importContext);
+
 			// Embed all surfaces (cached by embed_hash)
 			const embedItems: Array<{
 				hash: string;
@@ -559,7 +641,9 @@ export class IndexingServiceV2 extends TypedEmitter<V2IndexingServiceEvents> {
 					revision: item.file.revision,
 					file_path: item.file.file_path,
 					extension: item.file.extension,
+					crate_name: item.file.crate_name,
 					file_hash: item.file.file_hash,
+					is_test: item.file.is_test,
 					imports: item.file.imports,
 					exports: item.file.exports,
 					top_level_doc: item.file.top_level_doc,
@@ -576,6 +660,7 @@ export class IndexingServiceV2 extends TypedEmitter<V2IndexingServiceEvents> {
 						revision: s.revision,
 						file_path: s.file_path,
 						extension: s.extension,
+						crate_name: s.crate_name,
 						language_hint: s.language_hint,
 						start_line: s.start_line,
 						end_line: s.end_line,
@@ -590,7 +675,15 @@ export class IndexingServiceV2 extends TypedEmitter<V2IndexingServiceEvents> {
 						signature: s.signature,
 						docstring: s.docstring,
 						is_exported: s.is_exported,
+						visibility: s.visibility,
 						decorator_names: s.decorator_names,
+						impl_type_name: s.impl_type_name,
+						impl_trait_name: s.impl_trait_name,
+						impl_type_symbol_id: s.impl_type_symbol_id,
+						impl_trait_symbol_id: s.impl_trait_symbol_id,
+						supertypes: s.supertypes,
+						derives: s.derives,
+						is_test: s.is_test,
 						context_header: s.context_header,
 						code_text: s.code_text,
 						search_text: s.search_text,
@@ -614,12 +707,14 @@ export class IndexingServiceV2 extends TypedEmitter<V2IndexingServiceEvents> {
 						revision: c.revision,
 						file_path: c.file_path,
 						extension: c.extension,
+						crate_name: c.crate_name,
 						start_line: c.start_line,
 						end_line: c.end_line,
 						start_byte: c.start_byte,
 						end_byte: c.end_byte,
 						owner_symbol_id: c.owner_symbol_id,
 						chunk_kind: c.chunk_kind,
+						is_test: c.is_test,
 						context_header: c.context_header,
 						code_text: c.code_text,
 						search_text: c.search_text,
@@ -641,15 +736,21 @@ export class IndexingServiceV2 extends TypedEmitter<V2IndexingServiceEvents> {
 						revision: r.revision,
 						file_path: r.file_path,
 						extension: r.extension,
+						crate_name: r.crate_name,
 						start_line: r.start_line,
 						end_line: r.end_line,
 						start_byte: r.start_byte,
 						end_byte: r.end_byte,
 						ref_kind: r.ref_kind,
+						access: r.access,
 						token_texts: r.token_texts,
 						context_snippet: r.context_snippet,
+						is_test: r.is_test,
 						module_name: r.module_name,
 						imported_name: r.imported_name,
+						target_file_path: r.target_file_path,
+						target_symbol_id: r.target_symbol_id,
+						has_receiver: r.has_receiver,
 					});
 				}
 			}
@@ -659,6 +760,17 @@ export class IndexingServiceV2 extends TypedEmitter<V2IndexingServiceEvents> {
 			await storage.upsertChunks(chunkRows);
 			await storage.upsertRefs(refRows);
 
+			// Refs in untouched files may point at symbols that just changed.
+			if (!force) {
+				await relinkImportTargets(
+					[...filesToProcess, ...diff.deleted],
+					extracted,
+					storage,
+					previousDefinitions,
+					importContext,
+				);
+			}
+
 			stats.fileRowsUpserted += fileRows.length;
 			stats.symbolRowsUpserted += symbolRows.length;
 			stats.chunkRowsUpserted += chunkRows.length;
@@ -667,17 +779,23 @@ export class IndexingServiceV2 extends TypedEmitter<V2IndexingServiceEvents> {
 			const totalSymbols = await storage.getSymbolsTable().countRows();
 			const totalChunks = await storage.getChunksTable().countRows();
 			const totalRefs = await storage.getRefsTable().countRows();
+			const crates = await storage.countRowsByCrate(
+				workspacePackages.map(p => p.name),
+			);
 
 			manifest = {
 				...manifest,
 				repoId,
 				revision,
 				tree: currentTree.toJSON(),
+				workspace: workspacePackages,
+				grammars: this.grammarFingerprints,
 				stats: {
 					totalFiles: currentTree.fileCount,
 					totalSymbols,
 					totalChunks,
 					totalRefs,
+					crates,
 				},
 			};
 
@@ -730,8 +848,19 @@ export class IndexingServiceV2 extends TypedEmitter<V2IndexingServiceEvents> {
 		}
 
 		if (!this.chunker) {
-			this.chunker = new Chunker();
+			const {grammarPaths, plugins} = await loadGrammarOptions(
+				this.projectRoot,
+				this.config,
+			);
+			for (const {manifestPath, error} of plugins.errors) {
+				this.log('warn', `Invalid language plugin ${manifestPath}: ${error}`);
+			}
+			this.chunker = new Chunker({grammarPaths, plugins: plugins.plugins});
 			await this.chunker.initialize();
+			this.grammarFingerprints = await computeGrammarFingerprints({
+				grammarPaths,
+				plugins,
+			});
 		}
 
 		if (!this.embeddings) {
@@ -7,8 +7,10 @@
 import fs from 'node:fs/promises';
 import path from 'node:path';
 import {getViberagDir} from '../../lib/constants.js';
+import type {V2CrateCounts} from './storage/types.js';
+import type {WorkspacePackage} from './workspace.js';
 
-export const V2_SCHEMA_VERSION = 6;
+export const V2_SCHEMA_VERSION = 35;
 
 export type V2IndexCompatibilityStatus =
 	| 'not_indexed'
@@ -47,11 +49,9 @@ export class V2ReindexRequiredError extends Error {
 	}
 }
 
-export type V2ManifestStats = {
-	totalFiles: number;
-	totalSymbols: number;
-	totalChunks: number;
-	totalRefs: number;
+export type V2ManifestStats = V2CrateCounts & {
+	/** Per workspace member (crate/package/module) counts */
+	crates?: Record<string, V2CrateCounts>;
 };
 
 export type V2Manifest = {
@@ -63,6 +63,13 @@ export type V2Manifest = {
 	revision: string;
 	tree: object | null;
 	stats: V2ManifestStats;
+	/** Workspace members detected at last index (see workspace.ts) */
+	workspace?: WorkspacePackage[];
+	/**
+	 * Grammar override / language plugin fingerprints by extension at last
+	 * index (see computeGrammarFingerprints)
+	 */
+	grammars?: Record<string, string>;
 };
 
 export function getV2ManifestPath(projectRoot: string): string {
@@ -0,0 +1,63 @@
+/**
+ * C/C++ include resolver.
+ *
+ * Maps `#include` paths to the project file they name, searching like a
+ * compiler with typical include flags would:
+ *
+ * - the including file's directory (quoted includes)
+ * - each ancestor directory of the including file, and its `include/` and
+ *   `src/` subdirectories (`-I.`, `-Iinclude`, `-Isrc` from a project root)
+ *
+ * Resolution is purely path-based (no compile_commands.json). Includes that
+ * are not project files (system and third-party headers) are unresolved.
+ */
+
+import path from 'node:path';
+
+export type CIncludeTarget = {
+	/** File named by the include */
+	file_path: string;
+	/** Includes bring in a whole file, never a single item */
+	name: null;
+};
+
+const INCLUDE_SUBDIRS = ['', 'include', 'src'];
+
+/**
+ * Resolve an include path (`geo/shape.h`) from `filePath` to a project file.
+ */
+export function resolveCInclude(args: {
+	filePath: string;
+	moduleName: string;
+	files: ReadonlySet<string>;
+}): CIncludeTarget | null {
+	const include = args.moduleName.replace(/\\/g, '/');
+	if (include.startsWith('/')) return null;
+
+	let dir = path.posix.dirname(args.filePath);
+	const direct = normalize(path.posix.join(dir, include));
+	if (direct && args.files.has(direct)) {
+		return {file_path: direct, name: null};
+	}
+
+	for (;;) {
+		for (const subdir of INCLUDE_SUBDIRS) {
+			const base = dir === '.' ? subdir : path.posix.join(dir, subdir);
+			const candidate = normalize(path.posix.join(base, include));
+			if (candidate && args.files.has(candidate)) {
+				return {file_path: candidate, name: null};
+			}
+		}
+		if (dir === '.' || dir === '') return null;
+		dir = path.posix.dirname(dir);
+	}
+}
+
+/**
+ * Repo-relative normalized path, or null when it escapes the repo root.
+ */
+function normalize(filePath: string): string | null {
+	const normalized = path.posix.normalize(filePath);
+	if (normalized.startsWith('../') || normalized === '..') return null;
+	return normalized.replace(/^\.\//, '');
+}
@@ -0,0 +1,48 @@
+/**
+ * Go package resolver.
+ *
+ * Maps import paths to a file of the imported package, using the project's
+ * go.mod module paths: `example.com/shop/pricing` inside module
+ * `example.com/shop` (rooted at `D`) is the directory `D/pricing`.
+ *
+ * A Go package spans every file in its directory, so the target is the
+ * package's first non-test file and names are looked up package-wide by the
+ * linker. Standard library and third-party packages are unresolved.
+ */
+
+import {joinProjectPath, type ImportResolutionContext} from './project.js';
+
+export type GoImportTarget = {
+	/** A (non-test) file of the imported package */
+	file_path: string;
+	/** Imports bind a whole package, never a single item */
+	name: null;
+};
+
+/**
+ * Resolve a Go import path to a file of the package it names.
+ */
+export function resolveGoImport(args: {
+	moduleName: string;
+	context?: ImportResolutionContext;
+}): GoImportTarget | null {
+	if (!args.context) return null;
+	const importPath = args.moduleName.trim();
+	for (const mod of args.context.goModules) {
+		const rest =
+			importPath === mod.module
+				? ''
+				: importPath.startsWith(`${mod.module}/`)
+					? importPath.slice(mod.module.length + 1)
+					: null;
+		if (rest === null) continue;
+
+		const dir = joinProjectPath(mod.dir, rest);
+		if (dir === null) return null;
+		const file = args.context.filesByDir
+			.get(dir)
+			?.find(f => f.endsWith('.go') && !f.endsWith('_test.go'));
+		return file ? {file_path: file, name: null} : null;
+	}
+	return null;
+}
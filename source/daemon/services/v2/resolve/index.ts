@@ -0,0 +1,732 @@
+/**
+ * V2 import resolution - link import refs to the symbols they bind.
+ *
+ * Extraction resolves each import ref to the file that defines its target
+ * (`target_file_path`). Linking then looks the imported name up in that file
+ * (`target_symbol_id`), following re-exports (`pub use`) a few hops.
+ *
+ * Calls and identifiers that go through an import (`loadConfig()`,
+ * `pricing.Total()`, `Money.format()`) are bound the same way, so usages
+ * of a symbol can be told apart from same-named symbols elsewhere.
+ *
+ * Rust impl blocks (and their methods) are linked to the symbols of the
+ * type and trait they implement (`impl_type_symbol_id`,
+ * `impl_trait_symbol_id`), and C/C++ methods defined out of line
+ * (`Foo::bar`) to their class (`parent_symbol_id`).
+ */
+
+import path from 'node:path';
+import type {StorageV2} from '../storage/index.js';
+import type {V2RefKind} from '../storage/types.js';
+import type {V2ExtractedArtifacts, V2ExtractedRef} from '../extract/extract.js';
+import {resolveRustImport} from './rust.js';
+import {resolveCInclude} from './c.js';
+import {resolveJsImport} from './typescript.js';
+import {resolvePythonImport} from './python.js';
+import {resolveGoImport} from './go.js';
+import {resolveJvmImport} from './jvm.js';
+import {dirOf, type ImportResolutionContext} from './project.js';
+
+export {
+	createImportResolutionContext,
+	loadImportResolutionContext,
+	type ImportResolutionContext,
+} from './project.js';
+
+export type ImportTarget = {
+	/** File that defines the target module */
+	file_path: string;
+	/** Item name inside that module (null when the path names a module) */
+	name: string | null;
+};
+
+/** How many re-export hops to follow before giving up. */
+const MAX_REEXPORT_HOPS = 3;
+
+/** Max values per `IN (...)` filter. */
+const QUERY_BATCH_SIZE = 500;
+
+/**
+ * Languages whose packages span a directory: names resolve across the
+ * sibling files of the target file.
+ */
+const PACKAGE_SCOPED_EXTENSIONS = ['.go', '.java', '.kt'];
+
+/** Ref kinds that use an imported name and bind to its definition. */
+const IMPORT_USE_REF_KINDS = new Set<V2RefKind>([
+	'call',
+	'instantiation',
+	'identifier',
+	'type_ref',
+	'extends',
+	'implements',
+]);
+
+/**
+ * Resolve an import ref to its defining file (language-specific).
+ */
+export function resolveImportTarget(args: {
+	languageHint: string | null;
+	filePath: string;
+	moduleName: string;
+	importedName: string | null;
+	files: ReadonlySet<string>;
+	context?: ImportResolutionContext;
+}): ImportTarget | null {
+	switch (args.languageHint) {
+		case 'rust':
+			return resolveRustImport(args);
+		case 'c':
+		case 'cpp':
+			return resolveCInclude(args);
+		case 'typescript':
+		case 'tsx':
+		case 'javascript':
+		case 'vue':
+		case 'svelte':
+		case 'astro':
+			return resolveJsImport(args);
+		case 'python':
+			return resolvePythonImport(args);
+		case 'go':
+			return resolveGoImport(args);
+		case 'java':
+		case 'kotlin':
+			return resolveJvmImport(args);
+		default:
+			return null;
+	}
+}
+
+/**
+ * Languages whose imports bind names (`import {x}`, `use a::X`, `from m
+ * import x`, Go and JVM imports). C/C++ includes pull in whole headers
+ * and bind nothing.
+ */
+const NAME_BINDING_LANGUAGES = new Set([
+	'rust',
+	'typescript',
+	'tsx',
+	'javascript',
+	'vue',
+	'svelte',
+	'astro',
+	'python',
+	'go',
+	'java',
+	'kotlin',
+]);
+
+/**
+ * Whether a use of a name defined in another file must go through an
+ * import the linker can bind.
+ */
+export function bindsImportedNames(languageHint: string | null): boolean {
+	return languageHint !== null && NAME_BINDING_LANGUAGES.has(languageHint);
+}
+
+type ImportEdge = {
+	local: string;
+	imported_name: string | null;
+	target_file_path: string | null;
+};
+
+/**
+ * Looks up top-level symbols, members and import edges per file, preferring
+ * the current indexing batch and falling back to storage.
+ */
+class ImportTargetLinker {
+	private readonly symbolsByFile = new Map<string, Map<string, string>>();
+	private readonly importsByFile = new Map<string, ImportEdge[]>();
+	private readonly membersByParent = new Map<string, Map<string, string>>();
+	private readonly uniqueByName = new Map<string, string | null>();
+	private readonly batchFiles = new Set<string>();
+
+	constructor(
+		private readonly storage: StorageV2,
+		batch: V2ExtractedArtifacts[],
+		private readonly context?: ImportResolutionContext,
+	) {
+		for (const item of batch) {
+			const filePath = item.file.file_path;
+			this.batchFiles.add(filePath);
+			const symbols = new Map<string, string>();
+			for (const s of item.symbols) {
+				if (s.parent_symbol_id !== null) {
+					const members =
+						this.membersByParent.get(s.parent_symbol_id) ?? new Map();
+					if (!members.has(s.symbol_name)) {
+						members.set(s.symbol_name, s.symbol_id);
+					}
+					this.membersByParent.set(s.parent_symbol_id, members);
+					continue;
+				}
+				if (s.symbol_kind === 'method') continue;
+				if (!symbols.has(s.symbol_name)) {
+					symbols.set(s.symbol_name, s.symbol_id);
+				}
+			}
+			this.symbolsByFile.set(filePath, symbols);
+			this.importsByFile.set(
+				filePath,
+				item.refs
+					.filter(r => r.ref_kind === 'import')
+					.map(r => ({
+						local: r.token_texts[0] ?? '',
+						imported_name: r.imported_name,
+						target_file_path: r.target_file_path,
+					})),
+			);
+		}
+		// Batch symbols are complete: their members need no storage lookup
+		for (const item of batch) {
+			for (const s of item.symbols) {
+				if (!this.membersByParent.has(s.symbol_id)) {
+					this.membersByParent.set(s.symbol_id, new Map());
+				}
+			}
+		}
+	}
+
+	/**
+	 * Symbol named `name` in `filePath`. A qualified `Head.member` name is a
+	 * member of `Head`, or a top-level `member` when `Head` is the module
+	 * itself (namespace and module imports).
+	 */
+	async resolve(filePath: string, name: string): Promise<string | null> {
+		const dot = name.indexOf('.');
+		if (dot === -1) return this.resolveName(filePath, name);
+
+		const head = name.slice(0, dot);
+		const member = name.slice(dot + 1);
+		const headId =
+			head === '*' ? null : await this.resolveName(filePath, head);
+		if (headId) return this.resolveMember(headId, member);
+		return this.resolveName(filePath, member);
+	}
+
+	/**
+	 * Type or trait named by a Rust impl in `filePath`: an item of the file
+	 * or one it imports, else the only top-level item of that name.
+	 */
+	async resolveImplTarget(
+		filePath: string,
+		name: string,
+	): Promise<string | null> {
+		return (
+			(await this.resolveName(filePath, name)) ??
+			(await this.resolveUnique(name, path.posix.extname(filePath)))
+		);
+	}
+
+	private async resolveName(
+		filePath: string,
+		name: string,
+	): Promise<string | null> {
+		let currentFile = filePath;
+		let currentName = name;
+		for (let hop = 0; hop <= MAX_REEXPORT_HOPS; hop++) {
+			await this.load([currentFile]);
+			const symbolId = this.symbolsByFile.get(currentFile)?.get(currentName);
+			if (symbolId) return symbolId;
+
+			const reexport = this.importsByFile
+				.get(currentFile)
+				?.find(
+					e =>
+						e.local === currentName &&
+						e.target_file_path &&
+						e.imported_name,
+				);
+			if (!reexport?.target_file_path || !reexport.imported_name) {
+				return this.resolveInPackage(currentFile, currentName);
+			}
+			currentFile = reexport.target_file_path;
+			currentName = reexport.imported_name;
+		}
+		return null;
+	}
+
+	/**
+	 * Go/Java/Kotlin: a name the target file lacks may live in another file
+	 * of the same package (directory).
+	 */
+	private async resolveInPackage(
+		filePath: string,
+		name: string,
+	): Promise<string | null> {
+		const ext = PACKAGE_SCOPED_EXTENSIONS.find(e => filePath.endsWith(e));
+		if (!ext || !this.context) return null;
+		const siblings = (this.context.filesByDir.get(dirOf(filePath)) ?? [])
+			.filter(f => f !== filePath && f.endsWith(ext));
+		await this.load(siblings);
+		for (const sibling of siblings) {
+			const symbolId = this.symbolsByFile.get(sibling)?.get(name);
+			if (symbolId) return symbolId;
+		}
+		return null;
+	}
+
+	private async resolveUnique(
+		name: string,
+		extension: string,
+	): Promise<string | null> {
+		const key = `${extension}|${name}`;
+		if (!this.uniqueByName.has(key)) {
+			const ids = new Set<string>();
+			for (const [filePath, symbols] of this.symbolsByFile) {
+				const id = symbols.get(name);
+				if (!id || !this.batchFiles.has(filePath)) continue;
+				if (filePath.endsWith(extension)) ids.add(id);
+			}
+			for (const s of await this.storage.getTopLevelSymbolsByName(
+				[name],
+				extension,
+			)) {
+				if (!this.batchFiles.has(s.file_path)) ids.add(s.symbol_id);
+			}
+			this.uniqueByName.set(key, ids.size === 1 ? [...ids][0]! : null);
+		}
+		return this.uniqueByName.get(key)!;
+	}
+
+	private async resolveMember(
+		parentId: string,
+		name: string,
+	): Promise<string | null> {
+		if (!this.membersByParent.has(parentId)) {
+			const members = new Map<string, string>();
+			for (const s of await this.storage.getMemberSymbols([parentId])) {
+				if (!members.has(s.symbol_name)) {
+					members.set(s.symbol_name, s.symbol_id);
+				}
+			}
+			this.membersByParent.set(parentId, members);
+		}
+		return this.membersByParent.get(parentId)!.get(name) ?? null;
+	}
+
+	async load(filePaths: string[]): Promise<void> {
+		const missing = Array.from(new Set(filePaths)).filter(
+			p => !this.symbolsByFile.has(p),
+		);
+		for (const batch of chunked(missing, QUERY_BATCH_SIZE)) {
+			for (const p of batch) {
+				this.symbolsByFile.set(p, new Map());
+				this.importsByFile.set(p, []);
+			}
+			const symbols = await this.storage.getTopLevelSymbolsForFiles(batch);
+			for (const s of symbols) {
+				const byName = this.symbolsByFile.get(s.file_path)!;
+				if (!byName.has(s.symbol_name)) {
+					byName.set(s.symbol_name, s.symbol_id);
+				}
+			}
+			const imports = await this.storage.getImportRefs('file_path', batch);
+			for (const r of imports) {
+				this.importsByFile.get(r.file_path)!.push({
+					local: r.token_texts[0] ?? '',
+					imported_name: r.imported_name,
+					target_file_path: r.target_file_path,
+				});
+			}
+		}
+	}
+}
+
+/**
+ * Fill in `target_symbol_id` for resolved import refs in the batch, then
+ * bind calls and identifiers that go through those imports.
+ */
+export async function linkImportTargets(
+	extracted: V2ExtractedArtifacts[],
+	storage: StorageV2,
+	context?: ImportResolutionContext,
+): Promise<void> {
+	const linker = new ImportTargetLinker(storage, extracted, context);
+	const pending = extracted.flatMap(item =>
+		item.refs.filter(
+			r => r.ref_kind === 'import' && r.target_file_path && r.imported_name,
+		),
+	);
+	await linker.load(pending.map(r => r.target_file_path!));
+	for (const ref of pending) {
+		ref.target_symbol_id = await linker.resolve(
+			ref.target_file_path!,
+			ref.imported_name!,
+		);
+	}
+
+	const bound = extracted.flatMap(item => bindImportedUses(item.refs));
+	await linker.load(bound.map(r => r.target_file_path!));
+	for (const ref of bound) {
+		ref.target_symbol_id = await linker.resolve(
+			ref.target_file_path!,
+			ref.imported_name!,
+		);
+	}
+
+	await linkImplTargets(extracted, linker);
+	await linkNativeMethodOwners(extracted, storage);
+}
+
+/**
+ * Point Rust impl blocks and their methods at the symbols of the type and
+ * trait they implement.
+ */
+async function linkImplTargets(
+	extracted: V2ExtractedArtifacts[],
+	linker: ImportTargetLinker,
+): Promise<void> {
+	for (const item of extracted) {
+		const filePath = item.file.file_path;
+		const targets = new Map<
+			string,
+			{type: string | null; trait: string | null}
+		>();
+		for (const s of item.symbols) {
+			if (!s.impl_type_name) continue;
+			targets.set(s.symbol_id, {
+				type: await linker.resolveImplTarget(filePath, s.impl_type_name),
+				trait: s.impl_trait_name
+					? await linker.resolveImplTarget(filePath, s.impl_trait_name)
+					: null,
+			});
+		}
+		if (targets.size === 0) continue;
+		for (const s of item.symbols) {
+			const target =
+				targets.get(s.symbol_id) ??
+				(s.parent_symbol_id ? targets.get(s.parent_symbol_id) : undefined);
+			if (!target) continue;
+			s.impl_type_symbol_id = target.type;
+			s.impl_trait_symbol_id = target.trait;
+		}
+	}
+}
+
+/**
+ * Give C/C++ methods defined out of line (`Foo::bar` outside the body of
+ * `Foo`) their class as parent: the class or struct with that qualname,
+ * preferring the one in the file with the same stem (`shape.h` for
+ * `shape.cpp`).
+ */
+async function linkNativeMethodOwners(
+	extracted: V2ExtractedArtifacts[],
+	storage: StorageV2,
+): Promise<void> {
+	const orphans = extracted.flatMap(item =>
+		item.symbols.filter(
+			s =>
+				s.symbol_kind === 'method' &&
+				s.parent_symbol_id === null &&
+				isNative(s.language_hint) &&
+				nativeOwner(s.qualname) !== null,
+		),
+	);
+	if (orphans.length === 0) return;
+
+	const batchFiles = new Set(extracted.map(item => item.file.file_path));
+	const owners = new Set(orphans.map(s => nativeOwner(s.qualname)!));
+	const types: NativeType[] = extracted.flatMap(item =>
+		item.symbols
+			.filter(s => isNativeType(s) && owners.has(s.qualname))
+			.map(s => ({
+				symbol_id: s.symbol_id,
+				file_path: s.file_path,
+				qualname: s.qualname,
+			})),
+	);
+	for (const batch of chunked(Array.from(owners), QUERY_BATCH_SIZE)) {
+		for (const t of await storage.getNativeTypes('qualname', batch)) {
+			if (!batchFiles.has(t.file_path)) types.push(t);
+		}
+	}
+
+	for (const s of orphans) {
+		const owner = nativeOwner(s.qualname);
+		const parent = pickNativeCounterpart(
+			s.file_path,
+			types.filter(t => t.qualname === owner),
+		);
+		s.parent_symbol_id = parent?.symbol_id ?? null;
+	}
+}
+
+type NativeType = {symbol_id: string; file_path: string; qualname: string};
+
+function isNative(languageHint: string | null): boolean {
+	return languageHint === 'c' || languageHint === 'cpp';
+}
+
+function isNativeType(s: {
+	language_hint: string | null;
+	symbol_kind: string;
+}): boolean {
+	return (
+		isNative(s.language_hint) &&
+		(s.symbol_kind === 'class' || s.symbol_kind === 'struct')
+	);
+}
+
+/** `geo::Circle` for `geo::Circle::area`. */
+function nativeOwner(qualname: string): string | null {
+	const separator = qualname.lastIndexOf('::');
+	return separator > 0 ? qualname.slice(0, separator) : null;
+}
+
+/**
+ * The candidate most likely paired with `filePath`: same file stem
+ * (`shape.h` / `shape.cpp`) first, then the first candidate.
+ */
+function pickNativeCounterpart<T extends {file_path: string}>(
+	filePath: string,
+	candidates: T[],
+): T | null {
+	const stem = (p: string) => path.posix.basename(p).replace(/\.[^.]*$/, '');
+	return (
+		candidates.find(c => stem(c.file_path) === stem(filePath)) ??
+		candidates[0] ??
+		null
+	);
+}
+
+/**
+ * Point uses of an import (calls, instantiations, identifiers and type
+ * refs) at the import's target: `loadConfig()` after `import {loadConfig}`
+ * binds `loadConfig` in the imported file, and `pricing.Total()` after
+ * `import "…/pricing"` binds `*.Total` (a top-level `Total` of the
+ * package). Returns the bound refs.
+ */
+function bindImportedUses(refs: V2ExtractedRef[]): V2ExtractedRef[] {
+	const imports = new Map<string, V2ExtractedRef>();
+	for (const r of refs) {
+		const local = r.token_texts[0];
+		if (r.ref_kind !== 'import' || !local || !r.target_file_path) continue;
+		if (!imports.has(local)) imports.set(local, r);
+	}
+	if (imports.size === 0) return [];
+
+	const bound: V2ExtractedRef[] = [];
+	for (const ref of refs) {
+		if (!IMPORT_USE_REF_KINDS.has(ref.ref_kind)) continue;
+		const target = ref.has_receiver
+			? importedMember(ref, imports)
+			: importedName(ref, imports);
+		if (!target) continue;
+		ref.target_file_path = target.file_path;
+		ref.imported_name = target.name;
+		bound.push(ref);
+	}
+	return bound;
+}
+
+function importedName(
+	ref: V2ExtractedRef,
+	imports: Map<string, V2ExtractedRef>,
+): {file_path: string; name: string} | null {
+	const imp = imports.get(ref.token_texts[0] ?? '');
+	if (!imp?.target_file_path || !imp.imported_name) return null;
+	if (imp.imported_name === '*') return null;
+	return {file_path: imp.target_file_path, name: imp.imported_name};
+}
+
+/** `recv.member(...)` / `new recv.Member()` where `recv` is an import. */
+function importedMember(
+	ref: V2ExtractedRef,
+	imports: Map<string, V2ExtractedRef>,
+): {file_path: string; name: string} | null {
+	if (ref.ref_kind !== 'call' && ref.ref_kind !== 'instantiation') {
+		return null;
+	}
+	const qualified = ref.token_texts.find(t => t.includes('.'));
+	const [receiver, member, ...rest] = qualified?.split('.') ?? [];
+	if (!receiver || !member || rest.length > 0) return null;
+	const imp = imports.get(receiver);
+	if (!imp?.target_file_path) return null;
+	return {
+		file_path: imp.target_file_path,
+		name: `${imp.imported_name ?? '*'}.${member}`,
+	};
+}
+
+/** Definitions the changed files held before their rows were replaced. */
+export type StoredDefinitions = {
+	/** Top-level symbol names */
+	names: Set<string>;
+	/** C/C++ class and struct qualnames */
+	nativeTypes: Set<string>;
+};
+
+/**
+ * What the given files define in storage. Read before their rows are
+ * replaced, so impls and out-of-line methods naming a removed definition
+ * are re-linked.
+ */
+export async function getStoredDefinitions(
+	storage: StorageV2,
+	filePaths: string[],
+): Promise<StoredDefinitions> {
+	const previous: StoredDefinitions = {
+		names: new Set(),
+		nativeTypes: new Set(),
+	};
+	for (const batch of chunked(filePaths, QUERY_BATCH_SIZE)) {
+		for (const s of await storage.getTopLevelSymbolsForFiles(batch)) {
+			previous.names.add(s.symbol_name);
+		}
+		for (const t of await storage.getNativeTypes('file_path', batch)) {
+			previous.nativeTypes.add(t.qualname);
+		}
+	}
+	return previous;
+}
+
+/**
+ * Re-link stored refs (outside the batch) whose target file changed or was
+ * deleted, so their `target_symbol_id` does not go stale, along with impl
+ * and out-of-line method links to definitions of the changed files.
+ */
+export async function relinkImportTargets(
+	changedFiles: string[],
+	extracted: V2ExtractedArtifacts[],
+	storage: StorageV2,
+	previous: StoredDefinitions,
+	context?: ImportResolutionContext,
+): Promise<number> {
+	if (changedFiles.length === 0) return 0;
+	const batchFiles = new Set(extracted.map(item => item.file.file_path));
+	const linker = new ImportTargetLinker(storage, extracted, context);
+
+	const refIdsByTarget = new Map<string | null, string[]>();
+	let relinked = 0;
+	for (const batch of chunked(changedFiles, QUERY_BATCH_SIZE)) {
+		const refs = await storage.getRefsTargetingFiles(batch);
+		for (const ref of refs) {
+			if (batchFiles.has(ref.file_path)) continue;
+			if (!ref.target_file_path || !ref.imported_name) continue;
+			const target = await linker.resolve(
+				ref.target_file_path,
+				ref.imported_name,
+			);
+			const ids = refIdsByTarget.get(target) ?? [];
+			ids.push(ref.ref_id);
+			refIdsByTarget.set(target, ids);
+			relinked += 1;
+		}
+	}
+
+	for (const [target, refIds] of refIdsByTarget) {
+		for (const batch of chunked(refIds, QUERY_BATCH_SIZE)) {
+			await storage.setRefTargets(batch, target);
+		}
+	}
+
+	await relinkImplTargets(extracted, storage, linker, previous.names);
+	await relinkNativeMethodOwners(extracted, storage, previous.nativeTypes);
+	return relinked;
+}
+
+/**
+ * Re-resolve stored impl blocks outside the batch whose type or trait
+ * name the batch defines or the changed files used to define, updating
+ * impls that resolve alike together.
+ */
+async function relinkImplTargets(
+	extracted: V2ExtractedArtifacts[],
+	storage: StorageV2,
+	linker: ImportTargetLinker,
+	previousNames: ReadonlySet<string>,
+): Promise<void> {
+	const batchFiles = new Set(extracted.map(item => item.file.file_path));
+	const names = new Set(previousNames);
+	for (const item of extracted) {
+		for (const s of item.symbols) {
+			if (s.parent_symbol_id === null) names.add(s.symbol_name);
+		}
+	}
+
+	const seen = new Set<string>();
+	const implIdsByTarget = new Map<
+		string,
+		{type: string | null; trait: string | null; ids: string[]}
+	>();
+	for (const batch of chunked(Array.from(names), QUERY_BATCH_SIZE)) {
+		for (const impl of await storage.getImplSymbolsByName(batch)) {
+			if (batchFiles.has(impl.file_path) || seen.has(impl.symbol_id)) {
+				continue;
+			}
+			seen.add(impl.symbol_id);
+			const type = impl.impl_type_name
+				? await linker.resolveImplTarget(impl.file_path, impl.impl_type_name)
+				: null;
+			const trait = impl.impl_trait_name
+				? await linker.resolveImplTarget(impl.file_path, impl.impl_trait_name)
+				: null;
+			const key = `${type ?? ''}|${trait ?? ''}`;
+			const entry = implIdsByTarget.get(key) ?? {type, trait, ids: []};
+			entry.ids.push(impl.symbol_id);
+			implIdsByTarget.set(key, entry);
+		}
+	}
+
+	for (const {type, trait, ids} of implIdsByTarget.values()) {
+		for (const batch of chunked(ids, QUERY_BATCH_SIZE)) {
+			await storage.setImplTargets(batch, type, trait);
+		}
+	}
+}
+
+/**
+ * Re-link stored out-of-line C/C++ methods outside the batch whose class
+ * the batch defines or the changed files used to define.
+ */
+async function relinkNativeMethodOwners(
+	extracted: V2ExtractedArtifacts[],
+	storage: StorageV2,
+	previousTypes: ReadonlySet<string>,
+): Promise<void> {
+	const batchFiles = new Set(extracted.map(item => item.file.file_path));
+	const owners = new Set(previousTypes);
+	for (const item of extracted) {
+		for (const s of item.symbols) {
+			if (isNativeType(s)) owners.add(s.qualname);
+		}
+	}
+
+	const methodIdsByParent = new Map<string | null, string[]>();
+	for (const batch of chunked(Array.from(owners), QUERY_BATCH_SIZE)) {
+		const methods = (await storage.getNativeMethodsByOwner(batch)).filter(
+			m => !batchFiles.has(m.file_path),
+		);
+		if (methods.length === 0) continue;
+		const batchOwners = new Set(batch);
+		const types = await storage.getNativeTypes('qualname', batch);
+		for (const m of methods) {
+			const owner = nativeOwner(m.qualname);
+			if (owner === null || !batchOwners.has(owner)) continue;
+			const candidates = types.filter(t => t.qualname === owner);
+			// Declared inside its class: extraction already linked it.
+			if (candidates.some(t => t.file_path === m.file_path)) continue;
+			const parent =
+				pickNativeCounterpart(m.file_path, candidates)?.symbol_id ?? null;
+			const ids = methodIdsByParent.get(parent) ?? [];
+			ids.push(m.symbol_id);
+			methodIdsByParent.set(parent, ids);
+		}
+	}
+
+	for (const [parent, ids] of methodIdsByParent) {
+		for (const batch of chunked(ids, QUERY_BATCH_SIZE)) {
+			await storage.setParentSymbol(batch, parent);
+		}
+	}
+}
+
+function chunked<T>(values: T[], size: number): T[][] {
+	const out: T[][] = [];
+	for (let i = 0; i < values.length; i += size) {
+		out.push(values.slice(i, i + size));
+	}
+	return out;
+}
@@ -0,0 +1,79 @@
+/**
+ * Java/Kotlin import resolver.
+ *
+ * Maps fully qualified imports to the project file that declares them,
+ * relying on the one-top-level-class-per-file convention:
+ *
+ * - `com.shop.util.Money` → a `.../com/shop/util/Money.java` (or `.kt`)
+ *   file, whatever source root it lives under (`src/main/java`, ...)
+ * - static/member imports (`com.shop.util.Money.format`) → the class file,
+ *   binding the member name
+ * - top-level Kotlin functions and wildcard imports (`com.shop.text.slug`,
+ *   `com.shop.text.*`) → a file of the package directory
+ *
+ * Classes outside the project (JDK, dependencies) are unresolved.
+ */
+
+import type {ImportResolutionContext} from './project.js';
+
+export type JvmImportTarget = {
+	/** File that declares the class (or a file of the package) */
+	file_path: string;
+	/** Class or member name (null for a whole package) */
+	name: string | null;
+};
+
+const JVM_EXTENSIONS = ['.java', '.kt'];
+
+/**
+ * Resolve a Java/Kotlin import (`module_name` = qualified name) to a file
+ * and name.
+ */
+export function resolveJvmImport(args: {
+	moduleName: string;
+	importedName: string | null;
+	context?: ImportResolutionContext;
+}): JvmImportTarget | null {
+	if (!args.context) return null;
+	const segments = args.moduleName.trim().split('.').filter(Boolean);
+	if (segments.length === 0) return null;
+
+	// The class is the last segment, or the one before a member import
+	for (const end of [segments.length, segments.length - 1]) {
+		if (end < 1) continue;
+		const className = segments[end - 1]!;
+		const suffix = segments.slice(0, end).join('/');
+		for (const ext of JVM_EXTENSIONS) {
+			const file = findBySuffix(
+				args.context.filesByBasename.get(`${className}${ext}`),
+				`${suffix}${ext}`,
+			);
+			if (!file) continue;
+			const member = end < segments.length ? segments[end]! : className;
+			return {file_path: file, name: member};
+		}
+	}
+
+	// Package-level function/property or wildcard import
+	for (const end of [segments.length - 1, segments.length]) {
+		if (end < 1) continue;
+		const suffix = segments.slice(0, end).join('/');
+		for (const [dir, files] of args.context.filesByDir) {
+			if (dir !== suffix && !dir.endsWith(`/${suffix}`)) continue;
+			const file = files.find(f => JVM_EXTENSIONS.some(e => f.endsWith(e)));
+			if (!file) continue;
+			const name = end < segments.length ? args.importedName : null;
+			return {file_path: file, name};
+		}
+	}
+	return null;
+}
+
+function findBySuffix(
+	candidates: string[] | undefined,
+	suffix: string,
+): string | null {
+	return (
+		candidates?.find(f => f === suffix || f.endsWith(`/${suffix}`)) ?? null
+	);
+}
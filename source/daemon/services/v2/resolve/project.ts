@@ -0,0 +1,258 @@
+/**
+ * Project facts import resolution needs beyond the file list.
+ *
+ * - tsconfig.json / jsconfig.json `baseUrl` and `paths` (following relative
+ *   `extends`)
+ * - package.json `name` plus `exports` and entry fields, for imports of
+ *   packages that live in the project (workspaces, monorepo packages)
+ * - go.mod `module` paths
+ *
+ * Manifests under node_modules and vendor directories are ignored.
+ */
+
+import fs from 'node:fs/promises';
+import path from 'node:path';
+import fg from 'fast-glob';
+
+export type TsconfigPaths = {
+	/** Project-relative directory of the tsconfig ('' for the root) */
+	dir: string;
+	/** Project-relative `baseUrl` directory (null when unset) */
+	baseUrl: string | null;
+	/** Directory `paths` targets are relative to */
+	pathsBase: string;
+	paths: Array<{pattern: string; targets: string[]}>;
+};
+
+export type JsPackage = {
+	name: string;
+	/** Project-relative package directory ('' for the root) */
+	dir: string;
+	exports: unknown;
+	/** `source`, `module`, `main` and `types` entries, in that order */
+	entries: string[];
+};
+
+export type GoModule = {
+	/** Module path from go.mod (`example.com/shop`) */
+	module: string;
+	/** Project-relative module directory ('' for the root) */
+	dir: string;
+};
+
+export type ImportResolutionContext = {
+	/** Nearest-first: deeper config directories come first */
+	tsconfigs: TsconfigPaths[];
+	packages: Map<string, JsPackage>;
+	/** Longest module path first */
+	goModules: GoModule[];
+	/** Project files grouped by directory ('' for the root) */
+	filesByDir: Map<string, string[]>;
+	/** Project files grouped by base name */
+	filesByBasename: Map<string, string[]>;
+};
+
+const MANIFEST_PATTERNS = [
+	'**/tsconfig.json',
+	'**/jsconfig.json',
+	'**/package.json',
+	'**/go.mod',
+];
+
+const IGNORED_DIRS = ['**/node_modules/**', '**/vendor/**', '**/.git/**'];
+
+/** How many tsconfig `extends` hops to follow. */
+const MAX_EXTENDS_HOPS = 5;
+
+/**
+ * Read the manifests under `projectRoot` that import resolution uses.
+ */
+export async function loadImportResolutionContext(
+	projectRoot: string,
+	files: ReadonlySet<string>,
+): Promise<ImportResolutionContext> {
+	const context = createImportResolutionContext(files);
+	const manifests = await fg(MANIFEST_PATTERNS, {
+		cwd: projectRoot,
+		ignore: IGNORED_DIRS,
+		dot: false,
+	});
+
+	for (const manifest of manifests.sort()) {
+		const dir = dirOf(manifest);
+		const base = path.posix.basename(manifest);
+		if (base === 'go.mod') {
+			const text = await readText(path.join(projectRoot, manifest));
+			const module = text?.match(/^\s*module\s+"?([^\s"]+)"?/m)?.[1];
+			if (module) context.goModules.push({module, dir});
+		} else if (base === 'package.json') {
+			const pkg = await readJsonc(path.join(projectRoot, manifest));
+			const rawName = pkg?.['name'];
+			const name = typeof rawName === 'string' ? rawName.trim() : '';
+			if (!pkg || !name || context.packages.has(name)) continue;
+			const entries = ['source', 'module', 'main', 'types']
+				.map(field => pkg[field])
+				.filter((v): v is string => typeof v === 'string');
+			context.packages.set(name, {name, dir, exports: pkg['exports'], entries});
+		} else {
+			const config = await loadTsconfig(projectRoot, manifest);
+			if (config) context.tsconfigs.push(config);
+		}
+	}
+
+	context.tsconfigs.sort((a, b) => b.dir.length - a.dir.length);
+	context.goModules.sort((a, b) => b.module.length - a.module.length);
+	return context;
+}
+
+/**
+ * Context with file indexes only (no manifests).
+ */
+export function createImportResolutionContext(
+	files: ReadonlySet<string>,
+): ImportResolutionContext {
+	const filesByDir = new Map<string, string[]>();
+	const filesByBasename = new Map<string, string[]>();
+	for (const file of [...files].sort()) {
+		const dir = dirOf(file);
+		const inDir = filesByDir.get(dir) ?? [];
+		inDir.push(file);
+		filesByDir.set(dir, inDir);
+		const base = path.posix.basename(file);
+		const named = filesByBasename.get(base) ?? [];
+		named.push(file);
+		filesByBasename.set(base, named);
+	}
+	return {
+		tsconfigs: [],
+		packages: new Map(),
+		goModules: [],
+		filesByDir,
+		filesByBasename,
+	};
+}
+
+/**
+ * Project-relative directory of a file ('' for the root).
+ */
+export function dirOf(filePath: string): string {
+	const dir = path.posix.dirname(filePath);
+	return dir === '.' ? '' : dir;
+}
+
+/**
+ * Join project-relative path segments; null when the result leaves the
+ * project root.
+ */
+export function joinProjectPath(...parts: string[]): string | null {
+	const joined = path.posix.normalize(path.posix.join('.', ...parts));
+	if (joined === '..' || joined.startsWith('../')) return null;
+	return joined === '.' ? '' : joined.replace(/^\.\//, '');
+}
+
+async function loadTsconfig(
+	projectRoot: string,
+	manifest: string,
+): Promise<TsconfigPaths | null> {
+	let baseUrl: string | null = null;
+	let paths: TsconfigPaths['paths'] | null = null;
+	let pathsBase: string | null = null;
+
+	// Nearest config wins for each option; `extends` supplies the rest.
+	let current: string | null = manifest;
+	for (let hop = 0; current && hop <= MAX_EXTENDS_HOPS; hop++) {
+		const config = await readJsonc(path.join(projectRoot, current));
+		if (!config) break;
+		const dir = dirOf(current);
+		const options = config['compilerOptions'];
+		if (options && typeof options === 'object') {
+			const o = options as Record<string, unknown>;
+			if (baseUrl === null && typeof o['baseUrl'] === 'string') {
+				baseUrl = joinProjectPath(dir, o['baseUrl']);
+			}
+			if (paths === null && o['paths'] && typeof o['paths'] === 'object') {
+				paths = Object.entries(o['paths'] as Record<string, unknown>).map(
+					([pattern, targets]) => ({
+						pattern,
+						targets: Array.isArray(targets)
+							? targets.filter((t): t is string => typeof t === 'string')
+							: [],
+					}),
+				);
+				pathsBase = dir;
+			}
+		}
+		const parent = config['extends'];
+		current =
+			typeof parent === 'string' && parent.startsWith('.')
+				? joinProjectPath(
+						dir,
+						parent.endsWith('.json') ? parent : `${parent}.json`,
+					)
+				: null;
+	}
+
+	if (baseUrl === null && paths === null) return null;
+	return {
+		dir: dirOf(manifest),
+		baseUrl,
+		// `paths` resolve against baseUrl when set, else their own config
+		pathsBase: baseUrl ?? pathsBase ?? dirOf(manifest),
+		paths: paths ?? [],
+	};
+}
+
+async function readText(filePath: string): Promise<string | null> {
+	try {
+		return await fs.readFile(filePath, 'utf-8');
+	} catch {
+		return null;
+	}
+}
+
+/**
+ * Parse JSON allowing comments and trailing commas (tsconfig style).
+ */
+async function readJsonc(
+	filePath: string,
+): Promise<Record<string, unknown> | null> {
+	const text = await readText(filePath);
+	if (text === null) return null;
+	try {
+		const parsed = JSON.parse(stripJsonComments(text)) as unknown;
+		return parsed && typeof parsed === 'object'
+			? (parsed as Record<string, unknown>)
+			: null;
+	} catch {
+		return null;
+	}
+}
+
+function stripJsonComments(text: string): string {
+	let out = '';
+	let inString = false;
+	for (let i = 0; i < text.length; i++) {
+		const ch = text[i]!;
+		if (inString) {
+			out += ch;
+			if (ch === '\\') {
+				out += text[i + 1] ?? '';
+				i += 1;
+			} else if (ch === '"') {
+				inString = false;
+			}
+		} else if (ch === '"') {
+			inString = true;
+			out += ch;
+		} else if (ch === '/' && text[i + 1] === '/') {
+			while (i < text.length && text[i] !== '\n') i += 1;
+			out += '\n';
+		} else if (ch === '/' && text[i + 1] === '*') {
+			const end = text.indexOf('*/', i + 2);
+			i = end === -1 ? text.length : end + 1;
+		} else {
+			out += ch;
+		}
+	}
+	return out.replace(/,(\s*[}\]])/g, '$1');
+}
@@ -0,0 +1,93 @@
+/**
+ * Python module resolver.
+ *
+ * Maps `import` / `from ... import` statements to the project file that
+ * defines the target module or name:
+ *
+ * - relative imports (`from . import x`, `from ..pkg import y`) resolve
+ *   against the importing file's package, one directory up per extra dot
+ * - absolute imports try each ancestor of the importing file as a source
+ *   root, outermost first (covers flat layouts and `src/` layouts)
+ * - `from pkg import mod` binds the submodule `pkg/mod.py` when it exists,
+ *   otherwise the name `mod` inside `pkg/__init__.py`
+ *
+ * Modules outside the project (stdlib, site-packages) are unresolved.
+ */
+
+import {dirOf, joinProjectPath} from './project.js';
+
+export type PythonImportTarget = {
+	/** File that defines the target module */
+	file_path: string;
+	/** Name inside that module (null when the import names a module) */
+	name: string | null;
+};
+
+/**
+ * Resolve a Python import ref (`module_name` + `imported_name`) to a file
+ * and name.
+ */
+export function resolvePythonImport(args: {
+	filePath: string;
+	moduleName: string;
+	importedName: string | null;
+	files: ReadonlySet<string>;
+}): PythonImportTarget | null {
+	const moduleName = args.moduleName.trim();
+	const dots = moduleName.match(/^\.*/)?.[0].length ?? 0;
+	const modulePath = moduleName.slice(dots).split('.').filter(Boolean);
+	// `import a.b` names the module itself; `from a import b` names a member
+	const importsModule =
+		dots === 0 && modulePath[modulePath.length - 1] === args.importedName;
+
+	for (const root of sourceRoots(args.filePath, dots)) {
+		const base = joinProjectPath(root, ...modulePath);
+		if (base === null) continue;
+		if (importsModule) {
+			const file = resolveModuleFile(base, args.files);
+			if (file) return {file_path: file, name: null};
+			continue;
+		}
+		if (args.importedName) {
+			const sub = joinProjectPath(base, args.importedName);
+			const submodule =
+				sub === null ? null : resolveModuleFile(sub, args.files);
+			if (submodule) return {file_path: submodule, name: null};
+		}
+		const file = resolveModuleFile(base, args.files);
+		if (file) return {file_path: file, name: args.importedName};
+	}
+	return null;
+}
+
+/**
+ * Directories an import may be relative to: the package `dots - 1` levels
+ * above the importing file, or every ancestor (root first) for absolute
+ * imports.
+ */
+function sourceRoots(filePath: string, dots: number): string[] {
+	let dir = dirOf(filePath);
+	if (dots > 0) {
+		for (let i = 1; i < dots; i++) {
+			if (dir === '') return [];
+			dir = dirOf(dir);
+		}
+		return [dir];
+	}
+	const roots = [dir];
+	while (dir !== '') {
+		dir = dirOf(dir);
+		roots.push(dir);
+	}
+	return roots.reverse();
+}
+
+function resolveModuleFile(
+	base: string,
+	files: ReadonlySet<string>,
+): string | null {
+	const candidates = base
+		? [`${base}.py`, `${base}.pyi`, `${base}/__init__.py`]
+		: ['__init__.py'];
+	return candidates.find(c => files.has(c)) ?? null;
+}
@@ -0,0 +1,180 @@
+/**
+ * Rust module resolver.
+ *
+ * Maps `use` paths and `mod foo;` declarations to the file that defines the
+ * target module, following the default Cargo layout:
+ *
+ * - crate root: `src/lib.rs` / `src/main.rs` (nearest ancestor)
+ * - `mod foo;` in a module whose directory is `D` → `D/foo.rs` or `D/foo/mod.rs`
+ * - `crate::`, `self::` and `super::` are resolved relative to the importing file
+ *
+ * Resolution is purely path-based (no `#[path]` attributes, no macro-generated
+ * modules). Paths that leave the crate (std, external crates) are unresolved.
+ */
+
+import path from 'node:path';
+
+export type RustImportTarget = {
+	/** File that defines the target module */
+	file_path: string;
+	/** Item name inside that module (null when the path names a module) */
+	name: string | null;
+};
+
+type RustModule = {
+	/** File that defines the module (`foo.rs`, `foo/mod.rs`, `lib.rs`) */
+	file: string;
+	/** Directory holding the module's child module files */
+	dir: string;
+};
+
+const CRATE_ROOT_FILES = ['lib.rs', 'main.rs'];
+
+/**
+ * Resolve a Rust import ref (`module_name` + `imported_name`) to a file and
+ * item name.
+ */
+export function resolveRustImport(args: {
+	filePath: string;
+	moduleName: string;
+	importedName: string | null;
+	files: ReadonlySet<string>;
+}): RustImportTarget | null {
+	const segments = buildRustImportPath(args.moduleName, args.importedName);
+	if (segments.length === 0) return null;
+
+	const crate = findCrateRoot(args.filePath, args.files);
+	const current = moduleOfFile(args.filePath, crate);
+
+	let module: RustModule | null;
+	let index = 0;
+	const head = segments[0]!;
+	if (head === 'crate') {
+		module = crate;
+		index = 1;
+	} else if (head === 'self') {
+		module = current;
+		index = 1;
+	} else if (head === 'super') {
+		module = current;
+		while (module && segments[index] === 'super') {
+			module = parentModule(module, crate, args.files);
+			index += 1;
+		}
+	} else {
+		// Rust 2018: a leading segment may name a child module of the current
+		// module; 2015-style paths are relative to the crate root.
+		module = childModule(current, head, crate, args.files)
+			? current
+			: childModule(crate, head, crate, args.files)
+				? crate
+				: null;
+	}
+	if (!module) return null;
+
+	for (; index < segments.length; index++) {
+		const segment = segments[index]!;
+		if (segment === 'self') continue;
+		const child = childModule(module, segment, crate, args.files);
+		if (!child) {
+			return {file_path: module.file, name: segment};
+		}
+		module = child;
+	}
+
+	return {file_path: module.file, name: null};
+}
+
+/**
+ * Rebuild the full path of an import ref. Single-segment imports
+ * (`use foo;`, `extern crate foo;`) store the same value in both fields.
+ */
+function buildRustImportPath(
+	moduleName: string,
+	importedName: string | null,
+): string[] {
+	const moduleSegments = moduleName
+		.split('::')
+		.map(s => s.trim())
+		.filter(Boolean);
+	if (!importedName) return moduleSegments;
+	if (moduleSegments.length === 1 && moduleSegments[0] === importedName) {
+		return moduleSegments;
+	}
+	return [...moduleSegments, importedName];
+}
+
+function findCrateRoot(
+	filePath: string,
+	files: ReadonlySet<string>,
+): RustModule {
+	const ancestors: string[] = [];
+	let dir = path.posix.dirname(filePath);
+	for (;;) {
+		ancestors.push(dir);
+		const parent = path.posix.dirname(dir);
+		if (parent === dir) break;
+		dir = parent;
+	}
+
+	// Prefer the conventional `src/` crate root, then any directory holding a
+	// lib.rs/main.rs.
+	const candidates = [
+		...ancestors.filter(d => path.posix.basename(d) === 'src'),
+		...ancestors,
+	];
+	for (const candidate of candidates) {
+		for (const rootFile of CRATE_ROOT_FILES) {
+			const file = joinPath(candidate, rootFile);
+			if (files.has(file)) {
+				return {file, dir: candidate};
+			}
+		}
+	}
+
+	// Standalone file: treat it as its own crate root.
+	return {file: filePath, dir: path.posix.dirname(filePath)};
+}
+
+function moduleOfFile(filePath: string, crate: RustModule): RustModule {
+	if (filePath === crate.file) return crate;
+	const dir = path.posix.dirname(filePath);
+	const base = path.posix.basename(filePath);
+	if (base === 'mod.rs') {
+		return {file: filePath, dir};
+	}
+	return {file: filePath, dir: joinPath(dir, base.replace(/\.rs$/, ''))};
+}
+
+function childModule(
+	module: RustModule,
+	name: string,
+	crate: RustModule,
+	files: ReadonlySet<string>,
+): RustModule | null {
+	const flat = joinPath(module.dir, `${name}.rs`);
+	if (files.has(flat)) return moduleOfFile(flat, crate);
+	const nested = joinPath(module.dir, name, 'mod.rs');
+	if (files.has(nested)) return moduleOfFile(nested, crate);
+	return null;
+}
+
+function parentModule(
+	module: RustModule,
+	crate: RustModule,
+	files: ReadonlySet<string>,
+): RustModule | null {
+	if (module.file === crate.file) return null;
+	const parentDir = path.posix.dirname(module.dir);
+	if (parentDir === crate.dir) return crate;
+
+	const flat = `${parentDir}.rs`;
+	if (files.has(flat)) return moduleOfFile(flat, crate);
+	const nested = joinPath(parentDir, 'mod.rs');
+	if (files.has(nested)) return moduleOfFile(nested, crate);
+	return null;
+}
+
+function joinPath(...parts: string[]): string {
+	return path.posix.join(...parts);
+}
@@ -0,0 +1,307 @@
+/**
+ * TypeScript/JavaScript module resolver.
+ *
+ * Maps import specifiers to the project file that defines the module:
+ *
+ * - relative specifiers (`./util.js`, `../lib`): TypeScript sources stand in
+ *   for `.js` specifiers; extensionless paths try each source extension and
+ *   `index` files
+ * - tsconfig/jsconfig `paths` aliases and `baseUrl` (nearest config)
+ * - project packages by name, through package.json `exports` and then the
+ *   `source`/`module`/`main`/`types` entries. Build output paths (`dist/`,
+ *   `build/`, `lib/`) map back to `src/` when the built file isn't indexed.
+ *
+ * Packages outside the project (node_modules) are unresolved.
+ */
+
+import path from 'node:path';
+import {
+	dirOf,
+	joinProjectPath,
+	type ImportResolutionContext,
+	type JsPackage,
+	type TsconfigPaths,
+} from './project.js';
+
+export type JsImportTarget = {
+	/** File that defines the target module */
+	file_path: string;
+	/** Exported name (null for namespace and side-effect imports) */
+	name: string | null;
+};
+
+const SOURCE_EXTENSIONS = [
+	'.ts',
+	'.tsx',
+	'.mts',
+	'.cts',
+	'.js',
+	'.jsx',
+	'.mjs',
+	'.cjs',
+	'.vue',
+	'.svelte',
+	'.astro',
+];
+
+/** TypeScript sources compiled to each JavaScript extension. */
+const COMPILED_FROM: Record<string, string[]> = {
+	'.js': ['.ts', '.tsx'],
+	'.jsx': ['.tsx'],
+	'.mjs': ['.mts'],
+	'.cjs': ['.cts'],
+};
+
+const BUILD_OUTPUT_DIRS = new Set([
+	'dist',
+	'build',
+	'lib',
+	'out',
+	'esm',
+	'cjs',
+]);
+
+/** package.json `exports` conditions, most source-like first. */
+const EXPORT_CONDITIONS = [
+	'source',
+	'types',
+	'import',
+	'module',
+	'default',
+	'require',
+	'node',
+];
+
+/**
+ * Resolve a JS/TS import ref (`module_name` = specifier) to a file and
+ * exported name.
+ */
+export function resolveJsImport(args: {
+	filePath: string;
+	moduleName: string;
+	importedName: string | null;
+	files: ReadonlySet<string>;
+	context?: ImportResolutionContext;
+}): JsImportTarget | null {
+	const file = resolveJsModule(args);
+	if (!file) return null;
+	const name =
+		args.importedName && args.importedName !== '*' ? args.importedName : null;
+	return {file_path: file, name};
+}
+
+function resolveJsModule(args: {
+	filePath: string;
+	moduleName: string;
+	files: ReadonlySet<string>;
+	context?: ImportResolutionContext;
+}): string | null {
+	const specifier = args.moduleName.trim();
+	if (!specifier || specifier.startsWith('/')) return null;
+
+	if (specifier.startsWith('.')) {
+		const base = joinProjectPath(dirOf(args.filePath), specifier);
+		return base === null ? null : resolveJsFile(base, args.files);
+	}
+	if (!args.context) return null;
+
+	const tsconfig = args.context.tsconfigs.find(
+		c => c.dir === '' || args.filePath.startsWith(`${c.dir}/`),
+	);
+	if (tsconfig) {
+		const aliased = resolvePathsAlias(specifier, tsconfig, args.files);
+		if (aliased) return aliased;
+		if (tsconfig.baseUrl !== null) {
+			const base = joinProjectPath(tsconfig.baseUrl, specifier);
+			const file = base === null ? null : resolveJsFile(base, args.files);
+			if (file) return file;
+		}
+	}
+
+	return resolvePackageImport(specifier, args.context, args.files);
+}
+
+/**
+ * Match a specifier against tsconfig `paths` (exact patterns first, then
+ * the wildcard pattern with the longest prefix).
+ */
+function resolvePathsAlias(
+	specifier: string,
+	tsconfig: TsconfigPaths,
+	files: ReadonlySet<string>,
+): string | null {
+	let best: {targets: string[]; capture: string; prefix: number} | null =
+		null;
+	for (const {pattern, targets} of tsconfig.paths) {
+		const star = pattern.indexOf('*');
+		if (star === -1) {
+			if (pattern === specifier) {
+				best = {targets, capture: '', prefix: Number.MAX_SAFE_INTEGER};
+				break;
+			}
+			continue;
+		}
+		const prefix = pattern.slice(0, star);
+		const suffix = pattern.slice(star + 1);
+		if (
+			specifier.length >= prefix.length + suffix.length &&
+			specifier.startsWith(prefix) &&
+			specifier.endsWith(suffix) &&
+			(!best || prefix.length > best.prefix)
+		) {
+			best = {
+				targets,
+				capture: specifier.slice(
+					prefix.length,
+					specifier.length - suffix.length,
+				),
+				prefix: prefix.length,
+			};
+		}
+	}
+	if (!best) return null;
+
+	for (const target of best.targets) {
+		const base = joinProjectPath(
+			tsconfig.pathsBase,
+			target.replace('*', best.capture),
+		);
+		const file = base === null ? null : resolveJsFile(base, files);
+		if (file) return file;
+	}
+	return null;
+}
+
+/**
+ * `@scope/name/sub` / `name/sub` for a package that lives in the project.
+ */
+function resolvePackageImport(
+	specifier: string,
+	context: ImportResolutionContext,
+	files: ReadonlySet<string>,
+): string | null {
+	const parts = specifier.split('/');
+	const nameLength = specifier.startsWith('@') ? 2 : 1;
+	const pkg = context.packages.get(parts.slice(0, nameLength).join('/'));
+	if (!pkg) return null;
+	const rest = parts.slice(nameLength).join('/');
+	const subpath = rest ? `./${rest}` : '.';
+
+	const exported = resolveExportsTarget(pkg.exports, subpath);
+	if (exported) return resolvePackageFile(pkg, exported, files);
+	if (pkg.exports != null) return null;
+
+	if (rest) return resolvePackageFile(pkg, rest, files);
+	for (const entry of pkg.entries) {
+		const file = resolvePackageFile(pkg, entry, files);
+		if (file) return file;
+	}
+	return resolvePackageFile(pkg, 'index', files);
+}
+
+/**
+ * A package-relative target, falling back from build output to `src/`.
+ */
+function resolvePackageFile(
+	pkg: JsPackage,
+	target: string,
+	files: ReadonlySet<string>,
+): string | null {
+	const relative = path.posix.normalize(target).replace(/^\.\//, '');
+	const candidates = [relative];
+	const [head, ...tail] = relative.split('/');
+	if (head && BUILD_OUTPUT_DIRS.has(head) && tail.length > 0) {
+		candidates.push(['src', ...tail].join('/'), tail.join('/'));
+	}
+	for (const candidate of candidates) {
+		const base = joinProjectPath(pkg.dir, candidate);
+		const file = base === null ? null : resolveJsFile(base, files);
+		if (file) return file;
+	}
+	return null;
+}
+
+/**
+ * Target of a package.json `exports` field for a subpath (`.`, `./sub`).
+ */
+function resolveExportsTarget(
+	exports: unknown,
+	subpath: string,
+): string | null {
+	if (typeof exports === 'string' || Array.isArray(exports)) {
+		return subpath === '.' ? pickExportCondition(exports) : null;
+	}
+	if (!exports || typeof exports !== 'object') return null;
+
+	const map = exports as Record<string, unknown>;
+	const keys = Object.keys(map);
+	// `{"import": "./x.js", ...}` is a conditions object for `.`
+	if (!keys.some(k => k.startsWith('.'))) {
+		return subpath === '.' ? pickExportCondition(map) : null;
+	}
+	if (subpath in map) return pickExportCondition(map[subpath]);
+
+	for (const key of keys) {
+		const star = key.indexOf('*');
+		if (star === -1) continue;
+		const prefix = key.slice(0, star);
+		const suffix = key.slice(star + 1);
+		if (
+			subpath.length < prefix.length + suffix.length ||
+			!subpath.startsWith(prefix) ||
+			!subpath.endsWith(suffix)
+		) {
+			continue;
+		}
+		const capture = subpath.slice(
+			prefix.length,
+			subpath.length - suffix.length,
+		);
+		return pickExportCondition(map[key])?.replaceAll('*', capture) ?? null;
+	}
+	return null;
+}
+
+function pickExportCondition(value: unknown): string | null {
+	if (typeof value === 'string') return value;
+	if (Array.isArray(value)) {
+		for (const item of value) {
+			const picked = pickExportCondition(item);
+			if (picked) return picked;
+		}
+		return null;
+	}
+	if (!value || typeof value !== 'object') return null;
+	const conditions = value as Record<string, unknown>;
+	for (const condition of EXPORT_CONDITIONS) {
+		if (!(condition in conditions)) continue;
+		const picked = pickExportCondition(conditions[condition]);
+		if (picked) return picked;
+	}
+	return null;
+}
+
+/**
+ * Source file for a module path: the path itself, the TypeScript source of
+ * a compiled `.js`/`.d.ts` path, the path plus an extension, or its index.
+ */
+function resolveJsFile(
+	base: string,
+	files: ReadonlySet<string>,
+): string | null {
+	const candidates = [base];
+	if (base.endsWith('.d.ts')) {
+		candidates.push(`${base.slice(0, -'.d.ts'.length)}.ts`);
+	}
+	const ext = path.posix.extname(base);
+	for (const source of COMPILED_FROM[ext] ?? []) {
+		candidates.push(`${base.slice(0, -ext.length)}${source}`);
+	}
+	for (const source of SOURCE_EXTENSIONS) {
+		candidates.push(`${base}${source}`);
+	}
+	for (const source of SOURCE_EXTENSIONS) {
+		candidates.push(base ? `${base}/index${source}` : `index${source}`);
+	}
+	return candidates.find(c => files.has(c)) ?? null;
+}
@@ -5,6 +5,7 @@
  * stable follow-up handles.
  */
 
+import path from 'node:path';
 import * as lancedb from '@lancedb/lancedb';
 import type {Table} from '@lancedb/lancedb';
 import {
@@ -22,6 +23,9 @@ import {MistralEmbeddingProvider} from '../../../providers/mistral.js';
 import {OpenAIEmbeddingProvider} from '../../../providers/openai.js';
 import type {EmbeddingProvider} from '../../../providers/types.js';
 import {StorageV2} from '../storage/index.js';
+import type {V2RefKind} from '../storage/types.js';
+import {languageHintFromExtension} from '../extract/extract.js';
+import {bindsImportedNames} from '../resolve/index.js';
 import {
 	checkV2IndexCompatibility,
 	V2ReindexRequiredError,
@@ -37,12 +41,46 @@ import type {
 	V2NextAction,
 	V2FindUsagesOptions,
 	V2FindUsagesResponse,
+	V2AccessFilter,
+	V2FindImplementationsOptions,
+	V2FindImplementationsResponse,
+	V2Implementation,
+	V2ImplementationVia,
+	V2CallGraphOptions,
+	V2CallGraphResponse,
+	V2CallGraphNode,
+	V2CallEdge,
 	V2UsageRef,
 	V2SearchWarning,
 } from './types.js';
 
 const DEFAULT_K = 20;
 const RRF_K = 60;
+const MAX_CHILD_SYMBOLS = 500;
+const MAX_DISPATCH_TARGETS = 200;
+const MAX_CALL_SITES = 5000;
+
+/**
+ * Symbol kinds a call site can bind to (classes for constructor calls,
+ * targets for Make/just prerequisites).
+ */
+const CALL_GRAPH_CALLABLE_KINDS = new Set([
+	'function',
+	'method',
+	'macro',
+	'class',
+	'target',
+]);
+
+/**
+ * Symbol kinds whose bodies are walked for further calls.
+ */
+const CALL_GRAPH_EXPANDABLE_KINDS = new Set([
+	'function',
+	'method',
+	'macro',
+	'target',
+]);
 
 export type SearchEngineV2Options = {
 	logger?: Logger;
@@ -61,10 +99,15 @@ type Candidate = {
 	title: string;
 	snippet: string;
 	is_exported?: boolean;
+	is_test?: boolean;
 	ref_kind?: string;
+	access?: string | null;
 	token_text?: string;
 	module_name?: string | null;
 	imported_name?: string | null;
+	target_file_path?: string | null;
+	target_symbol_id?: string | null;
+	has_receiver?: boolean;
 	channels: V2ExplainChannel[];
 };
 
@@ -132,6 +175,7 @@ export class SearchEngineV2 {
 		await this.ensureInitialized();
 		await this.ensureIndexCompatible();
 		const filterClause = buildScopeFilter(scope);
+		const symbolFilterClause = buildSymbolScopeFilter(scope);
 
 		const warnings: V2SearchWarning[] = [];
 		const needsVector =
@@ -158,7 +202,7 @@ export class SearchEngineV2 {
 					query,
 					queryVector,
 					k,
-					filterClause,
+					symbolFilterClause,
 					explain,
 				);
 				groups.definitions = defs;
@@ -177,7 +221,7 @@ export class SearchEngineV2 {
 						query,
 						queryVector,
 						Math.max(10, Math.round(k / 2)),
-						filterClause,
+						symbolFilterClause,
 						explain,
 					),
 					this.retrieveBlocks(
@@ -238,7 +282,7 @@ export class SearchEngineV2 {
 						query,
 						queryVector,
 						Math.max(10, Math.round(k / 2)),
-						filterClause,
+						symbolFilterClause,
 						explain,
 					),
 					this.retrieveBlocks(
@@ -289,7 +333,13 @@ export class SearchEngineV2 {
 				'signature',
 				'docstring',
 				'is_exported',
+				'visibility',
 				'decorator_names',
+				'impl_type_name',
+				'impl_trait_name',
+				'impl_type_symbol_id',
+				'impl_trait_symbol_id',
+				'is_test',
 				'context_header',
 				'code_text',
 				'identifiers',
@@ -303,7 +353,447 @@ export class SearchEngineV2 {
 			.toArray();
 		if (rows.length === 0) return null;
 		const row = rows[0] as Record<string, unknown>;
-		return normalizeJsonRecord(row);
+		const symbol = normalizeJsonRecord(row);
+
+		if (symbol['impl_type_name']) {
+			symbol['impl_targets'] = await this.getImplTargets(
+				symbol['impl_type_symbol_id']
+					? String(symbol['impl_type_symbol_id'])
+					: null,
+				symbol['impl_trait_symbol_id']
+					? String(symbol['impl_trait_symbol_id'])
+					: null,
+			);
+		}
+
+		const children = await this.getChildSymbols(symbol_id);
+		if (children.length > 0) {
+			symbol['children'] = children;
+		}
+
+		const languageHint = symbol['language_hint'];
+		if (languageHint === 'c' || languageHint === 'cpp') {
+			await this.linkNativeDeclarations(symbol);
+		}
+
+		const parentId = symbol['parent_symbol_id']
+			? String(symbol['parent_symbol_id'])
+			: null;
+		if (symbol['symbol_kind'] === 'method' && parentId) {
+			const dispatch = await this.resolveMethodDispatch(
+				String(symbol['symbol_name']),
+				parentId,
+			);
+			if (dispatch.implementations.length > 0) {
+				symbol['implementations'] = dispatch.implementations;
+			}
+			if (dispatch.declarations.length > 0) {
+				symbol['declarations'] = dispatch.declarations;
+			}
+		}
+
+		return symbol;
+	}
+
+	/**
+	 * Trait/interface method dispatch in both directions:
+	 * - implementations: same-named methods on the types (and Rust impl
+	 *   blocks) that implement or extend the method's owner
+	 * - declarations: same-named methods on the traits/interfaces/base types
+	 *   the owner implements (Rust impl trait, declared supertypes, Go
+	 *   interfaces the receiver type satisfies)
+	 */
+	private async resolveMethodDispatch(
+		methodName: string,
+		parentId: string,
+	): Promise<{
+		implementations: Array<Record<string, unknown>>;
+		declarations: Array<Record<string, unknown>>;
+	}> {
+		const table = await this.getSymbolsTable();
+		const parents = await table
+			.query()
+			.where(`symbol_id = '${escapeForEquality(parentId)}'`)
+			.select([
+				'symbol_name',
+				'file_path',
+				'extension',
+				'start_line',
+				'end_line',
+				'impl_type_name',
+				'impl_trait_name',
+				'impl_trait_symbol_id',
+				'supertypes',
+			])
+			.limit(1)
+			.toArray();
+		if (parents.length === 0) return {implementations: [], declarations: []};
+		const parent = normalizeJsonRecord(parents[0] as Record<string, unknown>);
+
+		// Forward: implementors of the owner. Rust impl blocks own methods on
+		// behalf of a type and cannot be implemented themselves.
+		let implementations: Array<Record<string, unknown>> = [];
+		if (!parent['impl_type_name']) {
+			const implementors = await this.findImplementors(
+				String(parent['symbol_name']),
+				MAX_DISPATCH_TARGETS,
+				undefined,
+			);
+			implementations = await this.getMethodsOf(
+				methodName,
+				implementors.map(i => i.symbol_id).filter(id => id !== parentId),
+			);
+		}
+
+		// Reverse: the declarations this method satisfies. A Rust trait impl
+		// declares its trait, linked at index time.
+		const supertypesRaw = parent['supertypes'];
+		const declaredIn = Array.isArray(supertypesRaw)
+			? supertypesRaw.map(String)
+			: [];
+		let declarations: Array<Record<string, unknown>> = [];
+		if (parent['impl_trait_name']) {
+			const traitId = parent['impl_trait_symbol_id'];
+			declarations = traitId
+				? await this.getMethodsOf(methodName, [String(traitId)])
+				: [];
+		} else if (declaredIn.length > 0) {
+			declarations = await this.getMethodsOf(
+				methodName,
+				await this.resolveSupertypes(parent, declaredIn),
+			);
+		} else if (parent['extension'] === '.go') {
+			declarations = await this.getGoInterfaceDeclarations(
+				methodName,
+				parentId,
+			);
+		}
+
+		return {implementations, declarations};
+	}
+
+	/**
+	 * C/C++ header/implementation pairs, matched by qualname across files:
+	 * a bodiless prototype gets the `definitions` that implement it, a
+	 * definition gets its `prototypes`.
+	 */
+	private async linkNativeDeclarations(
+		symbol: Record<string, unknown>,
+	): Promise<void> {
+		const kind = String(symbol['symbol_kind']);
+		if (kind !== 'function' && kind !== 'method') return;
+		const table = await this.getSymbolsTable();
+		const qualname = String(symbol['qualname']);
+		const native = `language_hint IN ('c', 'cpp')`;
+
+		const rows = await table
+			.query()
+			.where(
+				`${native} AND symbol_kind = '${escapeForEquality(kind)}' AND ` +
+					`qualname = '${escapeForEquality(qualname)}' AND ` +
+					`symbol_id <> '${escapeForEquality(String(symbol['symbol_id']))}'`,
+			)
+			.select([
+				'symbol_id',
+				'symbol_kind',
+				'qualname',
+				'file_path',
+				'start_line',
+				'end_line',
+				'signature',
+				'code_text',
+			])
+			.limit(MAX_DISPATCH_TARGETS)
+			.toArray();
+		const isDefinition = hasNativeBody(String(symbol['code_text'] ?? ''));
+		const counterparts = rows
+			.map(r => normalizeJsonRecord(r as Record<string, unknown>))
+			.filter(r => hasNativeBody(String(r['code_text'] ?? '')) !== isDefinition)
+			.map(r => {
+				delete r['code_text'];
+				return r;
+			})
+			.sort(
+				(a, b) =>
+					String(a['file_path']).localeCompare(String(b['file_path'])) ||
+					Number(a['start_line']) - Number(b['start_line']),
+			);
+		if (counterparts.length > 0) {
+			symbol[isDefinition ? 'prototypes' : 'definitions'] = counterparts;
+		}
+	}
+
+	/**
+	 * Methods named `methodName` whose parent is one of `ownerIds`.
+	 */
+	private async getMethodsOf(
+		methodName: string,
+		ownerIds: string[],
+	): Promise<Array<Record<string, unknown>>> {
+		if (ownerIds.length === 0) return [];
+		const table = await this.getSymbolsTable();
+		const idList = ownerIds.map(id => `'${escapeForEquality(id)}'`).join(', ');
+		const rows = await table
+			.query()
+			.where(
+				`symbol_name = '${escapeForEquality(methodName)}' AND ` +
+					`symbol_kind = 'method' AND parent_symbol_id IN (${idList})`,
+			)
+			.select([
+				'symbol_id',
+				'symbol_kind',
+				'qualname',
+				'file_path',
+				'start_line',
+				'end_line',
+				'signature',
+				'parent_symbol_id',
+			])
+			.limit(MAX_DISPATCH_TARGETS)
+			.toArray();
+		return rows
+			.map(r => normalizeJsonRecord(r as Record<string, unknown>))
+			.sort(
+				(a, b) =>
+					String(a['file_path']).localeCompare(String(b['file_path'])) ||
+					Number(a['start_line']) - Number(b['start_line']),
+			);
+	}
+
+	/**
+	 * Symbols of the supertypes `type` declares: the targets its
+	 * `extends`/`implements` refs were bound to through imports, else the
+	 * same-named types of its file, else of its directory, else the only
+	 * one with its extension.
+	 */
+	private async resolveSupertypes(
+		type: Record<string, unknown>,
+		names: string[],
+	): Promise<string[]> {
+		const filePath = String(type['file_path']);
+		const wanted = new Set(names);
+		const ids = new Set<string>();
+		const bound = new Set<string>();
+		const refsTable = await this.getRefsTable();
+		const refs = await refsTable
+			.query()
+			.where(
+				`file_path = '${escapeForEquality(filePath)}' AND ` +
+					`ref_kind IN ('extends', 'implements') AND ` +
+					`start_line >= ${Number(type['start_line'])} AND ` +
+					`start_line <= ${Number(type['end_line'])} AND ` +
+					`target_symbol_id IS NOT NULL`,
+			)
+			.select(['token_texts', 'target_symbol_id'])
+			.limit(MAX_DISPATCH_TARGETS)
+			.toArray();
+		for (const row of refs) {
+			const r = normalizeJsonRecord(row as Record<string, unknown>);
+			const tokensRaw = r['token_texts'];
+			const tokens = Array.isArray(tokensRaw) ? tokensRaw.map(String) : [];
+			const name = tokens[0]?.split(/::|\./).pop() ?? '';
+			if (!wanted.has(name)) continue;
+			bound.add(name);
+			ids.add(String(r['target_symbol_id']));
+		}
+
+		const unbound = names.filter(n => !bound.has(n));
+		if (unbound.length === 0) return [...ids];
+		const table = await this.getSymbolsTable();
+		const nameList = unbound
+			.map(n => `'${escapeForEquality(n)}'`)
+			.join(', ');
+		const rows = await table
+			.query()
+			.where(
+				`symbol_name IN (${nameList}) AND impl_type_name IS NULL AND ` +
+					`extension = '${escapeForEquality(String(type['extension']))}'`,
+			)
+			.select(['symbol_id', 'symbol_name', 'file_path'])
+			.limit(MAX_DISPATCH_TARGETS)
+			.toArray();
+		const owners = rows.map(r =>
+			normalizeJsonRecord(r as Record<string, unknown>),
+		);
+		const dir = path.posix.dirname(filePath);
+		for (const name of unbound) {
+			const named = owners.filter(r => r['symbol_name'] === name);
+			const sameFile = named.filter(r => r['file_path'] === filePath);
+			const sameDir = named.filter(
+				r => path.posix.dirname(String(r['file_path'])) === dir,
+			);
+			const picked =
+				sameFile.length > 0
+					? sameFile
+					: sameDir.length > 0
+						? sameDir
+						: named.length === 1
+							? named
+							: [];
+			for (const r of picked) ids.add(String(r['symbol_id']));
+		}
+		return [...ids];
+	}
+
+	/**
+	 * Go interface method elements named `methodName` whose interface the
+	 * receiver type `typeId` satisfies structurally: the type's package
+	 * declares a method on it for every element of the interface.
+	 * Embedded interfaces are not expanded.
+	 */
+	private async getGoInterfaceDeclarations(
+		methodName: string,
+		typeId: string,
+	): Promise<Array<Record<string, unknown>>> {
+		const table = await this.getSymbolsTable();
+		const [typeRow] = await table
+			.query()
+			.where(`symbol_id = '${escapeForEquality(typeId)}'`)
+			.select(['symbol_name', 'file_path'])
+			.limit(1)
+			.toArray();
+		if (!typeRow) return [];
+		const type = normalizeJsonRecord(typeRow as Record<string, unknown>);
+		const typeName = String(type['symbol_name']);
+		const typeKey = goTypeKey(String(type['file_path']), typeName);
+
+		// Interfaces declaring `methodName`, then all of their elements.
+		const named = await table
+			.query()
+			.where(
+				`extension = '.go' AND symbol_kind = 'method' AND ` +
+					`symbol_name = '${escapeForEquality(methodName)}' AND ` +
+					`parent_symbol_id IS NOT NULL`,
+			)
+			.select(['parent_symbol_id'])
+			.limit(MAX_CALL_SITES)
+			.toArray();
+		const parentList = [
+			...new Set(
+				named.map(r =>
+					String((r as Record<string, unknown>)['parent_symbol_id']),
+				),
+			),
+		]
+			.map(id => `'${escapeForEquality(id)}'`)
+			.join(', ');
+		if (!parentList) return [];
+		const interfaces = await table
+			.query()
+			.where(`symbol_id IN (${parentList}) AND symbol_kind = 'interface'`)
+			.select(['symbol_id'])
+			.limit(MAX_DISPATCH_TARGETS)
+			.toArray();
+		const interfaceIds = interfaces.map(r =>
+			String((r as Record<string, unknown>)['symbol_id']),
+		);
+		if (interfaceIds.length === 0) return [];
+		const idList = interfaceIds
+			.map(id => `'${escapeForEquality(id)}'`)
+			.join(', ');
+		const elements = await table
+			.query()
+			.where(`parent_symbol_id IN (${idList}) AND symbol_kind = 'method'`)
+			.select(['parent_symbol_id', 'symbol_name'])
+			.limit(MAX_CHILD_SYMBOLS)
+			.toArray();
+		const required = new Map<string, Set<string>>();
+		for (const row of elements) {
+			const r = row as Record<string, unknown>;
+			const parent = String(r['parent_symbol_id']);
+			const set = required.get(parent) ?? new Set<string>();
+			set.add(String(r['symbol_name']));
+			required.set(parent, set);
+		}
+
+		// The receiver type's method set, restricted to those names.
+		const qualnames = [...new Set([...required.values()].flatMap(s => [...s]))]
+			.map(n => `'${escapeForEquality(`${typeName}.${n}`)}'`)
+			.join(', ');
+		const methods = await table
+			.query()
+			.where(
+				`extension = '.go' AND symbol_kind = 'method' AND ` +
+					`qualname IN (${qualnames})`,
+			)
+			.select(['symbol_name', 'file_path'])
+			.limit(MAX_CALL_SITES)
+			.toArray();
+		const methodSet = new Set(
+			methods
+				.map(r => r as Record<string, unknown>)
+				.filter(r => goTypeKey(String(r['file_path']), typeName) === typeKey)
+				.map(r => String(r['symbol_name'])),
+		);
+
+		const satisfied = interfaceIds.filter(id =>
+			[...(required.get(id) ?? [])].every(n => methodSet.has(n)),
+		);
+		return this.getMethodsOf(methodName, satisfied);
+	}
+
+	/**
+	 * Direct members of a symbol (methods, fields, variants, associated
+	 * items), in source order.
+	 */
+	private async getChildSymbols(
+		symbol_id: string,
+	): Promise<Array<Record<string, unknown>>> {
+		const table = await this.getSymbolsTable();
+		const rows = await table
+			.query()
+			.where(`parent_symbol_id = '${escapeForEquality(symbol_id)}'`)
+			.select([
+				'symbol_id',
+				'symbol_kind',
+				'symbol_name',
+				'qualname',
+				'start_line',
+				'end_line',
+				'signature',
+			])
+			.limit(MAX_CHILD_SYMBOLS)
+			.toArray();
+		return rows
+			.map(r => normalizeJsonRecord(r as Record<string, unknown>))
+			.sort((a, b) => Number(a['start_line']) - Number(b['start_line']));
+	}
+
+	/**
+	 * Rust impl blocks: the implementing type and trait, as linked at index
+	 * time.
+	 */
+	private async getImplTargets(
+		typeSymbolId: string | null,
+		traitSymbolId: string | null,
+	): Promise<{type: unknown[]; trait: unknown[]}> {
+		const ids = [typeSymbolId, traitSymbolId].filter(
+			(id): id is string => !!id,
+		);
+		if (ids.length === 0) return {type: [], trait: []};
+		const table = await this.getSymbolsTable();
+		const inList = ids.map(id => `'${escapeForEquality(id)}'`).join(', ');
+		const rows = await table
+			.query()
+			.where(`symbol_id IN (${inList})`)
+			.select([
+				'symbol_id',
+				'file_path',
+				'start_line',
+				'end_line',
+				'symbol_kind',
+				'symbol_name',
+				'qualname',
+			])
+			.limit(ids.length)
+			.toArray();
+		const targets = rows.map(r =>
+			normalizeJsonRecord(r as Record<string, unknown>),
+		);
+		return {
+			type: targets.filter(t => t['symbol_id'] === typeSymbolId),
+			trait: targets.filter(t => t['symbol_id'] === traitSymbolId),
+		};
 	}
 
 	async expandContext(args: {
@@ -412,16 +902,25 @@ export class SearchEngineV2 {
 		await this.ensureIndexCompatible();
 		const k = options.k ?? 200;
 		const scope = options.scope ?? {};
-		const filterClause = buildScopeFilter(scope);
+		const filterClause = andClauses(
+			buildScopeFilter(scope),
+			buildRefKindFilter(options.ref_kinds),
+			buildAccessFilter(options.access),
+		);
 
 		const resolvedSymbolId = options.symbol_id?.trim() || undefined;
 		let resolvedSymbolName = options.symbol_name?.trim() || '';
 
-		if (resolvedSymbolId) {
-			const symbolRow = await this.resolveSymbolNameFromId(resolvedSymbolId);
-			if (symbolRow?.symbol_name) {
-				resolvedSymbolName = symbolRow.symbol_name;
-			}
+		const symbolRow = resolvedSymbolId
+			? await this.resolveSymbolNameFromId(resolvedSymbolId)
+			: null;
+		if (symbolRow) {
+			// Config keys are referenced by their full path
+			// (`"database.pool.max_size"`), not their last segment
+			resolvedSymbolName =
+				symbolRow.symbol_kind === 'key' && symbolRow.qualname
+					? symbolRow.qualname
+					: symbolRow.symbol_name;
 		}
 
 		if (!resolvedSymbolName) {
@@ -439,7 +938,7 @@ export class SearchEngineV2 {
 		});
 
 		const oversample = Math.min(5000, Math.max(k * 12, 200));
-		const candidates = await this.ftsCandidatesRefs(
+		const nameHits = await this.ftsCandidatesRefs(
 			refsTable,
 			resolvedSymbolName,
 			'token_texts',
@@ -448,6 +947,31 @@ export class SearchEngineV2 {
 			'refs.token_texts',
 		);
 
+		// Import refs resolved to this exact symbol (covers renamed imports);
+		// drop name matches whose import resolves to a different symbol.
+		const linkedHits = resolvedSymbolId
+			? await this.exactCandidatesRefs(
+					refsTable,
+					`target_symbol_id = '${escapeForEquality(resolvedSymbolId)}'`,
+					oversample,
+					filterClause,
+					'refs.target_symbol_id',
+				)
+			: [];
+		const linkedIds = new Set(linkedHits.map(c => c.id));
+		const candidates = [
+			...linkedHits,
+			...nameHits.filter(
+				c =>
+					!linkedIds.has(c.id) &&
+					!(
+						resolvedSymbolId &&
+						c.target_symbol_id &&
+						c.target_symbol_id !== resolvedSymbolId
+					),
+			),
+		];
+
 		const reranked = rerankCandidates(candidates, {
 			intent: 'usage',
 			explain: true,
@@ -458,70 +982,766 @@ export class SearchEngineV2 {
 
 		const needle = resolvedSymbolName.toLowerCase();
 		const exact = reranked.filter(
-			r => (r.token_text ?? '').toLowerCase() === needle,
+			r =>
+				(r.token_text ?? '').toLowerCase() === needle ||
+				(resolvedSymbolId != null && r.target_symbol_id === resolvedSymbolId),
 		);
 		const chosen = exact.length > 0 ? exact : reranked;
 		const limited = chosen.slice(0, k);
 
-		const byFile = new Map<string, V2UsageRef[]>();
-		for (const hit of limited) {
-			const key = hit.file_path;
-			const list = byFile.get(key) ?? [];
-			list.push({
-				ref_id: hit.id,
-				file_path: hit.file_path,
-				start_line: hit.start_line,
-				end_line: hit.end_line,
-				ref_kind:
-					(hit.ref_kind as V2UsageRef['ref_kind']) ??
-					('identifier' as V2UsageRef['ref_kind']),
-				token_text: hit.token_text ?? resolvedSymbolName,
-				context_snippet: hit.snippet,
-				score: Number(hit.score.toFixed(8)),
-				why: {
-					channels: hit.channels.map(ch => ({
-						...ch,
-						rawScore: Number(ch.rawScore.toFixed(8)),
-					})),
-					priors: hit.priors,
-				},
-				module_name: hit.module_name ?? null,
-				imported_name: hit.imported_name ?? null,
+		// With a symbol id, refs bound to it are exact and refs bound
+		// elsewhere merely share its name. An unbound ref is exact only where
+		// it could not have been bound: a language whose imports bind no
+		// names, the definition's own file, or a receiver-less use in the
+		// same Go package.
+		const isExactRef = (hit: Candidate): boolean => {
+			if (!symbolRow || symbolRow.symbol_kind === 'key') return true;
+			if (hit.target_symbol_id === resolvedSymbolId) return true;
+			if (hit.target_file_path) {
+				return hit.target_file_path === symbolRow.file_path;
+			}
+			if (hit.file_path === symbolRow.file_path) return true;
+			const language = languageHintFromExtension(
+				path.posix.extname(hit.file_path),
+			);
+			if (!bindsImportedNames(language)) return true;
+			return (
+				!hit.has_receiver &&
+				inSameGoPackage(hit.file_path, symbolRow.file_path)
+			);
+		};
+
+		const byFile = new Map<string, V2UsageRef[]>();
+		const sameNameByFile = new Map<string, V2UsageRef[]>();
+		for (const hit of limited) {
+			const key = hit.file_path;
+			const groups = isExactRef(hit) ? byFile : sameNameByFile;
+			const list = groups.get(key) ?? [];
+			list.push({
+				ref_id: hit.id,
+				file_path: hit.file_path,
+				start_line: hit.start_line,
+				end_line: hit.end_line,
+				ref_kind:
+					(hit.ref_kind as V2UsageRef['ref_kind']) ??
+					('identifier' as V2UsageRef['ref_kind']),
+				access: (hit.access as V2UsageRef['access']) ?? null,
+				token_text: hit.token_text ?? resolvedSymbolName,
+				context_snippet: hit.snippet,
+				score: Number(hit.score.toFixed(8)),
+				why: {
+					channels: hit.channels.map(ch => ({
+						...ch,
+						rawScore: Number(ch.rawScore.toFixed(8)),
+					})),
+					priors: hit.priors,
+				},
+				module_name: hit.module_name ?? null,
+				imported_name: hit.imported_name ?? null,
+				target_symbol_id: hit.target_symbol_id ?? null,
+			});
+			groups.set(key, list);
+		}
+
+		const grouped = groupUsagesByFile(byFile);
+		const sameNameGrouped = groupUsagesByFile(sameNameByFile);
+
+		const suggested_next_actions: V2NextAction[] = [];
+		const first = grouped[0]?.refs[0];
+		if (first) {
+			suggested_next_actions.push({
+				tool: 'read_file_lines',
+				args: {
+					file_path: first.file_path,
+					start_line: first.start_line,
+					end_line: first.end_line,
+				},
+			});
+		}
+
+		return {
+			query: {symbol_id: options.symbol_id, symbol_name: options.symbol_name},
+			resolved: {
+				...(resolvedSymbolId ? {symbol_id: resolvedSymbolId} : {}),
+				symbol_name: resolvedSymbolName,
+			},
+			filters_applied: scope,
+			by_file: grouped,
+			total_refs: grouped.reduce((sum, g) => sum + g.refs.length, 0),
+			same_name_by_file: sameNameGrouped,
+			total_same_name_refs: sameNameGrouped.reduce(
+				(sum, g) => sum + g.refs.length,
+				0,
+			),
+			suggested_next_actions,
+		};
+	}
+
+	/**
+	 * Types implementing a trait/interface/base type: Rust impl blocks and
+	 * derives, declared supertypes (`extends`/`implements`, Python bases,
+	 * Swift conformances, supertraits) and Go types whose methods cover an
+	 * interface's method set. Matching is by base name.
+	 */
+	async findImplementations(
+		options: V2FindImplementationsOptions,
+	): Promise<V2FindImplementationsResponse> {
+		await this.ensureInitialized();
+		await this.ensureIndexCompatible();
+		const k = options.k ?? 100;
+		const scope = options.scope ?? {};
+		const filterClause = buildSymbolScopeFilter(scope);
+
+		const resolvedSymbolId = options.symbol_id?.trim() || undefined;
+		let resolvedSymbolName = options.symbol_name?.trim() || '';
+
+		if (resolvedSymbolId) {
+			const symbolRow = await this.resolveSymbolNameFromId(resolvedSymbolId);
+			if (symbolRow?.symbol_name) {
+				resolvedSymbolName = symbolRow.symbol_name;
+			}
+		}
+
+		// `fmt::Display` / `io.Reader` -> `Display` / `Reader`
+		const baseName = resolvedSymbolName.split(/::|\./).pop()?.trim() ?? '';
+		if (!baseName) {
+			throw new Error('findImplementations requires symbol_id or symbol_name');
+		}
+
+		const implementations = await this.findImplementors(
+			baseName,
+			k,
+			filterClause,
+		);
+		const limited = implementations
+			.sort(
+				(a, b) =>
+					a.file_path.localeCompare(b.file_path) ||
+					a.start_line - b.start_line,
+			)
+			.slice(0, k);
+
+		const suggested_next_actions: V2NextAction[] = [];
+		const first = limited[0];
+		if (first) {
+			suggested_next_actions.push(
+				{tool: 'get_symbol_details', args: {symbol_id: first.symbol_id}},
+				{
+					tool: 'read_file_lines',
+					args: {
+						file_path: first.file_path,
+						start_line: first.start_line,
+						end_line: first.end_line,
+					},
+				},
+			);
+		}
+
+		return {
+			query: {symbol_id: options.symbol_id, symbol_name: options.symbol_name},
+			resolved: {
+				...(resolvedSymbolId ? {symbol_id: resolvedSymbolId} : {}),
+				symbol_name: baseName,
+			},
+			filters_applied: scope,
+			implementations: limited,
+			total: limited.length,
+			suggested_next_actions,
+		};
+	}
+
+	/**
+	 * Implementing types (or Rust impl blocks) for a trait/interface/base
+	 * type base name.
+	 */
+	private async findImplementors(
+		baseName: string,
+		limit: number,
+		filterClause: string | undefined,
+	): Promise<V2Implementation[]> {
+		const table = await this.getSymbolsTable();
+		const name = `'${escapeForEquality(baseName)}'`;
+		const where = [
+			`impl_trait_name = ${name}`,
+			`array_has_any(supertypes, [${name}])`,
+			`array_has_any(derives, [${name}])`,
+		].join(' OR ');
+		const rows = await table
+			.query()
+			.where(filterClause ? `(${where}) AND (${filterClause})` : where)
+			.select([
+				'symbol_id',
+				'symbol_kind',
+				'symbol_name',
+				'qualname',
+				'file_path',
+				'start_line',
+				'end_line',
+				'impl_type_name',
+				'impl_trait_name',
+				'derives',
+			])
+			.limit(limit)
+			.toArray();
+
+		const implementations: V2Implementation[] = rows.map(row => {
+			const r = normalizeJsonRecord(row as Record<string, unknown>);
+			const derivesRaw = r['derives'];
+			const derives = Array.isArray(derivesRaw) ? derivesRaw.map(String) : [];
+			const via: V2ImplementationVia =
+				r['impl_trait_name'] === baseName
+					? 'impl'
+					: derives.includes(baseName)
+						? 'derive'
+						: 'supertype';
+			return {
+				symbol_id: String(r['symbol_id']),
+				symbol_kind: String(r['symbol_kind']),
+				qualname: String(r['qualname'] ?? r['symbol_name']),
+				file_path: String(r['file_path']),
+				start_line: Number(r['start_line']),
+				end_line: Number(r['end_line']),
+				type_name: String(
+					via === 'impl' ? r['impl_type_name'] : r['symbol_name'],
+				),
+				via,
+			};
+		});
+
+		const seen = new Set(implementations.map(i => i.symbol_id));
+		for (const match of await this.findGoStructuralImplementations(
+			baseName,
+			limit,
+			filterClause,
+		)) {
+			if (!seen.has(match.symbol_id)) implementations.push(match);
+		}
+
+		return implementations;
+	}
+
+	/**
+	 * Go interfaces are satisfied implicitly: find types in the same package
+	 * that declare a method for every method of an interface named
+	 * `interfaceName`. Embedded interfaces are not expanded.
+	 */
+	private async findGoStructuralImplementations(
+		interfaceName: string,
+		limit: number,
+		filterClause: string | undefined,
+	): Promise<V2Implementation[]> {
+		const table = await this.getSymbolsTable();
+		const interfaces = await table
+			.query()
+			.where(
+				`symbol_name = '${escapeForEquality(interfaceName)}' AND ` +
+					`extension = '.go' AND symbol_kind = 'interface'`,
+			)
+			.select(['symbol_id'])
+			.limit(20)
+			.toArray();
+
+		const interfaceIds = interfaces.map(r =>
+			String((r as Record<string, unknown>)['symbol_id']),
+		);
+		if (interfaceIds.length === 0) return [];
+		const idList = interfaceIds
+			.map(id => `'${escapeForEquality(id)}'`)
+			.join(', ');
+		const interfaceMethods = await table
+			.query()
+			.where(`parent_symbol_id IN (${idList}) AND symbol_kind = 'method'`)
+			.select(['symbol_name'])
+			.limit(MAX_CHILD_SYMBOLS)
+			.toArray();
+		const required = [
+			...new Set(
+				interfaceMethods.map(r =>
+					String((r as Record<string, unknown>)['symbol_name']),
+				),
+			),
+		];
+		if (required.length === 0) return [];
+
+		// Receiver methods with a matching name, grouped by package + type.
+		const nameList = required
+			.map(n => `'${escapeForEquality(n)}'`)
+			.join(', ');
+		const methods = await table
+			.query()
+			.where(
+				`extension = '.go' AND symbol_kind = 'method' AND ` +
+					`symbol_name IN (${nameList}) AND ` +
+					`(parent_symbol_id IS NULL OR parent_symbol_id NOT IN (${idList}))`,
+			)
+			.select(['symbol_name', 'qualname', 'file_path', 'signature'])
+			.limit(5000)
+			.toArray();
+		const methodsByType = new Map<string, Set<string>>();
+		for (const row of methods) {
+			const r = row as Record<string, unknown>;
+			// Interface method elements have no `func` keyword.
+			if (!String(r['signature'] ?? '').startsWith('func')) continue;
+			const qualname = String(r['qualname']);
+			const typeName = qualname.slice(0, qualname.lastIndexOf('.'));
+			if (!typeName) continue;
+			const key = goTypeKey(String(r['file_path']), typeName);
+			const set = methodsByType.get(key) ?? new Set<string>();
+			set.add(String(r['symbol_name']));
+			methodsByType.set(key, set);
+		}
+		const satisfying = [...methodsByType.entries()]
+			.filter(([, names]) => required.every(n => names.has(n)))
+			.map(([key]) => key);
+		if (satisfying.length === 0) return [];
+
+		const typeNames = [
+			...new Set(satisfying.map(key => key.slice(key.indexOf('|') + 1))),
+		];
+		const typeList = typeNames
+			.map(n => `'${escapeForEquality(n)}'`)
+			.join(', ');
+		const where =
+			`extension = '.go' AND symbol_kind IN ('struct', 'class') AND ` +
+			`symbol_name IN (${typeList})`;
+		const typeRows = await table
+			.query()
+			.where(filterClause ? `(${where}) AND (${filterClause})` : where)
+			.select([
+				'symbol_id',
+				'symbol_kind',
+				'symbol_name',
+				'qualname',
+				'file_path',
+				'start_line',
+				'end_line',
+			])
+			.limit(limit)
+			.toArray();
+
+		const wanted = new Set(satisfying);
+		return typeRows
+			.map(row => normalizeJsonRecord(row as Record<string, unknown>))
+			.filter(
+				r =>
+					!interfaceIds.includes(String(r['symbol_id'])) &&
+					wanted.has(
+						goTypeKey(String(r['file_path']), String(r['symbol_name'])),
+					),
+			)
+			.map(r => ({
+				symbol_id: String(r['symbol_id']),
+				symbol_kind: String(r['symbol_kind']),
+				qualname: String(r['qualname'] ?? r['symbol_name']),
+				file_path: String(r['file_path']),
+				start_line: Number(r['start_line']),
+				end_line: Number(r['end_line']),
+				type_name: String(r['symbol_name']),
+				via: 'structural' as const,
+				matched_methods: required,
+			}));
+	}
+
+	/**
+	 * Callers and callees of a symbol, following call refs up to `depth`
+	 * hops. Each call site is bound to a definition when possible (see
+	 * `V2CallResolution`); only edges bound to a definition are followed.
+	 */
+	async getCallGraph(
+		options: V2CallGraphOptions,
+	): Promise<V2CallGraphResponse> {
+		await this.ensureInitialized();
+		await this.ensureIndexCompatible();
+		const direction = options.direction ?? 'both';
+		const depth = Math.max(1, options.depth ?? 2);
+		const k = options.k ?? 200;
+		const scope = options.scope ?? {};
+		const filterClause = buildScopeFilter(scope);
+
+		const symbolId = options.symbol_id.trim();
+		if (!symbolId) {
+			throw new Error('getCallGraph requires symbol_id');
+		}
+		const cache: CallGraphCache = {
+			fileSymbols: new Map(),
+			candidates: new Map(),
+			byId: new Map(),
+		};
+		const root = await this.callGraphSymbolById(symbolId, cache);
+		if (!root) {
+			throw new Error(`Symbol not found: ${symbolId}`);
+		}
+
+		const nodes = new Map<string, CallGraphSymbol>([[root.symbol_id, root]]);
+		const callees =
+			direction === 'callers'
+				? {edges: [], truncated: false}
+				: await this.walkCallees(root, depth, k, filterClause, cache, nodes);
+		const callers =
+			direction === 'callees'
+				? {edges: [], truncated: false}
+				: await this.walkCallers(root, depth, k, filterClause, cache, nodes);
+
+		const suggested_next_actions: V2NextAction[] = [];
+		const next = [...callers.edges, ...callees.edges]
+			.map(e => (e.callee_id === root.symbol_id ? e.caller_id : e.callee_id))
+			.find((id): id is string => id != null && id !== root.symbol_id);
+		if (next) {
+			suggested_next_actions.push({
+				tool: 'get_symbol_details',
+				args: {symbol_id: next},
+			});
+		}
+		suggested_next_actions.push({
+			tool: 'read_file_lines',
+			args: {
+				file_path: root.file_path,
+				start_line: root.start_line,
+				end_line: root.end_line,
+			},
+		});
+
+		return {
+			query: {symbol_id: options.symbol_id},
+			root: toCallGraphNode(root),
+			direction,
+			depth,
+			filters_applied: scope,
+			nodes: [...nodes.values()].map(toCallGraphNode),
+			callers: callers.edges,
+			callees: callees.edges,
+			truncated: callers.truncated || callees.truncated,
+			suggested_next_actions,
+		};
+	}
+
+	/**
+	 * Breadth-first walk over the calls made inside each symbol's span.
+	 */
+	private async walkCallees(
+		root: CallGraphSymbol,
+		depth: number,
+		limit: number,
+		filterClause: string | undefined,
+		cache: CallGraphCache,
+		nodes: Map<string, CallGraphSymbol>,
+	): Promise<{edges: V2CallEdge[]; truncated: boolean}> {
+		const edges: V2CallEdge[] = [];
+		const edgeKeys = new Set<string>();
+		const expanded = new Set([root.symbol_id]);
+		let frontier = [root];
+		let truncated = false;
+
+		for (let d = 1; d <= depth && frontier.length > 0; d++) {
+			const next: CallGraphSymbol[] = [];
+			for (const caller of frontier) {
+				const span =
+					caller.start_byte != null && caller.end_byte != null
+						? `start_byte >= ${caller.start_byte} AND ` +
+							`end_byte <= ${caller.end_byte}`
+						: `start_line >= ${caller.start_line} AND ` +
+							`end_line <= ${caller.end_line}`;
+				const inSpan = await this.queryCallSites(
+					`file_path = '${escapeForEquality(caller.file_path)}' AND ${span}`,
+					filterClause,
+				);
+				truncated ||= inSpan.truncated;
+				for (const site of inSpan.sites) {
+					const target = await this.resolveCallSite(
+						site,
+						caller.language_hint,
+						cache,
+					);
+					const key = `${caller.symbol_id}|${target?.symbol_id ?? site.name}`;
+					if (edgeKeys.has(key)) continue;
+					if (edges.length >= limit) return {edges, truncated: true};
+					edgeKeys.add(key);
+					edges.push({
+						caller_id: caller.symbol_id,
+						callee_id: target?.symbol_id ?? null,
+						callee_name: site.name,
+						resolution: target ? 'definition' : 'name',
+						file_path: site.file_path,
+						line: site.line,
+						depth: d,
+					});
+					if (!target) continue;
+					nodes.set(target.symbol_id, target);
+					if (
+						!expanded.has(target.symbol_id) &&
+						CALL_GRAPH_EXPANDABLE_KINDS.has(target.symbol_kind)
+					) {
+						expanded.add(target.symbol_id);
+						next.push(target);
+					}
+				}
+			}
+			frontier = next;
+		}
+
+		return {edges, truncated};
+	}
+
+	/**
+	 * Breadth-first walk over call sites naming each symbol. A site counts
+	 * when it resolves to the symbol or to no definition at all (name-only).
+	 */
+	private async walkCallers(
+		root: CallGraphSymbol,
+		depth: number,
+		limit: number,
+		filterClause: string | undefined,
+		cache: CallGraphCache,
+		nodes: Map<string, CallGraphSymbol>,
+	): Promise<{edges: V2CallEdge[]; truncated: boolean}> {
+		const edges: V2CallEdge[] = [];
+		const edgeKeys = new Set<string>();
+		const expanded = new Set([root.symbol_id]);
+		let frontier = [root];
+		let truncated = false;
+
+		for (let d = 1; d <= depth && frontier.length > 0; d++) {
+			const next: CallGraphSymbol[] = [];
+			for (const callee of frontier) {
+				// Sites bound to the callee first; name matching only covers
+				// sites indexing could not bind.
+				const name = `'${escapeForEquality(callee.symbol_name)}'`;
+				const id = `'${escapeForEquality(callee.symbol_id)}'`;
+				const bound = await this.queryCallSites(
+					`target_symbol_id = ${id}`,
+					filterClause,
+				);
+				const unbound = await this.queryCallSites(
+					`target_symbol_id IS NULL AND ` +
+						`array_has_any(token_texts, [${name}])`,
+					filterClause,
+				);
+				truncated ||= bound.truncated || unbound.truncated;
+				for (const site of [...bound.sites, ...unbound.sites]) {
+					if (
+						site.target_symbol_id !== callee.symbol_id &&
+						site.name !== callee.symbol_name
+					) {
+						continue;
+					}
+					const fileSymbols = await this.callGraphSymbolsInFile(
+						site.file_path,
+						cache,
+					);
+					const target = await this.resolveCallSite(
+						site,
+						fileSymbols[0]?.language_hint ?? null,
+						cache,
+					);
+					if (target && target.symbol_id !== callee.symbol_id) continue;
+					const caller = innermostSymbolAt(fileSymbols, site);
+					const callerKey = caller?.symbol_id ?? site.file_path;
+					const key = `${callee.symbol_id}|${callerKey}`;
+					if (edgeKeys.has(key)) continue;
+					if (edges.length >= limit) return {edges, truncated: true};
+					edgeKeys.add(key);
+					edges.push({
+						caller_id: caller?.symbol_id ?? null,
+						callee_id: callee.symbol_id,
+						callee_name: site.name,
+						resolution: target ? 'definition' : 'name',
+						file_path: site.file_path,
+						line: site.line,
+						depth: d,
+					});
+					if (!caller) continue;
+					nodes.set(caller.symbol_id, caller);
+					if (
+						target &&
+						!expanded.has(caller.symbol_id) &&
+						CALL_GRAPH_EXPANDABLE_KINDS.has(caller.symbol_kind)
+					) {
+						expanded.add(caller.symbol_id);
+						next.push(caller);
+					}
+				}
+			}
+			frontier = next;
+		}
+
+		return {edges, truncated};
+	}
+
+	/**
+	 * Call sites matching `where`, capped at `MAX_CALL_SITES` (`truncated`
+	 * when the cap was hit).
+	 */
+	private async queryCallSites(
+		where: string,
+		filterClause: string | undefined,
+	): Promise<{sites: CallSite[]; truncated: boolean}> {
+		const table = await this.getRefsTable();
+		const clause = `ref_kind IN ('call', 'instantiation') AND ${where}`;
+		const rows = await table
+			.query()
+			.where(filterClause ? `(${clause}) AND (${filterClause})` : clause)
+			.select([
+				'file_path',
+				'start_line',
+				'start_byte',
+				'token_texts',
+				'target_symbol_id',
+			])
+			.limit(MAX_CALL_SITES)
+			.toArray();
+
+		const sites: CallSite[] = [];
+		for (const row of rows) {
+			const r = normalizeJsonRecord(row as Record<string, unknown>);
+			const tokensRaw = r['token_texts'];
+			const tokens = Array.isArray(tokensRaw) ? tokensRaw.map(String) : [];
+			// `[base, receiver.method?]`; the base is absent for non-identifier
+			// callees, leaving only the qualified token.
+			const name = tokens[0]?.split('.').pop() ?? '';
+			if (!name) continue;
+			sites.push({
+				name,
+				qualified: tokens.find(t => t.includes('.')) ?? null,
+				file_path: String(r['file_path']),
+				line: Number(r['start_line']),
+				start_byte: r['start_byte'] == null ? null : Number(r['start_byte']),
+				target_symbol_id:
+					r['target_symbol_id'] == null ? null : String(r['target_symbol_id']),
 			});
-			byFile.set(key, list);
+		}
+		sites.sort(
+			(a, b) =>
+				a.file_path.localeCompare(b.file_path) ||
+				a.line - b.line ||
+				(a.start_byte ?? 0) - (b.start_byte ?? 0),
+		);
+		return {sites, truncated: rows.length >= MAX_CALL_SITES};
+	}
+
+	/**
+	 * Bind a call site to one definition: the symbol indexing bound it to
+	 * through an import, else the one callable of that name (same language
+	 * family) matching the qualified `receiver.method` token, defined in the
+	 * calling file, or defined anywhere. Ambiguous or unknown names are null.
+	 */
+	private async resolveCallSite(
+		site: CallSite,
+		languageHint: string | null,
+		cache: CallGraphCache,
+	): Promise<CallGraphSymbol | null> {
+		if (site.target_symbol_id) {
+			const bound = await this.callGraphSymbolById(
+				site.target_symbol_id,
+				cache,
+			);
+			if (bound) return bound;
 		}
 
-		const grouped = [...byFile.entries()]
-			.sort(([a], [b]) => a.localeCompare(b))
-			.map(([file_path, refs]) => ({
-				file_path,
-				refs: refs.sort((a, b) => a.start_line - b.start_line),
-			}));
+		const family = languageFamily(languageHint);
+		const named = await this.callGraphCandidates(site.name, cache);
+		const candidates = named.filter(
+			c => languageFamily(c.language_hint) === family,
+		);
+		if (candidates.length <= 1) return candidates[0] ?? null;
 
-		const suggested_next_actions: V2NextAction[] = [];
-		const first = grouped[0]?.refs[0];
-		if (first) {
-			suggested_next_actions.push({
-				tool: 'read_file_lines',
-				args: {
-					file_path: first.file_path,
-					start_line: first.start_line,
-					end_line: first.end_line,
-				},
+		if (site.qualified) {
+			const suffix = site.qualified;
+			const qualified = candidates.filter(c => {
+				const q = c.qualname.replaceAll('::', '.');
+				return q === suffix || q.endsWith(`.${suffix}`);
 			});
+			if (qualified.length === 1) return qualified[0]!;
 		}
 
-		return {
-			query: {symbol_id: options.symbol_id, symbol_name: options.symbol_name},
-			resolved: {
-				...(resolvedSymbolId ? {symbol_id: resolvedSymbolId} : {}),
-				symbol_name: resolvedSymbolName,
-			},
-			filters_applied: scope,
-			by_file: grouped,
-			total_refs: grouped.reduce((sum, g) => sum + g.refs.length, 0),
-			suggested_next_actions,
-		};
+		const local = candidates.filter(c => c.file_path === site.file_path);
+		if (local.length === 1) return local[0]!;
+		return null;
+	}
+
+	private async callGraphSymbolById(
+		symbol_id: string,
+		cache: CallGraphCache,
+	): Promise<CallGraphSymbol | null> {
+		const cached = cache.byId.get(symbol_id);
+		if (cached !== undefined) return cached;
+		const [symbol] = await this.queryCallGraphSymbols(
+			`symbol_id = '${escapeForEquality(symbol_id)}'`,
+			1,
+		);
+		cache.byId.set(symbol_id, symbol ?? null);
+		return symbol ?? null;
+	}
+
+	private async callGraphCandidates(
+		name: string,
+		cache: CallGraphCache,
+	): Promise<CallGraphSymbol[]> {
+		const cached = cache.candidates.get(name);
+		if (cached) return cached;
+		const kinds = [...CALL_GRAPH_CALLABLE_KINDS]
+			.map(kind => `'${kind}'`)
+			.join(', ');
+		const candidates = await this.queryCallGraphSymbols(
+			`symbol_name = '${escapeForEquality(name)}' AND ` +
+				`symbol_kind IN (${kinds})`,
+			MAX_DISPATCH_TARGETS,
+		);
+		cache.candidates.set(name, candidates);
+		return candidates;
+	}
+
+	private async callGraphSymbolsInFile(
+		file_path: string,
+		cache: CallGraphCache,
+	): Promise<CallGraphSymbol[]> {
+		const cached = cache.fileSymbols.get(file_path);
+		if (cached) return cached;
+		const symbols = await this.queryCallGraphSymbols(
+			`file_path = '${escapeForEquality(file_path)}'`,
+			MAX_CALL_SITES,
+		);
+		cache.fileSymbols.set(file_path, symbols);
+		return symbols;
+	}
+
+	private async queryCallGraphSymbols(
+		where: string,
+		limit: number,
+	): Promise<CallGraphSymbol[]> {
+		const table = await this.getSymbolsTable();
+		const rows = await table
+			.query()
+			.where(where)
+			.select([
+				'symbol_id',
+				'symbol_kind',
+				'symbol_name',
+				'qualname',
+				'file_path',
+				'language_hint',
+				'start_line',
+				'end_line',
+				'start_byte',
+				'end_byte',
+			])
+			.limit(limit)
+			.toArray();
+		return rows.map(row => {
+			const r = normalizeJsonRecord(row as Record<string, unknown>);
+			return {
+				symbol_id: String(r['symbol_id']),
+				symbol_kind: String(r['symbol_kind']),
+				symbol_name: String(r['symbol_name']),
+				qualname: String(r['qualname'] ?? r['symbol_name']),
+				file_path: String(r['file_path']),
+				language_hint:
+					r['language_hint'] == null ? null : String(r['language_hint']),
+				start_line: Number(r['start_line']),
+				end_line: Number(r['end_line']),
+				start_byte: r['start_byte'] == null ? null : Number(r['start_byte']),
+				end_byte: r['end_byte'] == null ? null : Number(r['end_byte']),
+			};
+		});
 	}
 
 	async getFile(file_id: string): Promise<Record<string, unknown> | null> {
@@ -695,6 +1915,7 @@ export class SearchEngineV2 {
 		}
 
 		const oversample = Math.min(200, Math.max(k * 6, 30));
+		const qualnameFilter = buildQualnameExactFilter(query);
 		const fuzzySymbolToken = fuzzyPlan
 			? fuzzyPlan.symbolToken.toLowerCase()
 			: '';
@@ -702,6 +1923,7 @@ export class SearchEngineV2 {
 			? fuzzyPlan.qualToken.toLowerCase()
 			: null;
 		const [
+			qualExactHits,
 			nameHits,
 			qualHits,
 			nameFuzzyHits,
@@ -709,6 +1931,15 @@ export class SearchEngineV2 {
 			identHits,
 			vecHits,
 		] = await Promise.all([
+			qualnameFilter
+				? this.exactCandidatesSymbols(
+						table,
+						qualnameFilter,
+						oversample,
+						filterClause,
+						'symbols.qualname_exact',
+					)
+				: Promise.resolve([]),
 			this.ftsCandidatesSymbols(
 				table,
 				query,
@@ -771,6 +2002,7 @@ export class SearchEngineV2 {
 		]);
 
 		const candidates = mergeCandidates([
+			qualExactHits,
 			nameHits,
 			qualHits,
 			nameFuzzyHits,
@@ -1173,6 +2405,7 @@ export class SearchEngineV2 {
 					String(r['signature'] ?? '').trim() ||
 					String(r['code_text'] ?? '').slice(0, 200),
 				is_exported: Boolean(r['is_exported']),
+				is_test: readIsTest(r),
 				channels: [
 					{
 						channel: 'fts',
@@ -1185,6 +2418,57 @@ export class SearchEngineV2 {
 		});
 	}
 
+	private async exactCandidatesSymbols(
+		table: Table,
+		where: string,
+		limit: number,
+		filterClause: string | undefined,
+		source: string,
+	): Promise<Candidate[]> {
+		const clause = filterClause ? `(${where}) AND (${filterClause})` : where;
+		const rows = await table
+			.query()
+			.where(clause)
+			.select([
+				'symbol_id',
+				'file_path',
+				'start_line',
+				'end_line',
+				'symbol_name',
+				'qualname',
+				'signature',
+				'code_text',
+				'is_exported',
+				'is_test',
+			])
+			.limit(limit)
+			.toArray();
+		return rows.map((row, index) => {
+			const r = row as Record<string, unknown>;
+			return {
+				table: 'symbols',
+				id: String(r['symbol_id']),
+				file_path: String(r['file_path']),
+				start_line: Number(r['start_line']),
+				end_line: Number(r['end_line']),
+				title: String(r['qualname'] ?? r['symbol_name'] ?? r['symbol_id']),
+				snippet:
+					String(r['signature'] ?? '').trim() ||
+					String(r['code_text'] ?? '').slice(0, 200),
+				is_exported: Boolean(r['is_exported']),
+				is_test: readIsTest(r),
+				channels: [
+					{
+						channel: 'fts',
+						source,
+						rank: index,
+						rawScore: 1,
+					},
+				],
+			};
+		});
+	}
+
 	private async ftsCandidatesSymbolsFullTextQuery(
 		table: Table,
 		query: lancedb.FullTextQuery,
@@ -1208,6 +2492,7 @@ export class SearchEngineV2 {
 					String(r['signature'] ?? '').trim() ||
 					String(r['code_text'] ?? '').slice(0, 200),
 				is_exported: Boolean(r['is_exported']),
+				is_test: readIsTest(r),
 				channels: [
 					{
 						channel: 'fts',
@@ -1241,6 +2526,7 @@ export class SearchEngineV2 {
 				end_line: Number(r['end_line']),
 				title: `${r['chunk_kind'] ?? 'block'}`,
 				snippet: String(r['code_text'] ?? '').slice(0, 240),
+				is_test: readIsTest(r),
 				channels: [
 					{
 						channel: 'fts',
@@ -1275,6 +2561,7 @@ export class SearchEngineV2 {
 				end_line: 1,
 				title: String(r['file_path']),
 				snippet: summary.slice(0, 240),
+				is_test: readIsTest(r),
 				channels: [
 					{
 						channel: 'fts',
@@ -1300,44 +2587,34 @@ export class SearchEngineV2 {
 		const rows = await q.toArray();
 		return rows.map((row, index) => {
 			const r = row as Record<string, unknown> & {_score?: number};
-			const refKind = String(r['ref_kind'] ?? 'identifier');
-			const tokenTextsRaw = normalizeJsonValue(r['token_texts']);
-			const tokenTexts = Array.isArray(tokenTextsRaw)
-				? tokenTextsRaw.map(v => String(v)).filter(Boolean)
-				: [];
-			const tokenQuery = query.trim().toLowerCase();
-			const tokenText =
-				tokenTexts.find(t => t.toLowerCase() === tokenQuery) ??
-				tokenTexts.find(t => t.toLowerCase().includes(tokenQuery)) ??
-				tokenTexts[0] ??
-				'';
-			return {
-				table: 'refs',
-				id: String(r['ref_id']),
-				file_path: String(r['file_path']),
-				start_line: Number(r['start_line']),
-				end_line: Number(r['end_line']),
-				start_byte: r['start_byte'] != null ? Number(r['start_byte']) : null,
-				end_byte: r['end_byte'] != null ? Number(r['end_byte']) : null,
-				title: `${refKind}: ${tokenText}`,
-				snippet: String(r['context_snippet'] ?? '').slice(0, 240),
-				ref_kind: refKind,
-				token_text: tokenText,
-				module_name: r['module_name'] != null ? String(r['module_name']) : null,
-				imported_name:
-					r['imported_name'] != null ? String(r['imported_name']) : null,
-				channels: [
-					{
-						channel: 'fts',
-						source,
-						rank: index,
-						rawScore: typeof r._score === 'number' ? r._score : 0,
-					},
-				],
-			};
+			return refRowToCandidate(r, query, {
+				channel: 'fts',
+				source,
+				rank: index,
+				rawScore: typeof r._score === 'number' ? r._score : 0,
+			});
 		});
 	}
 
+	private async exactCandidatesRefs(
+		table: Table,
+		where: string,
+		limit: number,
+		filterClause: string | undefined,
+		source: string,
+	): Promise<Candidate[]> {
+		const clause = filterClause ? `(${where}) AND (${filterClause})` : where;
+		const rows = await table.query().where(clause).limit(limit).toArray();
+		return rows.map((row, index) =>
+			refRowToCandidate(row as Record<string, unknown>, '', {
+				channel: 'fts',
+				source,
+				rank: index,
+				rawScore: 1,
+			}),
+		);
+	}
+
 	private async vectorCandidatesSymbols(
 		table: Table,
 		queryVector: number[],
@@ -1363,6 +2640,7 @@ export class SearchEngineV2 {
 					String(r['signature'] ?? '').trim() ||
 					String(r['code_text'] ?? '').slice(0, 200),
 				is_exported: Boolean(r['is_exported']),
+				is_test: readIsTest(r),
 				channels: [{channel: 'vector', source, rank: index, rawScore: sim}],
 			};
 		});
@@ -1390,6 +2668,7 @@ export class SearchEngineV2 {
 				end_line: Number(r['end_line']),
 				title: `${r['chunk_kind'] ?? 'block'}`,
 				snippet: String(r['code_text'] ?? '').slice(0, 240),
+				is_test: readIsTest(r),
 				channels: [{channel: 'vector', source, rank: index, rawScore: sim}],
 			};
 		});
@@ -1418,6 +2697,7 @@ export class SearchEngineV2 {
 				end_line: 1,
 				title: String(r['file_path']),
 				snippet: summary.slice(0, 240),
+				is_test: readIsTest(r),
 				channels: [{channel: 'vector', source, rank: index, rawScore: sim}],
 			};
 		});
@@ -1600,19 +2880,27 @@ export class SearchEngineV2 {
 
 	private async resolveSymbolNameFromId(symbol_id: string): Promise<{
 		symbol_name: string;
+		symbol_kind: string;
+		qualname: string;
+		file_path: string;
 	} | null> {
 		const table = await this.getSymbolsTable();
 		const rows = await table
 			.query()
 			.where(`symbol_id = '${escapeForEquality(symbol_id)}'`)
-			.select(['symbol_name'])
+			.select(['symbol_name', 'symbol_kind', 'qualname', 'file_path'])
 			.limit(1)
 			.toArray();
 		if (rows.length === 0) return null;
 		const row = rows[0] as Record<string, unknown>;
 		const symbol_name = String(row['symbol_name'] ?? '').trim();
 		if (!symbol_name) return null;
-		return {symbol_name};
+		return {
+			symbol_name,
+			symbol_kind: String(row['symbol_kind'] ?? ''),
+			qualname: String(row['qualname'] ?? '').trim(),
+			file_path: String(row['file_path'] ?? ''),
+		};
 	}
 
 	private log(
@@ -1667,6 +2955,7 @@ function routeIntent(query: string): Exclude<V2SearchIntent, 'auto'> {
 		/[A-Za-z_][A-Za-z0-9_]*\s*\(/.test(q) ||
 		/[A-Za-z0-9_]+\.[A-Za-z0-9_]+/.test(q) ||
 		/[A-Za-z0-9_]+::[A-Za-z0-9_]+/.test(q) ||
+		/^<[^<>]+>::[A-Za-z0-9_]+/.test(q) ||
 		/[A-Z][a-z]+[A-Z][A-Za-z0-9]*/.test(q);
 
 	if (symbolish || lower.includes('defined') || lower.includes('definition')) {
@@ -1750,6 +3039,52 @@ function normalizeDefinitionFuzzyQuery(query: string): {
 	return {rawToken, symbolToken, hasQualifier};
 }
 
+/**
+ * Build an exact qualname filter for owner-qualified queries.
+ *
+ * - `<Greeter as Display>::fmt` matches that qualname exactly
+ * - `Greeter::fmt` / `Greeter.fmt` also match trait methods implemented for
+ *   Greeter (`<Greeter as Display>::fmt`)
+ * - `Display::fmt` also matches implementations of the trait method
+ *   (`<Greeter as Display>::fmt`)
+ */
+function buildQualnameExactFilter(query: string): string | null {
+	let q = stripWrappingQuotes(query.trim());
+	q = q.replace(/\(\)\s*$/, '').replace(/[;,]+$/, '');
+	if (!q) return null;
+
+	const traitQualified = q.match(
+		/^<\s*([A-Za-z_][A-Za-z0-9_]*)\s+as\s+([A-Za-z_][A-Za-z0-9_]*)\s*>::([A-Za-z_][A-Za-z0-9_]*)$/,
+	);
+	if (traitQualified) {
+		const [, typeName, traitName, member] = traitQualified;
+		const qualname = `<${typeName} as ${traitName}>::${member}`;
+		return `qualname = '${escapeForEquality(qualname)}'`;
+	}
+
+	const parts = q.split(/::|\./);
+	if (parts.length < 2) return null;
+	if (!parts.every(p => /^[A-Za-z_$][A-Za-z0-9_$]*$/.test(p))) return null;
+
+	const owner = parts[parts.length - 2]!;
+	const member = parts[parts.length - 1]!;
+	const ownerEq = escapeForEquality(owner);
+	const memberEq = escapeForEquality(member);
+	const ownerLike = escapeForLike(owner);
+	const memberLike = escapeForLike(member);
+	return [
+		`qualname = '${escapeForEquality(q)}'`,
+		`qualname = '${ownerEq}::${memberEq}'`,
+		`qualname = '${ownerEq}.${memberEq}'`,
+		// C++ namespaces prefix the owner (`geo::Shape::area`)
+		`qualname LIKE '%::${ownerLike}::${memberLike}'`,
+		// Packages prefix the owner (`acme.users.v1.GetUserRequest.user_id`)
+		`qualname LIKE '%.${ownerLike}.${memberLike}'`,
+		`qualname LIKE '<${ownerLike} as %>::${memberLike}'`,
+		`qualname LIKE '<% as ${ownerLike}>::${memberLike}'`,
+	].join(' OR ');
+}
+
 function stripWrappingQuotes(value: string): string {
 	const v = value.trim();
 	if (v.length >= 2) {
@@ -2064,9 +3399,9 @@ function rerankCandidates(
 		if (options.intent === 'usage' && c.table === 'refs') {
 			const kind = c.ref_kind ?? 'identifier';
 			const kindWeight =
-				kind === 'call'
+				kind === 'call' || kind === 'instantiation'
 					? 1.15
-					: kind === 'import'
+					: kind === 'import' || kind === 'extends' || kind === 'implements'
 						? 1.1
 						: kind === 'string_literal'
 							? 0.75
@@ -2083,17 +3418,19 @@ function rerankCandidates(
 
 		if (options.applyTestDemotion) {
 			const lowerPath = c.file_path.toLowerCase();
+			// Prefer the indexed is_test fact; fall back to path heuristics.
 			const isTestish =
-				lowerPath.includes('__tests__') ||
-				lowerPath.includes('/test/') ||
-				lowerPath.includes('.spec.') ||
-				lowerPath.includes('.test.');
+				c.is_test ??
+				(lowerPath.includes('__tests__') ||
+					lowerPath.includes('/test/') ||
+					lowerPath.includes('.spec.') ||
+					lowerPath.includes('.test.'));
 			if (isTestish) {
 				score *= 0.6;
 				priors.push({
 					name: 'test_path_demotion',
 					value: 0.6,
-					note: 'Soft-demote test-ish paths',
+					note: 'Soft-demote test code',
 				});
 			}
 		}
@@ -2162,6 +3499,7 @@ function channelRrfWeight(
 
 	switch (intent) {
 		case 'definition': {
+			if (s === 'symbols.qualname_exact') return 1.5;
 			if (s === 'symbols.name') return 1.35;
 			if (s === 'symbols.qualname') return 1.25;
 			if (s === 'symbols.name_fuzzy') return 1.2;
@@ -2193,6 +3531,7 @@ function channelRrfWeight(
 			return base;
 		}
 		case 'usage': {
+			if (s === 'refs.target_symbol_id') return 1.5;
 			if (s === 'refs.token_texts_qualified') return 1.15;
 			if (s === 'refs.token_texts') return 1.0;
 			return base;
@@ -2403,6 +3742,39 @@ function buildNextActions(groups: {
 	return actions.slice(0, 5);
 }
 
+/**
+ * `ref_kind IN (...)` for a find_references kind filter (undefined when
+ * unset or empty).
+ */
+function buildRefKindFilter(kinds?: V2RefKind[]): string | undefined {
+	if (!kinds || kinds.length === 0) return undefined;
+	const quoted = kinds.map(kind => `'${escapeForEquality(kind)}'`);
+	return `ref_kind IN (${quoted.join(', ')})`;
+}
+
+/**
+ * Identifier refs with an access: `write` also matches `read_write` refs
+ * (`count += 1` both reads and writes `count`).
+ */
+function buildAccessFilter(access?: V2AccessFilter): string | undefined {
+	switch (access) {
+		case 'write':
+			return `access IN ('write', 'read_write')`;
+		case 'read_write':
+			return `access = 'read_write'`;
+		default:
+			return undefined;
+	}
+}
+
+function andClauses(
+	...clauses: Array<string | undefined>
+): string | undefined {
+	const present = clauses.filter((c): c is string => Boolean(c));
+	if (present.length === 0) return undefined;
+	return present.length === 1 ? present[0] : `(${present.join(') AND (')})`;
+}
+
 function buildScopeFilter(scope: V2SearchScope): string | undefined {
 	const conditions: string[] = [];
 
@@ -2432,14 +3804,231 @@ function buildScopeFilter(scope: V2SearchScope): string | undefined {
 		conditions.push(`extension IN (${exts})`);
 	}
 
+	if (scope.tests === 'exclude') {
+		conditions.push('is_test = false');
+	} else if (scope.tests === 'only') {
+		conditions.push('is_test = true');
+	}
+
+	if (scope.crate && scope.crate.length > 0) {
+		const crates = scope.crate
+			.map(c => `'${escapeForEquality(c)}'`)
+			.join(', ');
+		conditions.push(`crate_name IN (${crates})`);
+	}
+
+	if (conditions.length === 0) return undefined;
+	return conditions.join(' AND ');
+}
+
+/**
+ * Go packages are exactly a directory: files in it share names without
+ * imports.
+ */
+function inSameGoPackage(refPath: string, defPath: string): boolean {
+	if (!refPath.endsWith('.go') || !defPath.endsWith('.go')) return false;
+	return path.posix.dirname(refPath) === path.posix.dirname(defPath);
+}
+
+/**
+ * Scope filter for the symbols table: the shared scope plus symbol-only
+ * facts (visibility, kind).
+ */
+function buildSymbolScopeFilter(scope: V2SearchScope): string | undefined {
+	const conditions: string[] = [];
+	const base = buildScopeFilter(scope);
+	if (base) conditions.push(base);
+
+	if (scope.visibility && scope.visibility.length > 0) {
+		const levels = scope.visibility
+			.map(v => `'${escapeForEquality(v)}'`)
+			.join(', ');
+		conditions.push(`visibility IN (${levels})`);
+	}
+
+	if (scope.kinds && scope.kinds.length > 0) {
+		const kinds = scope.kinds.map(k => `'${escapeForEquality(k)}'`).join(', ');
+		conditions.push(`symbol_kind IN (${kinds})`);
+	}
+
 	if (conditions.length === 0) return undefined;
 	return conditions.join(' AND ');
 }
 
+/**
+ * Call graph view of a symbol row.
+ */
+type CallGraphSymbol = {
+	symbol_id: string;
+	symbol_kind: string;
+	symbol_name: string;
+	qualname: string;
+	file_path: string;
+	language_hint: string | null;
+	start_line: number;
+	end_line: number;
+	start_byte: number | null;
+	end_byte: number | null;
+};
+
+type CallSite = {
+	/** Called base name */
+	name: string;
+	/** `receiver.method` token, when the call has a receiver */
+	qualified: string | null;
+	file_path: string;
+	line: number;
+	start_byte: number | null;
+	/** Definition the call was bound to through an import */
+	target_symbol_id: string | null;
+};
+
+/**
+ * Lookups shared by one call graph request.
+ */
+type CallGraphCache = {
+	fileSymbols: Map<string, CallGraphSymbol[]>;
+	candidates: Map<string, CallGraphSymbol[]>;
+	byId: Map<string, CallGraphSymbol | null>;
+};
+
+function toCallGraphNode(symbol: CallGraphSymbol): V2CallGraphNode {
+	return {
+		symbol_id: symbol.symbol_id,
+		symbol_kind: symbol.symbol_kind,
+		qualname: symbol.qualname,
+		file_path: symbol.file_path,
+		start_line: symbol.start_line,
+		end_line: symbol.end_line,
+	};
+}
+
+/**
+ * Innermost symbol whose span contains a call site (null at module level).
+ */
+function innermostSymbolAt(
+	symbols: CallGraphSymbol[],
+	site: CallSite,
+): CallGraphSymbol | null {
+	let best: CallGraphSymbol | null = null;
+	for (const s of symbols) {
+		const contains =
+			site.start_byte != null && s.start_byte != null && s.end_byte != null
+				? s.start_byte <= site.start_byte && site.start_byte < s.end_byte
+				: s.start_line <= site.line && site.line <= s.end_line;
+		if (!contains) continue;
+		// Spans nest, so the latest-starting container is the innermost.
+		const start = s.start_byte ?? s.start_line;
+		const bestStart = best ? (best.start_byte ?? best.start_line) : -1;
+		if (
+			start > bestStart ||
+			(best && start === bestStart && s.end_line < best.end_line)
+		) {
+			best = s;
+		}
+	}
+	return best;
+}
+
+/**
+ * Languages whose symbols can call each other directly (null for files
+ * without a language hint, such as Makefiles and justfiles).
+ */
+function languageFamily(languageHint: string | null): string | null {
+	switch (languageHint) {
+		case 'typescript':
+		case 'tsx':
+		case 'javascript':
+		case 'vue':
+		case 'svelte':
+		case 'astro':
+			return 'javascript';
+		case 'c':
+		case 'cpp':
+			return 'c';
+		case 'java':
+		case 'kotlin':
+			return 'jvm';
+		default:
+			return languageHint;
+	}
+}
+
+/**
+ * Go package (directory) + type name key used for structural matching.
+ */
+function goTypeKey(filePath: string, typeName: string): string {
+	return `${path.posix.dirname(filePath)}|${typeName}`;
+}
+
+/**
+ * Whether C/C++ definition text has a body (prototypes end with `;`).
+ */
+function hasNativeBody(codeText: string): boolean {
+	return /\}\s*$/.test(codeText);
+}
+
 function escapeForEquality(str: string): string {
 	return str.replace(/'/g, "''");
 }
 
+function readIsTest(r: Record<string, unknown>): boolean | undefined {
+	return r['is_test'] == null ? undefined : Boolean(r['is_test']);
+}
+
+function refRowToCandidate(
+	r: Record<string, unknown>,
+	query: string,
+	channel: V2ExplainChannel,
+): Candidate {
+	const refKind = String(r['ref_kind'] ?? 'identifier');
+	const tokenTextsRaw = normalizeJsonValue(r['token_texts']);
+	const tokenTexts = Array.isArray(tokenTextsRaw)
+		? tokenTextsRaw.map(v => String(v)).filter(Boolean)
+		: [];
+	const tokenQuery = query.trim().toLowerCase();
+	const tokenText =
+		tokenTexts.find(t => t.toLowerCase() === tokenQuery) ??
+		tokenTexts.find(t => t.toLowerCase().includes(tokenQuery)) ??
+		tokenTexts[0] ??
+		'';
+	return {
+		table: 'refs',
+		id: String(r['ref_id']),
+		file_path: String(r['file_path']),
+		start_line: Number(r['start_line']),
+		end_line: Number(r['end_line']),
+		start_byte: r['start_byte'] != null ? Number(r['start_byte']) : null,
+		end_byte: r['end_byte'] != null ? Number(r['end_byte']) : null,
+		title: `${refKind}: ${tokenText}`,
+		snippet: String(r['context_snippet'] ?? '').slice(0, 240),
+		ref_kind: refKind,
+		access: r['access'] != null ? String(r['access']) : null,
+		token_text: tokenText,
+		module_name: r['module_name'] != null ? String(r['module_name']) : null,
+		imported_name:
+			r['imported_name'] != null ? String(r['imported_name']) : null,
+		target_file_path:
+			r['target_file_path'] != null ? String(r['target_file_path']) : null,
+		target_symbol_id:
+			r['target_symbol_id'] != null ? String(r['target_symbol_id']) : null,
+		has_receiver: Boolean(r['has_receiver']),
+		is_test: readIsTest(r),
+		channels: [channel],
+	};
+}
+
+function groupUsagesByFile(
+	byFile: Map<string, V2UsageRef[]>,
+): Array<{file_path: string; refs: V2UsageRef[]}> {
+	return [...byFile.entries()]
+		.sort(([a], [b]) => a.localeCompare(b))
+		.map(([file_path, refs]) => ({
+			file_path,
+			refs: refs.sort((a, b) => a.start_line - b.start_line),
+		}));
+}
+
 function escapeForLike(str: string): string {
 	return str.replace(/'/g, "''").replace(/%/g, '\\%').replace(/_/g, '\\_');
 }
@@ -4,7 +4,12 @@
  * Search is intent-routed and returns grouped, agent-centric results.
  */
 
-import type {V2RefKind} from '../storage/types.js';
+import type {
+	V2RefAccess,
+	V2RefKind,
+	V2SymbolKind,
+	V2Visibility,
+} from '../storage/types.js';
 
 export type V2SearchIntent =
 	| 'auto'
@@ -24,6 +29,14 @@ export type V2SearchScope = {
 	path_contains?: string[];
 	path_not_contains?: string[];
 	extension?: string[];
+	/** Workspace member names (Cargo crate / npm package / Go module) */
+	crate?: string[];
+	/** Test code handling: include (default), exclude, or only */
+	tests?: 'include' | 'exclude' | 'only';
+	/** Symbol visibility levels (definitions only) */
+	visibility?: V2Visibility[];
+	/** Symbol kinds (definitions only) */
+	kinds?: V2SymbolKind[];
 };
 
 export type V2ExplainChannel = {
@@ -81,10 +94,21 @@ export type V2SearchOptions = {
 	explain?: boolean;
 };
 
+/**
+ * find_usages access filter; `write` includes `read_write` (`+=`). There
+ * is no `read` filter: plain reads of lowercase names (fields, locals) are
+ * not indexed, so it would miss most reads.
+ */
+export type V2AccessFilter = Exclude<V2RefAccess, 'read'>;
+
 export type V2FindUsagesOptions = {
 	symbol_id?: string;
 	symbol_name?: string;
 	scope?: V2SearchScope;
+	/** Only refs of these kinds (e.g. `instantiation`, `extends`) */
+	ref_kinds?: V2RefKind[];
+	/** Only identifier refs with this access (see V2AccessFilter) */
+	access?: V2AccessFilter;
 	k?: number;
 };
 
@@ -94,19 +118,134 @@ export type V2UsageRef = {
 	start_line: number;
 	end_line: number;
 	ref_kind: V2RefKind;
+	/** Identifier refs: read, write or read_write (null for other kinds) */
+	access: V2RefAccess | null;
 	token_text: string;
 	context_snippet: string;
 	score: number;
 	why?: V2Explain;
 	module_name: string | null;
 	imported_name: string | null;
+	/** Resolved target (set when the ref's import binds a known symbol) */
+	target_symbol_id: string | null;
 };
 
 export type V2FindUsagesResponse = {
 	query: {symbol_id?: string; symbol_name?: string};
 	resolved: {symbol_id?: string; symbol_name: string};
 	filters_applied: V2SearchScope;
+	/** Refs to the symbol (by id: bound to it, or unbound in its file) */
 	by_file: Array<{file_path: string; refs: V2UsageRef[]}>;
 	total_refs: number;
+	/** Refs that only share the name (empty for symbol_name queries) */
+	same_name_by_file: Array<{file_path: string; refs: V2UsageRef[]}>;
+	total_same_name_refs: number;
+	suggested_next_actions: V2NextAction[];
+};
+
+export type V2FindImplementationsOptions = {
+	symbol_id?: string;
+	symbol_name?: string;
+	scope?: V2SearchScope;
+	k?: number;
+};
+
+/**
+ * How a type implements the queried trait/interface:
+ * - impl: Rust `impl Trait for Type` block
+ * - derive: Rust `#[derive(Trait)]`
+ * - supertype: declared `extends`/`implements`, base class, protocol
+ *   conformance or supertrait
+ * - structural: Go type whose methods cover the interface's method set
+ */
+export type V2ImplementationVia =
+	| 'impl'
+	| 'derive'
+	| 'supertype'
+	| 'structural';
+
+export type V2Implementation = {
+	/** Impl block (via `impl`) or implementing type definition */
+	symbol_id: string;
+	symbol_kind: string;
+	qualname: string;
+	file_path: string;
+	start_line: number;
+	end_line: number;
+	/** Implementing type name */
+	type_name: string;
+	via: V2ImplementationVia;
+	/** Interface methods found on the type (structural matches only) */
+	matched_methods?: string[];
+};
+
+export type V2FindImplementationsResponse = {
+	query: {symbol_id?: string; symbol_name?: string};
+	resolved: {symbol_id?: string; symbol_name: string};
+	filters_applied: V2SearchScope;
+	implementations: V2Implementation[];
+	total: number;
+	suggested_next_actions: V2NextAction[];
+};
+
+export type V2CallGraphDirection = 'callers' | 'callees' | 'both';
+
+export type V2CallGraphOptions = {
+	symbol_id: string;
+	direction?: V2CallGraphDirection;
+	/** Hops to follow from the symbol (1 = direct callers/callees) */
+	depth?: number;
+	/** Call-site filters */
+	scope?: V2SearchScope;
+	/** Max edges per direction */
+	k?: number;
+};
+
+/**
+ * How a call edge was bound:
+ * - definition: to one definition (the symbol an import in the calling file
+ *   resolves to, a definition in the calling file, or the only definition
+ *   with the called name)
+ * - name: by name only (no definition found, or several share the name).
+ *   Name-only edges are not followed further.
+ */
+export type V2CallResolution = 'definition' | 'name';
+
+export type V2CallGraphNode = {
+	symbol_id: string;
+	symbol_kind: string;
+	qualname: string;
+	file_path: string;
+	start_line: number;
+	end_line: number;
+};
+
+export type V2CallEdge = {
+	/** Calling symbol (null for module-level code) */
+	caller_id: string | null;
+	/** Called definition (null for name-only callees) */
+	callee_id: string | null;
+	/** Called name as written at the call site */
+	callee_name: string;
+	resolution: V2CallResolution;
+	/** First call site of this caller/callee pair */
+	file_path: string;
+	line: number;
+	/** Hops from the queried symbol (1 = direct) */
+	depth: number;
+};
+
+export type V2CallGraphResponse = {
+	query: {symbol_id: string};
+	root: V2CallGraphNode;
+	direction: V2CallGraphDirection;
+	depth: number;
+	filters_applied: V2SearchScope;
+	/** Every symbol an edge refers to, including the root */
+	nodes: V2CallGraphNode[];
+	callers: V2CallEdge[];
+	callees: V2CallEdge[];
+	/** Set when the edge limit or the call-site cap cut the graph short */
+	truncated: boolean;
 	suggested_next_actions: V2NextAction[];
 };
@@ -15,7 +15,7 @@ import {
 	createV2RefsSchema,
 	createV2SymbolsSchema,
 } from './schema.js';
-import type {V2EmbeddingCacheRow} from './types.js';
+import type {V2CrateCounts, V2EmbeddingCacheRow} from './types.js';
 
 export const V2_TABLE_NAMES = {
 	SYMBOLS: 'v2_symbols',
@@ -304,6 +304,306 @@ export class StorageV2 {
 			.execute(rows);
 	}
 
+	/**
+	 * Row counts per crate (workspace member) across the entity tables.
+	 */
+	async countRowsByCrate(
+		crateNames: string[],
+	): Promise<Record<string, V2CrateCounts>> {
+		const out: Record<string, V2CrateCounts> = {};
+		for (const name of new Set(crateNames)) {
+			const filter = `crate_name = '${escapeString(name)}'`;
+			out[name] = {
+				totalFiles: await this.getFilesTable().countRows(filter),
+				totalSymbols: await this.getSymbolsTable().countRows(filter),
+				totalChunks: await this.getChunksTable().countRows(filter),
+				totalRefs: await this.getRefsTable().countRows(filter),
+			};
+		}
+		return out;
+	}
+
+	// ============================================================
+	// Import target links
+	// ============================================================
+
+	/**
+	 * Top-level symbols (no parent, not methods) defined in the given files.
+	 */
+	async getTopLevelSymbolsForFiles(filePaths: string[]): Promise<
+		Array<{symbol_id: string; file_path: string; symbol_name: string}>
+	> {
+		if (filePaths.length === 0) return [];
+		const escaped = filePaths.map(p => `'${escapeString(p)}'`).join(', ');
+		const rows = await this.getSymbolsTable()
+			.query()
+			.where(
+				`file_path IN (${escaped}) AND parent_symbol_id IS NULL AND symbol_kind <> 'method'`,
+			)
+			.select(['symbol_id', 'file_path', 'symbol_name'])
+			.toArray();
+		return rows.map(row => ({
+			symbol_id: String(row.symbol_id),
+			file_path: String(row.file_path),
+			symbol_name: String(row.symbol_name),
+		}));
+	}
+
+	/**
+	 * Top-level symbols with one of the given names, in files with the
+	 * given extension.
+	 */
+	async getTopLevelSymbolsByName(
+		names: string[],
+		extension: string,
+	): Promise<
+		Array<{symbol_id: string; file_path: string; symbol_name: string}>
+	> {
+		if (names.length === 0) return [];
+		const escaped = names.map(n => `'${escapeString(n)}'`).join(', ');
+		const rows = await this.getSymbolsTable()
+			.query()
+			.where(
+				`symbol_name IN (${escaped}) AND extension = '${escapeString(extension)}' AND parent_symbol_id IS NULL AND symbol_kind <> 'method'`,
+			)
+			.select(['symbol_id', 'file_path', 'symbol_name'])
+			.toArray();
+		return rows.map(row => ({
+			symbol_id: String(row.symbol_id),
+			file_path: String(row.file_path),
+			symbol_name: String(row.symbol_name),
+		}));
+	}
+
+	/**
+	 * Symbols declared directly inside the given parent symbols.
+	 */
+	async getMemberSymbols(parentIds: string[]): Promise<
+		Array<{symbol_id: string; parent_symbol_id: string; symbol_name: string}>
+	> {
+		if (parentIds.length === 0) return [];
+		const escaped = parentIds.map(id => `'${escapeString(id)}'`).join(', ');
+		const rows = await this.getSymbolsTable()
+			.query()
+			.where(`parent_symbol_id IN (${escaped})`)
+			.select(['symbol_id', 'parent_symbol_id', 'symbol_name'])
+			.toArray();
+		return rows.map(row => ({
+			symbol_id: String(row.symbol_id),
+			parent_symbol_id: String(row.parent_symbol_id),
+			symbol_name: String(row.symbol_name),
+		}));
+	}
+
+	/**
+	 * Import refs matching a filter over `file_path` or `target_file_path`.
+	 */
+	async getImportRefs(
+		column: 'file_path' | 'target_file_path',
+		filePaths: string[],
+	): Promise<
+		Array<{
+			ref_id: string;
+			file_path: string;
+			token_texts: string[];
+			imported_name: string | null;
+			target_file_path: string | null;
+		}>
+	> {
+		if (filePaths.length === 0) return [];
+		const escaped = filePaths.map(p => `'${escapeString(p)}'`).join(', ');
+		const rows = await this.getRefsTable()
+			.query()
+			.where(`ref_kind = 'import' AND ${column} IN (${escaped})`)
+			.select([
+				'ref_id',
+				'file_path',
+				'token_texts',
+				'imported_name',
+				'target_file_path',
+			])
+			.toArray();
+		return rows.map(row => ({
+			ref_id: String(row.ref_id),
+			file_path: String(row.file_path),
+			token_texts: toStringList(row.token_texts),
+			imported_name:
+				row.imported_name != null ? String(row.imported_name) : null,
+			target_file_path:
+				row.target_file_path != null ? String(row.target_file_path) : null,
+		}));
+	}
+
+	/**
+	 * Refs of any kind (imports and the calls/identifiers bound through
+	 * them) whose target is one of the given files.
+	 */
+	async getRefsTargetingFiles(filePaths: string[]): Promise<
+		Array<{
+			ref_id: string;
+			file_path: string;
+			imported_name: string | null;
+			target_file_path: string | null;
+		}>
+	> {
+		if (filePaths.length === 0) return [];
+		const escaped = filePaths.map(p => `'${escapeString(p)}'`).join(', ');
+		const rows = await this.getRefsTable()
+			.query()
+			.where(`target_file_path IN (${escaped})`)
+			.select(['ref_id', 'file_path', 'imported_name', 'target_file_path'])
+			.toArray();
+		return rows.map(row => ({
+			ref_id: String(row.ref_id),
+			file_path: String(row.file_path),
+			imported_name:
+				row.imported_name != null ? String(row.imported_name) : null,
+			target_file_path:
+				row.target_file_path != null ? String(row.target_file_path) : null,
+		}));
+	}
+
+	/**
+	 * Point the given refs at a (new) target symbol, or clear the link.
+	 */
+	async setRefTargets(
+		refIds: string[],
+		targetSymbolId: string | null,
+	): Promise<void> {
+		if (refIds.length === 0) return;
+		const escaped = refIds.map(id => `'${escapeString(id)}'`).join(', ');
+		await this.getRefsTable().update({
+			where: `ref_id IN (${escaped})`,
+			valuesSql: {
+				target_symbol_id:
+					targetSymbolId != null
+						? `'${escapeString(targetSymbolId)}'`
+						: 'NULL',
+			},
+		});
+	}
+
+	/**
+	 * Rust impl blocks whose type or trait is one of the given names.
+	 */
+	async getImplSymbolsByName(names: string[]): Promise<
+		Array<{
+			symbol_id: string;
+			file_path: string;
+			impl_type_name: string | null;
+			impl_trait_name: string | null;
+		}>
+	> {
+		if (names.length === 0) return [];
+		const escaped = names.map(n => `'${escapeString(n)}'`).join(', ');
+		const rows = await this.getSymbolsTable()
+			.query()
+			.where(
+				`impl_type_name IN (${escaped}) OR impl_trait_name IN (${escaped})`,
+			)
+			.select(['symbol_id', 'file_path', 'impl_type_name', 'impl_trait_name'])
+			.toArray();
+		const optional = (value: unknown) =>
+			value != null ? String(value) : null;
+		return rows.map(row => ({
+			symbol_id: String(row.symbol_id),
+			file_path: String(row.file_path),
+			impl_type_name: optional(row.impl_type_name),
+			impl_trait_name: optional(row.impl_trait_name),
+		}));
+	}
+
+	/**
+	 * Point impl blocks and their methods at (new) type/trait symbols.
+	 */
+	async setImplTargets(
+		implSymbolIds: string[],
+		typeSymbolId: string | null,
+		traitSymbolId: string | null,
+	): Promise<void> {
+		if (implSymbolIds.length === 0) return;
+		const escaped = implSymbolIds
+			.map(id => `'${escapeString(id)}'`)
+			.join(', ');
+		const sqlValue = (value: string | null) =>
+			value != null ? `'${escapeString(value)}'` : 'NULL';
+		await this.getSymbolsTable().update({
+			where: `symbol_id IN (${escaped}) OR parent_symbol_id IN (${escaped})`,
+			valuesSql: {
+				impl_type_symbol_id: sqlValue(typeSymbolId),
+				impl_trait_symbol_id: sqlValue(traitSymbolId),
+			},
+		});
+	}
+
+	/**
+	 * C/C++ classes and structs matching a filter over `file_path` or
+	 * `qualname`.
+	 */
+	async getNativeTypes(
+		column: 'file_path' | 'qualname',
+		values: string[],
+	): Promise<Array<{symbol_id: string; file_path: string; qualname: string}>> {
+		if (values.length === 0) return [];
+		const escaped = values.map(v => `'${escapeString(v)}'`).join(', ');
+		const rows = await this.getSymbolsTable()
+			.query()
+			.where(
+				`${column} IN (${escaped}) AND language_hint IN ('c', 'cpp') AND symbol_kind IN ('class', 'struct')`,
+			)
+			.select(['symbol_id', 'file_path', 'qualname'])
+			.toArray();
+		return rows.map(row => ({
+			symbol_id: String(row.symbol_id),
+			file_path: String(row.file_path),
+			qualname: String(row.qualname),
+		}));
+	}
+
+	/**
+	 * C/C++ methods qualified by one of the given owners (`Owner::name`).
+	 */
+	async getNativeMethodsByOwner(
+		owners: string[],
+	): Promise<Array<{symbol_id: string; file_path: string; qualname: string}>> {
+		if (owners.length === 0) return [];
+		const prefixes = owners
+			.map(o => `qualname LIKE '${escapeString(o)}::%'`)
+			.join(' OR ');
+		const rows = await this.getSymbolsTable()
+			.query()
+			.where(
+				`language_hint IN ('c', 'cpp') AND symbol_kind = 'method' AND (${prefixes})`,
+			)
+			.select(['symbol_id', 'file_path', 'qualname'])
+			.toArray();
+		return rows.map(row => ({
+			symbol_id: String(row.symbol_id),
+			file_path: String(row.file_path),
+			qualname: String(row.qualname),
+		}));
+	}
+
+	/**
+	 * Point the given symbols at a (new) parent, or clear the link.
+	 */
+	async setParentSymbol(
+		symbolIds: string[],
+		parentSymbolId: string | null,
+	): Promise<void> {
+		if (symbolIds.length === 0) return;
+		const escaped = symbolIds.map(id => `'${escapeString(id)}'`).join(', ');
+		await this.getSymbolsTable().update({
+			where: `symbol_id IN (${escaped})`,
+			valuesSql: {
+				parent_symbol_id:
+					parentSymbolId != null
+						? `'${escapeString(parentSymbolId)}'`
+						: 'NULL',
+			},
+		});
+	}
+
 	/**
 	 * Add rows using Arrow conversion (useful after a full reset).
 	 */
@@ -395,3 +695,15 @@ function normalizeVector(value: unknown): number[] | null {
 	}
 	return null;
 }
+
+function toStringList(value: unknown): string[] {
+	if (value == null) return [];
+	if (Array.isArray(value)) return value.map(v => String(v));
+	if (typeof value === 'object') {
+		const v = value as {toArray?: () => unknown};
+		if (typeof v.toArray === 'function') {
+			return toStringList(v.toArray());
+		}
+	}
+	return [];
+}
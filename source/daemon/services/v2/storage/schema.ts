@@ -27,6 +27,7 @@ export function createV2SymbolsSchema(dimensions: number): Schema {
 		new Field('revision', new Utf8(), false),
 		new Field('file_path', new Utf8(), false),
 		new Field('extension', new Utf8(), false),
+		new Field('crate_name', new Utf8(), true),
 		new Field('language_hint', new Utf8(), true),
 		new Field('start_line', new Int32(), false),
 		new Field('end_line', new Int32(), false),
@@ -41,11 +42,23 @@ export function createV2SymbolsSchema(dimensions: number): Schema {
 		new Field('signature', new Utf8(), true),
 		new Field('docstring', new Utf8(), true),
 		new Field('is_exported', new Bool(), false),
+		new Field('visibility', new Utf8(), false),
 		new Field(
 			'decorator_names',
 			new List(new Field('item', new Utf8(), false)),
 			false,
 		),
+		new Field('impl_type_name', new Utf8(), true),
+		new Field('impl_trait_name', new Utf8(), true),
+		new Field('impl_type_symbol_id', new Utf8(), true),
+		new Field('impl_trait_symbol_id', new Utf8(), true),
+		new Field(
+			'supertypes',
+			new List(new Field('item', new Utf8(), false)),
+			false,
+		),
+		new Field('derives', new List(new Field('item', new Utf8(), false)), false),
+		new Field('is_test', new Bool(), false),
 
 		// Search surfaces
 		new Field('symbol_name_fuzzy', new Utf8(), false),
@@ -101,6 +114,7 @@ export function createV2ChunksSchema(dimensions: number): Schema {
 		new Field('revision', new Utf8(), false),
 		new Field('file_path', new Utf8(), false),
 		new Field('extension', new Utf8(), false),
+		new Field('crate_name', new Utf8(), true),
 		new Field('start_line', new Int32(), false),
 		new Field('end_line', new Int32(), false),
 		new Field('start_byte', new Int32(), true),
@@ -108,6 +122,7 @@ export function createV2ChunksSchema(dimensions: number): Schema {
 
 		new Field('owner_symbol_id', new Utf8(), true),
 		new Field('chunk_kind', new Utf8(), false),
+		new Field('is_test', new Bool(), false),
 
 		// Surfaces
 		new Field('context_header', new Utf8(), false),
@@ -160,7 +175,9 @@ export function createV2FilesSchema(dimensions: number): Schema {
 		new Field('revision', new Utf8(), false),
 		new Field('file_path', new Utf8(), false),
 		new Field('extension', new Utf8(), false),
+		new Field('crate_name', new Utf8(), true),
 		new Field('file_hash', new Utf8(), false),
+		new Field('is_test', new Bool(), false),
 
 		new Field('imports', new List(new Field('item', new Utf8(), false)), false),
 		new Field('exports', new List(new Field('item', new Utf8(), false)), false),
@@ -205,18 +222,24 @@ export function createV2RefsSchema(): Schema {
 		new Field('revision', new Utf8(), false),
 		new Field('file_path', new Utf8(), false),
 		new Field('extension', new Utf8(), false),
+		new Field('crate_name', new Utf8(), true),
 		new Field('start_line', new Int32(), false),
 		new Field('end_line', new Int32(), false),
 		new Field('start_byte', new Int32(), true),
 		new Field('end_byte', new Int32(), true),
 		new Field('ref_kind', new Utf8(), false),
+		new Field('access', new Utf8(), true),
 		new Field(
 			'token_texts',
 			new List(new Field('item', new Utf8(), false)),
 			false,
 		),
 		new Field('context_snippet', new Utf8(), false),
+		new Field('is_test', new Bool(), false),
 		new Field('module_name', new Utf8(), true),
 		new Field('imported_name', new Utf8(), true),
+		new Field('target_file_path', new Utf8(), true),
+		new Field('target_symbol_id', new Utf8(), true),
+		new Field('has_receiver', new Bool(), false),
 	]);
 }
@@ -5,15 +5,61 @@
  * Column names are snake_case to match Arrow/LanceDB conventions.
  */
 
-export type V2SymbolKind = 'function' | 'class' | 'method' | 'module';
+import type {Visibility} from '../../../lib/chunker/types.js';
+
+export type V2SymbolKind =
+	| 'function'
+	| 'class'
+	| 'interface'
+	| 'struct'
+	| 'trait'
+	| 'method'
+	| 'macro'
+	| 'constant'
+	| 'variable'
+	| 'type_alias'
+	| 'variant'
+	| 'field'
+	| 'module'
+	| 'message'
+	| 'service'
+	| 'rpc'
+	| 'enum'
+	| 'table'
+	| 'column'
+	| 'endpoint'
+	| 'operation'
+	| 'resource'
+	| 'module_call'
+	| 'stage'
+	| 'key'
+	| 'target';
+
+/**
+ * Declared visibility of a symbol (the chunker's `Visibility`).
+ */
+export type V2Visibility = Visibility;
 
 export type V2ChunkKind =
 	| 'statement_group'
 	| 'block'
 	| 'markdown_section'
+	| 'doc_test'
 	| 'unknown';
 
-export type V2RefKind = 'import' | 'call' | 'identifier' | 'string_literal';
+export type V2RefKind =
+	| 'import'
+	| 'call'
+	| 'instantiation'
+	| 'identifier'
+	| 'type_ref'
+	| 'extends'
+	| 'implements'
+	| 'string_literal'
+	| 'doc_link';
+
+/** How an identifier ref uses its name (null for other ref kinds). */
+export type V2RefAccess = 'read' | 'write' | 'read_write';
 
 export type V2EmbeddingCacheRow = {
 	input_hash: string;
@@ -21,12 +67,24 @@ export type V2EmbeddingCacheRow = {
 	created_at: string;
 };
 
+/**
+ * Row counts for one workspace member (crate/package/module).
+ */
+export type V2CrateCounts = {
+	totalFiles: number;
+	totalSymbols: number;
+	totalChunks: number;
+	totalRefs: number;
+};
+
 export type V2SymbolRow = {
 	symbol_id: string;
 	repo_id: string;
 	revision: string;
 	file_path: string;
 	extension: string;
+	/** Owning workspace member (Cargo crate / npm package / Go module) */
+	crate_name: string | null;
 	language_hint: string | null;
 	start_line: number;
 	end_line: number;
@@ -42,7 +100,28 @@ export type V2SymbolRow = {
 	signature: string | null;
 	docstring: string | null;
 	is_exported: boolean;
+	/** Declared visibility, normalized across languages */
+	visibility: V2Visibility;
 	decorator_names: string[];
+	/** Rust impl blocks: implementing type (e.g. `Greeter`) */
+	impl_type_name: string | null;
+	/** Rust trait impl blocks: implemented trait (e.g. `Display`) */
+	impl_trait_name: string | null;
+	/**
+	 * Rust impl blocks and their methods: the resolved symbol of the
+	 * implementing type and trait (null when defined outside the repo)
+	 */
+	impl_type_symbol_id: string | null;
+	impl_trait_symbol_id: string | null;
+	/**
+	 * Declared supertypes (`extends`/`implements`, base classes, protocol
+	 * conformances, Rust supertraits), reduced to base names
+	 */
+	supertypes: string[];
+	/** Rust `#[derive(...)]` traits */
+	derives: string[];
+	/** Test code (test file, test function, or inside a test module/block) */
+	is_test: boolean;
 
 	context_header: string;
 	code_text: string;
@@ -66,6 +145,7 @@ export type V2ChunkRow = {
 	revision: string;
 	file_path: string;
 	extension: string;
+	crate_name: string | null;
 	start_line: number;
 	end_line: number;
 	start_byte: number | null;
@@ -73,6 +153,7 @@ export type V2ChunkRow = {
 
 	owner_symbol_id: string | null;
 	chunk_kind: V2ChunkKind | string;
+	is_test: boolean;
 
 	context_header: string;
 	code_text: string;
@@ -96,7 +177,9 @@ export type V2FileRow = {
 	revision: string;
 	file_path: string;
 	extension: string;
+	crate_name: string | null;
 	file_hash: string;
+	is_test: boolean;
 
 	imports: string[];
 	exports: string[];
@@ -112,13 +195,24 @@ export type V2RefRow = {
 	revision: string;
 	file_path: string;
 	extension: string;
+	crate_name: string | null;
 	start_line: number;
 	end_line: number;
 	start_byte: number | null;
 	end_byte: number | null;
 	ref_kind: V2RefKind | string;
+	/** Identifier refs: read, write (assignment target) or read_write */
+	access: V2RefAccess | string | null;
 	token_texts: string[];
 	context_snippet: string;
+	is_test: boolean;
 	module_name: string | null;
+	/** Calls/identifiers bound through an import: name in the target file */
 	imported_name: string | null;
+	/** File that defines the import target (null if unresolved/external) */
+	target_file_path: string | null;
+	/** Resolved target symbol (null if unresolved or a module import) */
+	target_symbol_id: string | null;
+	/** Call/member access through a receiver (`a.b()`) */
+	has_receiver: boolean;
 };
@@ -0,0 +1,347 @@
+/**
+ * V2 workspace detection - map files to the package (crate) that owns them.
+ *
+ * Supported manifests:
+ * - Cargo: root `Cargo.toml` `[workspace] members/exclude` (+ root `[package]`)
+ * - npm/yarn: root `package.json` `workspaces`
+ * - pnpm: root `pnpm-workspace.yaml` `packages`
+ * - Go: root `go.work` `use` directives
+ *
+ * Member names come from each member manifest (`[package] name`,
+ * package.json `name`, go.mod `module`). Files are tagged with the innermost
+ * member directory that contains them.
+ */
+
+import fs from 'node:fs/promises';
+import path from 'node:path';
+import fg from 'fast-glob';
+
+export type WorkspacePackageKind = 'cargo' | 'npm' | 'go';
+
+export type WorkspacePackage = {
+	/** Package/crate/module name from the member manifest */
+	name: string;
+	/** Project-relative member directory ('' for the project root) */
+	path: string;
+	kind: WorkspacePackageKind;
+};
+
+/**
+ * Discover workspace members declared in the project root.
+ * Sorted by path (deepest first) so prefix matching picks the innermost member.
+ */
+export async function loadWorkspacePackages(
+	projectRoot: string,
+): Promise<WorkspacePackage[]> {
+	const packages = [
+		...(await loadCargoPackages(projectRoot)),
+		...(await loadNpmPackages(projectRoot)),
+		...(await loadGoPackages(projectRoot)),
+	];
+
+	const byPath = new Map<string, WorkspacePackage>();
+	for (const pkg of packages) {
+		if (!byPath.has(pkg.path)) byPath.set(pkg.path, pkg);
+	}
+	return [...byPath.values()].sort(
+		(a, b) => b.path.length - a.path.length || a.path.localeCompare(b.path),
+	);
+}
+
+/**
+ * Name of the workspace member containing `filePath`, or null.
+ */
+export function crateForFile(
+	packages: WorkspacePackage[],
+	filePath: string,
+): string | null {
+	for (const pkg of packages) {
+		if (pkg.path === '' || filePath.startsWith(`${pkg.path}/`)) {
+			return pkg.name;
+		}
+	}
+	return null;
+}
+
+export function sameWorkspacePackages(
+	a: WorkspacePackage[],
+	b: WorkspacePackage[],
+): boolean {
+	if (a.length !== b.length) return false;
+	return a.every(
+		(pkg, i) =>
+			pkg.name === b[i]!.name &&
+			pkg.path === b[i]!.path &&
+			pkg.kind === b[i]!.kind,
+	);
+}
+
+// ============================================================
+// Cargo
+// ============================================================
+
+async function loadCargoPackages(
+	projectRoot: string,
+): Promise<WorkspacePackage[]> {
+	const root = await readText(path.join(projectRoot, 'Cargo.toml'));
+	if (root === null) return [];
+
+	const packages: WorkspacePackage[] = [];
+	const rootPackage = readTomlTable(root, 'package');
+	const rootName = parseTomlString(rootPackage.get('name'));
+	if (rootName) {
+		packages.push({name: rootName, path: '', kind: 'cargo'});
+	}
+
+	const workspace = readTomlTable(root, 'workspace');
+	const memberDirs = await expandMemberGlobs(
+		projectRoot,
+		parseTomlStringArray(workspace.get('members')),
+		parseTomlStringArray(workspace.get('exclude')),
+	);
+	for (const dir of memberDirs) {
+		const manifest = await readText(
+			path.join(projectRoot, dir, 'Cargo.toml'),
+		);
+		if (manifest === null) continue;
+		const name = parseTomlString(
+			readTomlTable(manifest, 'package').get('name'),
+		);
+		if (name) packages.push({name, path: dir, kind: 'cargo'});
+	}
+	return packages;
+}
+
+/**
+ * Minimal TOML reader: `key = value` pairs of a single `[table]`, with
+ * multi-line arrays joined. Enough for Cargo manifest names and members.
+ */
+function readTomlTable(content: string, table: string): Map<string, string> {
+	const out = new Map<string, string>();
+	let current: string | null = null;
+	let pendingKey: string | null = null;
+	let pendingValue = '';
+
+	for (const rawLine of content.split(/\r?\n/)) {
+		const line = stripTomlComment(rawLine).trim();
+		if (pendingKey) {
+			pendingValue += ` ${line}`;
+			if (bracketDepth(pendingValue) <= 0) {
+				out.set(pendingKey, pendingValue.trim());
+				pendingKey = null;
+			}
+			continue;
+		}
+		if (!line) continue;
+
+		const header = line.match(/^\[\[?\s*([^[\]]+?)\s*\]\]?$/);
+		if (header) {
+			current = line.startsWith('[[') ? `[${header[1]}]` : header[1]!;
+			continue;
+		}
+		if (current !== table) continue;
+
+		const kv = line.match(/^([A-Za-z0-9_.-]+)\s*=\s*(.*)$/);
+		if (!kv) continue;
+		const value = kv[2]!;
+		if (bracketDepth(value) > 0) {
+			pendingKey = kv[1]!;
+			pendingValue = value;
+			continue;
+		}
+		out.set(kv[1]!, value.trim());
+	}
+	return out;
+}
+
+function stripTomlComment(line: string): string {
+	let quote: string | null = null;
+	for (let i = 0; i < line.length; i++) {
+		const ch = line[i];
+		if (quote) {
+			if (ch === quote) quote = null;
+		} else if (ch === '"' || ch === "'") {
+			quote = ch;
+		} else if (ch === '#') {
+			return line.slice(0, i);
+		}
+	}
+	return line;
+}
+
+function bracketDepth(value: string): number {
+	let depth = 0;
+	for (const ch of value) {
+		if (ch === '[') depth += 1;
+		else if (ch === ']') depth -= 1;
+	}
+	return depth;
+}
+
+function parseTomlString(value: string | undefined): string | null {
+	if (!value) return null;
+	const match = value.match(/^"([^"]*)"|^'([^']*)'/);
+	return match ? (match[1] ?? match[2] ?? null) : null;
+}
+
+function parseTomlStringArray(value: string | undefined): string[] {
+	if (!value) return [];
+	const out: string[] = [];
+	for (const match of value.matchAll(/"([^"]*)"|'([^']*)'/g)) {
+		const item = match[1] ?? match[2];
+		if (item) out.push(item);
+	}
+	return out;
+}
+
+// ============================================================
+// npm / yarn / pnpm (package.json workspaces, pnpm-workspace.yaml)
+// ============================================================
+
+async function loadNpmPackages(
+	projectRoot: string,
+): Promise<WorkspacePackage[]> {
+	const root = await readJson(path.join(projectRoot, 'package.json'));
+	const workspaces = root?.['workspaces'];
+	const patterns = Array.isArray(workspaces)
+		? workspaces
+		: Array.isArray((workspaces as {packages?: unknown})?.packages)
+			? (workspaces as {packages: unknown[]}).packages
+			: [];
+	const pnpm = await readText(path.join(projectRoot, 'pnpm-workspace.yaml'));
+	const globs = [
+		...patterns.filter((p): p is string => typeof p === 'string'),
+		...(pnpm !== null ? parsePnpmPackages(pnpm) : []),
+	];
+	if (globs.length === 0) return [];
+
+	const memberDirs = await expandMemberGlobs(
+		projectRoot,
+		globs.filter(p => !p.startsWith('!')),
+		globs.filter(p => p.startsWith('!')).map(p => p.slice(1)),
+	);
+
+	const packages: WorkspacePackage[] = [];
+	for (const dir of memberDirs) {
+		const manifest = await readJson(
+			path.join(projectRoot, dir, 'package.json'),
+		);
+		const name = manifest?.['name'];
+		if (typeof name === 'string' && name.trim()) {
+			packages.push({name: name.trim(), path: dir, kind: 'npm'});
+		}
+	}
+	return packages;
+}
+
+/**
+ * Minimal YAML reader for the top-level `packages:` list of
+ * pnpm-workspace.yaml (block `- item` or flow `[a, b]` style).
+ */
+function parsePnpmPackages(content: string): string[] {
+	const out: string[] = [];
+	let inPackages = false;
+	for (const rawLine of content.split(/\r?\n/)) {
+		const line = rawLine.replace(/\s+#.*$|^#.*$/, '');
+		if (!line.trim()) continue;
+		const key = line.match(/^([A-Za-z0-9_-]+)\s*:\s*(.*)$/);
+		if (key) {
+			inPackages = key[1] === 'packages';
+			const flow = key[2]!.match(/^\[(.*)\]$/);
+			if (inPackages && flow) {
+				out.push(...flow[1]!.split(',').map(unquoteYaml).filter(Boolean));
+				inPackages = false;
+			}
+			continue;
+		}
+		const item = line.match(/^\s*-\s*(.+)$/);
+		if (inPackages && item) out.push(unquoteYaml(item[1]!));
+	}
+	return out;
+}
+
+function unquoteYaml(value: string): string {
+	return value.trim().replace(/^"(.*)"$|^'(.*)'$/, '$1$2');
+}
+
+// ============================================================
+// Go (go.work)
+// ============================================================
+
+async function loadGoPackages(
+	projectRoot: string,
+): Promise<WorkspacePackage[]> {
+	const work = await readText(path.join(projectRoot, 'go.work'));
+	if (work === null) return [];
+
+	const dirs: string[] = [];
+	const content = work.replace(/\/\/.*$/gm, '');
+	for (const block of content.matchAll(/^\s*use\s*\(([^)]*)\)/gm)) {
+		dirs.push(...block[1]!.split(/\s+/).filter(Boolean));
+	}
+	for (const line of content.matchAll(/^\s*use\s+([^\s(]+)\s*$/gm)) {
+		dirs.push(line[1]!);
+	}
+
+	const packages: WorkspacePackage[] = [];
+	for (const dir of dirs.map(d => normalizeMemberDir(unquote(d)))) {
+		const goMod = await readText(path.join(projectRoot, dir, 'go.mod'));
+		const name = goMod?.match(/^\s*module\s+(\S+)/m)?.[1];
+		if (name) packages.push({name: unquote(name), path: dir, kind: 'go'});
+	}
+	return packages;
+}
+
+// ============================================================
+// Helpers
+// ============================================================
+
+async function expandMemberGlobs(
+	projectRoot: string,
+	include: string[],
+	exclude: string[],
+): Promise<string[]> {
+	const patterns = include.map(normalizeMemberDir).filter(Boolean);
+	if (patterns.length === 0) return [];
+	const dirs = await fg(patterns, {
+		cwd: projectRoot,
+		onlyDirectories: true,
+		ignore: [...exclude.map(normalizeMemberDir), '**/node_modules/**'],
+		dot: false,
+	});
+	return [...new Set(dirs.map(normalizeMemberDir))].sort();
+}
+
+function normalizeMemberDir(dir: string): string {
+	return path.posix
+		.normalize(dir.replace(/\\/g, '/'))
+		.replace(/^\.(\/|$)/, '')
+		.replace(/\/+$/, '');
+}
+
+function unquote(value: string): string {
+	return value.replace(/^"(.*)"$/, '$1');
+}
+
+async function readText(filePath: string): Promise<string | null> {
+	try {
+		return await fs.readFile(filePath, 'utf-8');
+	} catch {
+		return null;
+	}
+}
+
+async function readJson(
+	filePath: string,
+): Promise<Record<string, unknown> | null> {
+	const text = await readText(filePath);
+	if (text === null) return null;
+	try {
+		const parsed = JSON.parse(text) as unknown;
+		return parsed && typeof parsed === 'object'
+			? (parsed as Record<string, unknown>)
+			: null;
+	} catch {
+		return null;
+	}
+}
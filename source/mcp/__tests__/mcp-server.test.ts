@@ -508,6 +508,8 @@ describe('MCP Server', () => {
 			expect(toolNames).toContain('codebase_search');
 			expect(toolNames).toContain('get_symbol_details');
 			expect(toolNames).toContain('find_references');
+			expect(toolNames).toContain('find_implementations');
+			expect(toolNames).toContain('get_call_graph');
 			expect(toolNames).toContain('get_surrounding_code');
 			expect(toolNames).toContain('read_file_lines');
 			expect(toolNames).toContain('build_index');
@@ -748,6 +750,38 @@ describe('MCP Server', () => {
 			expect(Array.isArray(data.neighbors)).toBe(true);
 		});
 
+		it('read_file_lines returns notebook cell source at file lines', async ({
+			skip,
+		}) => {
+			if (setupError) {
+				skip();
+				return;
+			}
+			const result = await harness!.client.callTool({
+				name: 'read_file_lines',
+				arguments: {
+					file_path: 'notebooks/sales_analysis.ipynb',
+					start_line: 7,
+					end_line: 11,
+				},
+			});
+			const {data} = parseToolJson<{
+				start_line: number;
+				end_line: number;
+				text: string;
+			}>(result);
+			expect(data.start_line).toBe(7);
+			expect(data.end_line).toBe(11);
+			// The markdown cell's lines, decoded; the JSON around them is blank
+			expect(data.text.split('\n')).toEqual([
+				'',
+				'# Sales analysis',
+				'',
+				'Loads the quarterly sales export and summarises revenue by region.',
+				'',
+			]);
+		});
+
 		it('search respects max_response_size', async ({skip}) => {
 			if (setupError) {
 				skip();
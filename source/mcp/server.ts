@@ -31,7 +31,15 @@ import {
 	loadV2Manifest,
 	v2ManifestExists,
 } from '../daemon/services/v2/manifest.js';
-import {getGrammarSupportSummary} from '../daemon/lib/chunker/grammars.js';
+import {
+	getGrammarSupportSummary,
+	loadGrammarOptions,
+	type GrammarOptions,
+} from '../daemon/lib/chunker/grammars.js';
+import {
+	notebookSourceView,
+	parseNotebook,
+} from '../daemon/lib/chunker/notebook.js';
 import {DaemonClient} from '../client/index.js';
 import type {DaemonStatusResponse} from '../client/types.js';
 import {createServiceLogger, type Logger} from '../daemon/lib/logger.js';
@@ -71,9 +79,9 @@ Use viberag when trying to find all code, documentation, and files semantically
 When you use viberag to search, viberag will uncover semantically related variables, types, classes, functions, definitions, symbols, and files so that you can ensure no important context is missed.
 
 General workflow:
-- Use codebase_search as the starting point for exploration. Choose an intent (auto/definition/usage/concept/exact_text/similar_code) and optional scope filters (path_prefix/path_contains/path_not_contains/extension).
+- Use codebase_search as the starting point for exploration. Choose an intent (auto/definition/usage/concept/exact_text/similar_code) and optional scope filters (path_prefix/path_contains/path_not_contains/extension/crate/tests/visibility/kinds).
 - Use subagents with viberag search tools to explore more in parallel.
-- Use get_symbol_details(symbol_id) to fetch full definitions, find_references to locate usages, get_surrounding_code to expand context around a hit, and read_file_lines for raw source when you need exact lines.
+- Use get_symbol_details(symbol_id) to fetch full definitions, find_references to locate usages, find_implementations to list implementors of a trait/interface, get_call_graph to trace callers and callees, get_surrounding_code to expand context around a hit, and read_file_lines for raw source when you need exact lines.
 - If errors or not initialized, call get_status to check if "not_initialized" or "not_indexed", ask the user to run "npx viberag" in the project and complete /init, then call build_index.
 `;
 
@@ -235,6 +243,61 @@ export function createMcpServer(projectRoot: string): McpServerWithDaemon {
 				.describe(
 					'Only include files with these extensions (including the dot). Example: [".ts", ".tsx"]. Avoiding when exploring and trying to find all related files.',
 				),
+			crate: z
+				.array(z.string())
+				.optional()
+				.describe(
+					'Only include files owned by these workspace members (Cargo crate, package.json workspace package, or go.work module name). Example: ["my-core"]. See get_status crates for names.',
+				),
+			tests: z
+				.enum(['include', 'exclude', 'only'])
+				.optional()
+				.describe(
+					'Test code handling. "exclude" drops test files and test items (#[test], #[cfg(test)], test_*, describe/it blocks, @Test); "only" returns just those. Default: include.',
+				),
+			visibility: z
+				.array(
+					z.enum(['public', 'crate', 'restricted', 'protected', 'private']),
+				)
+				.optional()
+				.describe(
+					'Only include definitions with these visibility levels (codebase_search definitions and find_implementations). public = public API; crate = pub(crate)/package-private/internal/Go unexported; restricted = pub(super)/pub(in path)/pub inside a private mod/fileprivate; protected; private. Example: ["public"] to review a crate\'s surface.',
+				),
+			kinds: z
+				.array(
+					z.enum([
+						'function',
+						'class',
+						'interface',
+						'struct',
+						'trait',
+						'enum',
+						'method',
+						'macro',
+						'constant',
+						'variable',
+						'type_alias',
+						'variant',
+						'field',
+						'module',
+						'message',
+						'service',
+						'rpc',
+						'table',
+						'column',
+						'endpoint',
+						'operation',
+						'resource',
+						'module_call',
+						'stage',
+						'key',
+						'target',
+					]),
+				)
+				.optional()
+				.describe(
+					'Only include definitions of these symbol kinds (codebase_search definitions and find_implementations). Type definitions are class, interface (incl. protocols), struct, trait, enum and type_alias; enum members are variant; module-level constants/variables are constant/variable; class members are method and field. Example: ["enum"] to find "the UserRole enum".',
+				),
 		})
 		.optional();
 
@@ -331,6 +394,8 @@ RETURNS: Detailed guide with examples and workflow suggestions.`,
 					'read_file_lines',
 					'get_symbol_details',
 					'find_references',
+					'find_implementations',
+					'get_call_graph',
 					'get_surrounding_code',
 					'build_index',
 					'get_status',
@@ -380,7 +445,7 @@ RETURNS: Detailed guide with examples and workflow suggestions.`,
 						key_inputs: [
 							'query (required)',
 							'intent: auto|definition|usage|concept|exact_text|similar_code',
-							'scope filters (path_prefix/path_contains/path_not_contains/extension)',
+							'scope filters (path_prefix/path_contains/path_not_contains/extension/crate/tests/visibility/kinds)',
 						],
 						output:
 							'Grouped hits (definitions/files/blocks/usages) + stable IDs.',
@@ -391,6 +456,7 @@ RETURNS: Detailed guide with examples and workflow suggestions.`,
 						],
 						examples: [
 							{query: 'HttpClient', intent: 'definition'},
+							{query: 'Greeter::new', intent: 'definition'},
 							{query: 'how does authentication work', intent: 'concept'},
 							{query: 'ECONNRESET', intent: 'exact_text'},
 							{query: 'where is login used', intent: 'usage'},
@@ -422,8 +488,27 @@ RETURNS: Detailed guide with examples and workflow suggestions.`,
 					find_references: {
 						when_to_use:
 							'Find all references to a symbol (calls, imports, type annotations).',
-						key_inputs: ['symbol_id (preferred) or symbol_name'],
-						output: 'Refs grouped by file with context snippets.',
+						key_inputs: [
+							'symbol_id (preferred) or symbol_name',
+							'ref_kinds (e.g. instantiation, type_ref, extends)',
+							'access (write: where a field/variable is assigned)',
+						],
+						output:
+							'Refs grouped by file with context snippets; same-name refs apart.',
+					},
+					find_implementations: {
+						when_to_use:
+							'Find types implementing a trait/interface or extending a base type.',
+						key_inputs: ['symbol_id or symbol_name (trait/interface)'],
+						output:
+							'Implementing types with via: impl|derive|supertype|structural.',
+					},
+					get_call_graph: {
+						when_to_use:
+							'Trace who calls a function and what it calls, several hops deep.',
+						key_inputs: ['symbol_id (required), direction, depth'],
+						output:
+							'Caller/callee edges with resolution: definition|name, plus nodes.',
 					},
 					build_index: {
 						when_to_use:
@@ -523,7 +608,7 @@ NEXT STEPS:
 					'Search strategy: auto (detect from query), concept (how does X work), definition (symbol lookup), usage (where is X used), exact_text (literal strings), similar_code (code patterns)',
 				),
 			scope: scopeSchema.describe(
-				'Path/extension filters: path_prefix, path_contains, path_not_contains, extension',
+				'Path/extension/crate/test/visibility/kind filters: path_prefix, path_contains, path_not_contains, extension, crate, tests, visibility, kinds',
 			),
 			k: z
 				.number()
@@ -593,7 +678,9 @@ WHEN TO USE:
 INPUT: file_path (project-relative), start_line, end_line
 RETURNS: Exact text for the requested line range.
 
-NOTE: Use after search results give you a file_path and line numbers.`,
+NOTE: Use after search results give you a file_path and line numbers.
+For Jupyter notebooks (.ipynb), returns cell source at the notebook's line
+numbers, with the surrounding JSON blanked.`,
 		parameters: z.object({
 			file_path: z
 				.string()
@@ -611,7 +698,12 @@ NOTE: Use after search results give you a file_path and line numbers.`,
 		execute: async args => {
 			const absolutePath = safeResolveProjectPath(projectRoot, args.file_path);
 			const content = await fs.readFile(absolutePath, 'utf-8');
-			const lines = content.split('\n');
+			const notebook = absolutePath.toLowerCase().endsWith('.ipynb')
+				? parseNotebook(content)
+				: null;
+			const lines = (
+				notebook ? notebookSourceView(notebook) : content
+			).split('\n');
 
 			const {start, end, truncated} = clampLineRange({
 				start_line: args.start_line,
@@ -643,7 +735,12 @@ WHEN TO USE:
 - Want deterministic metadata (not search-ranked)
 
 INPUT: symbol_id from codebase_search results
-RETURNS: Full code_text, signature, docstring, decorators, location, export status.
+RETURNS: Full code_text, signature, docstring, decorators, location, export status, visibility.
+Rust impl blocks also return impl_targets (implementing type + trait).
+Types return children: their methods, fields, enum variants and associated consts/types.
+Trait/interface methods return implementations (same-named methods on implementing types);
+implementing methods return declarations (the trait/interface methods they satisfy).
+C/C++ prototypes return definitions (header -> implementation); definitions return prototypes.
 
 NEXT STEPS:
 - find_references(symbol_id) → where this symbol is used
@@ -708,10 +805,29 @@ WHEN TO USE:
 
 INPUT: symbol_id (preferred, from codebase_search) or symbol_name as fallback
 RETURNS: References grouped by file, with line numbers and context snippets.
+Imports resolved to the symbol (including renamed Rust "use ... as" imports)
+and calls made through them carry target_symbol_id. TS/JS (relative paths,
+tsconfig paths, package exports), Python, Go, Java/Kotlin and Rust imports
+are resolved. With symbol_id, by_file holds refs bound to that symbol and
+same_name_by_file the unresolved refs that only share its name.
+Documentation links (Rust intra-doc links, {@link}, Sphinx roles) are
+ref_kind "doc_link"; calls inside doc-test examples are included too.
+Each ref has a ref_kind: call, instantiation (new Foo(), Foo {..},
+Python Foo()), type_ref (annotations, generic arguments, parameter and
+return types), extends, implements, import, identifier, string_literal or
+doc_link. Filter with ref_kinds. Identifier refs also carry access: read,
+write (assignment targets, field initialisers) or read_write (+=, ++,
+Rust &mut borrows); access: "write" finds where a field or variable is
+assigned. There is no "read" filter: plain reads of lowercase names are
+not indexed.
 
 EXAMPLES:
 - find_references(symbol_id: "abc123") → precise results for that symbol
-- find_references(symbol_name: "HttpClient") → all refs to any HttpClient`,
+- find_references(symbol_name: "HttpClient") → all refs to any HttpClient
+- find_references(symbol_name: "Session", ref_kinds: ["instantiation"])
+  → where Session objects are constructed
+- find_references(symbol_name: "retry_count", access: "write")
+  → where retry_count is assigned`,
 		parameters: z
 			.object({
 				symbol_id: z
@@ -722,7 +838,33 @@ EXAMPLES:
 					.string()
 					.optional()
 					.describe('Symbol name as fallback (e.g., "HttpClient", "login")'),
-				scope: scopeSchema.describe('Path/extension filters to narrow results'),
+				scope: scopeSchema.describe(
+					'Path/extension/crate/test filters to narrow results',
+				),
+				ref_kinds: z
+					.array(
+						z.enum([
+							'import',
+							'call',
+							'instantiation',
+							'identifier',
+							'type_ref',
+							'extends',
+							'implements',
+							'string_literal',
+							'doc_link',
+						]),
+					)
+					.optional()
+					.describe(
+						'Only include refs of these kinds. Example: ["extends", "implements"] for subclasses, ["type_ref"] for type annotations.',
+					),
+				access: z
+					.enum(['write', 'read_write'])
+					.optional()
+					.describe(
+						'Only include identifier refs with this access. "write" = assignment targets and field initialisers, including read_write (+=, ++, &mut).',
+					),
 				k: z
 					.number()
 					.min(1)
@@ -749,6 +891,8 @@ EXAMPLES:
 					symbol_id: args.symbol_id,
 					symbol_name: args.symbol_name,
 					scope: args.scope,
+					ref_kinds: args.ref_kinds,
+					access: args.access,
 					k,
 				});
 			};
@@ -766,6 +910,132 @@ EXAMPLES:
 		},
 	});
 
+	// Tool: find_implementations
+	addToolWithTelemetry({
+		name: 'find_implementations',
+		description: `Find types that implement a trait, interface or base type.
+
+use subagents to do this in parallel.
+
+WHEN TO USE:
+- "Which types implement Serialize / Display / Handler?"
+- Find subclasses or implementors before changing an interface
+- Locate the concrete types behind an abstract API
+
+INPUT: symbol_id (of the trait/interface) or symbol_name (e.g. "Display")
+RETURNS: Implementing types with location and how they implement it (via):
+- impl: Rust "impl Trait for Type" block
+- derive: Rust #[derive(Trait)]
+- supertype: extends/implements clauses, base lists, Python base classes,
+  Swift protocol conformances, Rust supertraits
+- structural: Go types whose methods cover the interface's method set
+Matching is by base name ("fmt::Display" and "Display" are equivalent).
+
+EXAMPLES:
+- find_implementations(symbol_name: "Serialize") → derives + manual impls
+- find_implementations(symbol_id: "abc123") → implementors of that interface`,
+		parameters: z
+			.object({
+				symbol_id: z
+					.string()
+					.optional()
+					.describe('Trait/interface symbol ID from codebase_search'),
+				symbol_name: z
+					.string()
+					.optional()
+					.describe('Trait/interface/base type name (e.g., "Display")'),
+				scope: scopeSchema.describe(
+					'Path/extension/crate/test/visibility/kind filters on implementors',
+				),
+				k: z
+					.number()
+					.min(1)
+					.max(500)
+					.optional()
+					.default(100)
+					.describe('Max implementations to return'),
+			})
+			.refine(v => v.symbol_id || v.symbol_name, {
+				message: 'Provide symbol_id or symbol_name',
+			}),
+		execute: async args => {
+			await ensureInitialized(projectRoot);
+			const result = await client.findImplementations({
+				symbol_id: args.symbol_id,
+				symbol_name: args.symbol_name,
+				scope: args.scope,
+				k: args.k,
+			});
+			return JSON.stringify(result);
+		},
+	});
+
+	// Tool: get_call_graph
+	addToolWithTelemetry({
+		name: 'get_call_graph',
+		description: `Get the callers and callees of a function or method.
+
+use subagents to do this in parallel.
+
+WHEN TO USE:
+- "Who calls placeOrder, and who calls those?"
+- "What does this handler end up calling?"
+- Estimate the blast radius of a change before refactoring
+
+INPUT: symbol_id (from codebase_search), direction, depth (hops, default 2)
+RETURNS: The root symbol, nodes (every symbol on an edge) and caller/callee
+edges with caller_id, callee_id, first call site (file_path, line) and depth.
+Each edge has a resolution:
+- definition: bound to one definition (an import in the calling file, a
+  definition in the same file, or the only definition with that name)
+- name: matched by name only (unknown or ambiguous); callee_id is null for
+  name-only callees and these edges are not followed further
+caller_id is null for calls made from module-level code.
+
+EXAMPLES:
+- get_call_graph(symbol_id: "abc123") → callers and callees, 2 hops
+- get_call_graph(symbol_id: "abc123", direction: "callers", depth: 3)`,
+		parameters: z.object({
+			symbol_id: z
+				.string()
+				.describe('Function/method symbol ID from codebase_search'),
+			direction: z
+				.enum(['callers', 'callees', 'both'])
+				.optional()
+				.default('both')
+				.describe('Which side of the graph to walk'),
+			depth: z
+				.number()
+				.int()
+				.min(1)
+				.max(5)
+				.optional()
+				.default(2)
+				.describe('Hops to follow (1 = direct callers/callees only)'),
+			scope: scopeSchema.describe(
+				'Path/extension/crate/test filters on call sites',
+			),
+			k: z
+				.number()
+				.min(1)
+				.max(500)
+				.optional()
+				.default(200)
+				.describe('Max edges per direction'),
+		}),
+		execute: async args => {
+			await ensureInitialized(projectRoot);
+			const result = await client.getCallGraph({
+				symbol_id: args.symbol_id,
+				direction: args.direction,
+				depth: args.depth,
+				scope: args.scope,
+				k: args.k,
+			});
+			return JSON.stringify(result);
+		},
+	});
+
 	// Tool: get_surrounding_code
 	addToolWithTelemetry({
 		name: 'get_surrounding_code',
@@ -947,14 +1217,17 @@ WHEN TO USE:
 RETURNS:
 - Initialization status and setup instructions (if not initialized)
 - Index compatibility (may indicate reindex needed)
-- Index stats: file/symbol/chunk counts
+- Index stats: file/symbol/chunk counts (plus per-crate counts in workspaces)
 - Daemon status: running, warmup progress, indexing state
 
 CALL THIS FIRST if unsure whether VibeRAG is ready to use.`,
 		parameters: z.object({}),
 		execute: async () => {
 			const v2IndexCompatibility = await checkV2IndexCompatibility(projectRoot);
-			const grammar = getGrammarSupportSummary();
+			const grammarOptions = await loadConfig(projectRoot)
+				.then(config => loadGrammarOptions(projectRoot, config))
+				.catch((): Partial<GrammarOptions> => ({}));
+			const grammar = getGrammarSupportSummary(grammarOptions);
 			const parsing = {
 				enabled: grammar.enabled.map(g => g.display_name),
 				disabled: grammar.disabled.map(g => ({
@@ -1018,6 +1291,7 @@ CALL THIS FIRST if unsure whether VibeRAG is ready to use.`,
 				totalSymbols: manifest.stats.totalSymbols,
 				totalChunks: manifest.stats.totalChunks,
 				totalRefs: manifest.stats.totalRefs,
+				crates: manifest.stats.crates ?? {},
 				embeddingProvider: config.embeddingProvider,
 				embeddingModel: config.embeddingModel,
 				embeddingDimensions: config.embeddingDimensions,
@@ -60,6 +60,19 @@ public interface Greeter {
     String greet();
 }
 
+/**
+ * Service that always says hello.
+ */
+class HelloService extends Sample implements Greeter {
+    HelloService() {
+        super("friend");
+    }
+
+    public String greet() {
+        return "Hello, friend!";
+    }
+}
+
 /**
  * Add two numbers together.
  */
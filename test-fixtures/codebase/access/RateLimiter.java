@@ -0,0 +1,12 @@
+/** Limits requests per window. */
+public class RateLimiter {
+    private int tokens = 10;
+
+    public boolean tryAcquire() {
+        if (tokens <= 0) {
+            return false;
+        }
+        tokens--;
+        return true;
+    }
+}
@@ -0,0 +1,17 @@
+package access
+
+// CacheStats tracks cache lookups.
+type CacheStats struct {
+	misses int
+}
+
+// NewCacheStats starts with no misses.
+func NewCacheStats() *CacheStats {
+	return &CacheStats{misses: 0}
+}
+
+// Miss records a lookup that found nothing.
+func (s *CacheStats) Miss() int {
+	s.misses++
+	return s.misses
+}
@@ -0,0 +1,18 @@
+/** Counts page hits. */
+export class HitCounter {
+	hits = 0;
+
+	record(): number {
+		this.hits += 1;
+		return this.hits;
+	}
+
+	reset(): void {
+		this.hits = 0;
+	}
+
+	snapshot(): number {
+		const seen = this.hits;
+		return seen;
+	}
+}
@@ -0,0 +1,11 @@
+class RetryPolicy:
+    """Gives up after a few attempts."""
+
+    def __init__(self):
+        self.retries = 0
+
+    def should_retry(self):
+        if self.retries >= 3:
+            return False
+        self.retries += 1
+        return True
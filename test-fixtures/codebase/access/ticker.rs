@@ -0,0 +1,19 @@
+/// Counts clock ticks.
+pub struct Ticker {
+    ticks: u64,
+}
+
+fn advance(count: &mut u64) {
+    *count += 1;
+}
+
+impl Ticker {
+    pub fn new() -> Self {
+        Ticker { ticks: 0 }
+    }
+
+    pub fn tick(&mut self) -> u64 {
+        advance(&mut self.ticks);
+        self.ticks
+    }
+}
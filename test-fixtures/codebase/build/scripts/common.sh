@@ -0,0 +1,14 @@
+#!/usr/bin/env bash
+# Shared helpers for build scripts.
+
+# Print a timestamped message to stderr.
+log() {
+	echo "[$(date +%H:%M:%S)] $*" >&2
+}
+
+require_env() {
+	if [ -z "${!1:-}" ]; then
+		log "missing $1"
+		exit 1
+	fi
+}
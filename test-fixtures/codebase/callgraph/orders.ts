@@ -0,0 +1,24 @@
+/**
+ * Order placement.
+ */
+
+import {chargeCard} from './payments.js';
+
+declare function notify(message: string): void;
+
+export function validateTotal(total: number): void {
+	if (total <= 0) {
+		throw new Error('Order total must be positive');
+	}
+}
+
+export function placeOrder(total: number): string {
+	validateTotal(total);
+	const receipt = chargeCard(total);
+	notify(`Order placed: ${receipt}`);
+	return receipt;
+}
+
+export function reorder(previousTotal: number): string {
+	return placeOrder(previousTotal);
+}
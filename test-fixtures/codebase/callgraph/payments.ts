@@ -0,0 +1,11 @@
+/**
+ * Card payments.
+ */
+
+export function formatReceipt(amount: number): string {
+	return `Charged ${amount.toFixed(2)}`;
+}
+
+export function chargeCard(amount: number): string {
+	return formatReceipt(amount);
+}
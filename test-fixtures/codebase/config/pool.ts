@@ -0,0 +1,6 @@
+type Config = {get(path: string): unknown};
+
+/** Connection pool size from the loaded configuration. */
+export function poolSize(config: Config): number {
+	return Number(config.get('database.pool.max_size') ?? 10);
+}
@@ -0,0 +1,20 @@
+-- Registered accounts.
+CREATE TABLE IF NOT EXISTS public.users (
+    id BIGSERIAL PRIMARY KEY,
+    email TEXT NOT NULL UNIQUE, -- primary contact address
+    role user_role NOT NULL DEFAULT 'member',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE TYPE user_role AS ENUM ('admin', 'member');
+
+CREATE TABLE orders (
+    id BIGSERIAL PRIMARY KEY,
+    user_id BIGINT NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    total NUMERIC(12, 2) NOT NULL,
+    CONSTRAINT orders_total_positive CHECK (total >= 0)
+);
+
+CREATE INDEX orders_user_id_idx ON orders (user_id);
+
+ALTER TABLE users ADD COLUMN last_login_at TIMESTAMPTZ;
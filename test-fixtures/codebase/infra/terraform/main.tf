@@ -0,0 +1,44 @@
+provider "aws" {
+  region = var.region
+}
+
+locals {
+  name_prefix = "platform-${var.region}"
+  tags = {
+    team = "platform"
+  }
+}
+
+module "network" {
+  source = "./modules/network"
+  cidr   = "10.0.0.0/16"
+}
+
+data "aws_ami" "ubuntu" {
+  most_recent = true
+  owners      = ["099720109477"]
+}
+
+# Session store for the web tier.
+resource "aws_elasticache_cluster" "redis" {
+  cluster_id        = "${local.name_prefix}-redis"
+  engine            = "redis"
+  node_type         = var.redis_node_type
+  num_cache_nodes   = 1
+  subnet_group_name = module.network.cache_subnet_group
+  tags              = local.tags
+}
+
+resource "aws_instance" "bastion" {
+  ami       = data.aws_ami.ubuntu.id
+  user_data = <<-EOT
+    #!/bin/bash
+    resource "not" "a_block" {}
+    echo ${aws_elasticache_cluster.redis.cache_nodes[0].address}
+  EOT
+}
+
+output "redis_endpoint" {
+  description = "Primary endpoint of the session store"
+  value       = aws_elasticache_cluster.redis.cache_nodes[0].address
+}
@@ -0,0 +1,10 @@
+variable "region" {
+  description = "AWS region for all resources"
+  type        = string
+  default     = "eu-west-1"
+}
+
+variable "redis_node_type" {
+  description = "Instance type of the redis cache nodes"
+  type        = string
+}
@@ -0,0 +1,30 @@
+"""Permissions granted to each role."""
+
+from dataclasses import dataclass
+from enum import Enum
+from typing import Protocol
+
+MAX_GRANTS = 16
+
+default_scope = "org"
+
+
+class Permission(Enum):
+    """Action a role may perform."""
+
+    READ = "read"
+    WRITE = "write"
+
+
+class Grantable(Protocol):
+    """Anything permissions can be granted to."""
+
+    def grant(self, permission: Permission) -> None: ...
+
+
+@dataclass
+class Grant:
+    """A permission held within a scope."""
+
+    permission: Permission
+    scope: str = "org"
@@ -0,0 +1,42 @@
+/**
+ * Roles a user can hold and the shapes that carry them.
+ */
+
+/**
+ * Role a user holds in an organization.
+ */
+export enum UserRole {
+	Admin = 'admin',
+	Member = 'member',
+	Guest = 'guest',
+}
+
+/**
+ * Anything that can be granted a role.
+ */
+export interface RoleHolder {
+	role: UserRole;
+	grant(role: UserRole): void;
+}
+
+/**
+ * Roles keyed by organization id.
+ */
+export type RoleMap = Record<string, UserRole>;
+
+/**
+ * Role given to new members.
+ */
+export const DEFAULT_ROLE = UserRole.Member;
+
+export let sessionRole: UserRole = DEFAULT_ROLE;
+
+export const userRole = (holder: RoleHolder): UserRole => holder.role;
+
+export class RoleRegistry {
+	private roles: RoleMap = {};
+
+	assign(orgId: string, role: UserRole): void {
+		this.roles[orgId] = role;
+	}
+}
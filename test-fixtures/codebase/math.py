@@ -12,5 +12,11 @@ def subtract(a: int, b: int) -> int:
 
 
 def multiply(a: int, b: int) -> int:
-    """Multiply two numbers and return the product."""
+    """Multiply two numbers and return the product.
+
+    See :func:`add_two_numbers` for addition.
+
+    >>> multiply(2, 3)
+    6
+    """
     return a * b
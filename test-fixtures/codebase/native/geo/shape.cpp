@@ -0,0 +1,29 @@
+#include "shape.h"
+
+#define GEO_PI 3.14159265358979
+
+namespace geo {
+
+double Shape::area() const {
+	return 0.0;
+}
+
+std::string Shape::label() const {
+	return "shape";
+}
+
+Circle::Circle(double radius) : radius_(radius) {}
+
+double Circle::area() const {
+	return GEO_PI * radius_ * radius_;
+}
+
+namespace {
+
+double square(double value) {
+	return value * value;
+}
+
+}  // namespace
+
+}  // namespace geo
@@ -0,0 +1,37 @@
+#ifndef GEO_SHAPE_H
+#define GEO_SHAPE_H
+
+#include <string>
+
+namespace geo {
+
+/**
+ * @brief Base class for 2D shapes.
+ */
+class Shape {
+public:
+	virtual ~Shape() = default;
+
+	/// Area of the shape in square units.
+	virtual double area() const;
+
+	std::string label() const;
+
+protected:
+	int sides_;
+};
+
+/** A circle centered at the origin. */
+class Circle : public Shape {
+public:
+	explicit Circle(double radius);
+
+	double area() const override;
+
+private:
+	double radius_;
+};
+
+}  // namespace geo
+
+#endif  // GEO_SHAPE_H
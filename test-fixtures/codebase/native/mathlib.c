@@ -0,0 +1,15 @@
+#include "mathlib.h"
+#include <stdlib.h>
+
+static int grid_abs(int value) {
+	return value < 0 ? -value : value;
+}
+
+int grid_distance(struct GridPoint a, struct GridPoint b) {
+	return grid_abs(a.x - b.x) + grid_abs(a.y - b.y);
+}
+
+struct GridPoint grid_origin(void) {
+	struct GridPoint origin = {0, 0};
+	return origin;
+}
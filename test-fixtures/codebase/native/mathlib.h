@@ -0,0 +1,19 @@
+#ifndef MATHLIB_H
+#define MATHLIB_H
+
+/** A point on the integer grid. */
+struct GridPoint {
+	int x;
+	int y;
+};
+
+/**
+ * Manhattan distance between two grid points.
+ *
+ * @see grid_origin
+ */
+int grid_distance(struct GridPoint a, struct GridPoint b);
+
+struct GridPoint grid_origin(void);
+
+#endif
@@ -0,0 +1,18 @@
+package refkinds;
+
+/** Delivers parcels. */
+interface Dispatchable {
+    void dispatch(String parcel);
+}
+
+/** Bicycle courier. */
+class Courier implements Dispatchable {
+    public void dispatch(String parcel) {
+        System.out.println(parcel);
+    }
+
+    static Courier hire() {
+        Courier courier = new Courier();
+        return courier;
+    }
+}
@@ -0,0 +1,15 @@
+class Ledger:
+    """Running balance of entries."""
+
+    def __init__(self):
+        self.entries = []
+
+
+class AuditLedger(Ledger):
+    """Ledger that keeps who made each entry."""
+
+
+def open_ledger(audited: bool) -> Ledger:
+    if audited:
+        return AuditLedger()
+    return Ledger()
@@ -0,0 +1,23 @@
+/// Reports a distance travelled.
+pub trait Meter {
+    fn reading(&self) -> u32;
+}
+
+/// Counts kilometres.
+pub struct Odometer {
+    km: u32,
+}
+
+impl Meter for Odometer {
+    fn reading(&self) -> u32 {
+        self.km
+    }
+}
+
+pub fn fresh_odometer() -> Odometer {
+    Odometer { km: 0 }
+}
+
+pub fn total(meters: &[Odometer]) -> u32 {
+    meters.iter().map(|m| m.reading()).sum()
+}
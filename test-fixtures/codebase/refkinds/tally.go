@@ -0,0 +1,18 @@
+package refkinds
+
+// Tally counts votes per option.
+type Tally struct {
+	Votes map[string]int
+}
+
+// NewTally starts an empty tally.
+func NewTally() *Tally {
+	return &Tally{Votes: map[string]int{}}
+}
+
+// Merge adds the votes of other into t.
+func Merge(t *Tally, other Tally) {
+	for k, v := range other.Votes {
+		t.Votes[k] += v
+	}
+}
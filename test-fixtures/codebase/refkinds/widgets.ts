@@ -0,0 +1,22 @@
+/** Something that can draw itself. */
+export interface Renderable {
+	render(): string;
+}
+
+/** Shared sizing for widgets. */
+export class BaseWidget {
+	constructor(readonly width: number) {}
+}
+
+/** A dial showing a single value. */
+export class Gauge extends BaseWidget implements Renderable {
+	render(): string {
+		return `[${'#'.repeat(this.width)}]`;
+	}
+}
+
+export function mountGauge(width: number): Gauge {
+	const gauge = new Gauge(width);
+	const widgets: Array<Renderable> = [gauge];
+	return widgets.length > 0 ? gauge : new Gauge(1);
+}
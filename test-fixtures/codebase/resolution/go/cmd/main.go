@@ -0,0 +1,11 @@
+package main
+
+import (
+	"fmt"
+
+	"example.com/shop/pricing"
+)
+
+func main() {
+	fmt.Println(pricing.Total([]int{250, 100}))
+}
@@ -0,0 +1,6 @@
+package pricing
+
+// Discounted applies a percentage discount to the total.
+func Discounted(prices []int, percent int) int {
+	return Total(prices) * (100 - percent) / 100
+}
@@ -0,0 +1,9 @@
+package com.shop;
+
+import com.shop.util.Money;
+
+public class App {
+    public String receipt(long cents) {
+        return Money.format(cents);
+    }
+}
@@ -0,0 +1,7 @@
+package com.shop.util;
+
+public class Money {
+    public static String format(long cents) {
+        return String.format("%d.%02d", cents / 100, cents % 100);
+    }
+}
@@ -0,0 +1,7 @@
+package com.shop
+
+import com.shop.text.slug
+
+fun main() {
+    println(slug("Hello World"))
+}
@@ -0,0 +1,3 @@
+package com.shop.text
+
+fun slug(value: String): String = value.trim().lowercase().replace(" ", "-")
@@ -0,0 +1 @@
+"""Import resolution fixture package."""
@@ -0,0 +1,7 @@
+from . import pricing
+from .pricing import total_price
+
+
+def checkout(items):
+    subtotal = total_price(items)
+    return subtotal + pricing.total_price(items) // 10
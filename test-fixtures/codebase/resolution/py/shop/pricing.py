@@ -0,0 +1,3 @@
+def total_price(items):
+    """Sum of item prices in cents."""
+    return sum(item["price"] for item in items)
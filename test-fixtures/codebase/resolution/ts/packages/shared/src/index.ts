@@ -0,0 +1,3 @@
+export function slugify(value: string): string {
+	return value.trim().toLowerCase().replace(/\s+/g, '-');
+}
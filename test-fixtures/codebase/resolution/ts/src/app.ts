@@ -0,0 +1,7 @@
+import {Config, loadConfig} from './config.js';
+import {slugify} from '@fixture/shared';
+
+export function startApp(): string {
+	const config: Config = loadConfig();
+	return slugify(config.name);
+}
@@ -0,0 +1,10 @@
+/**
+ * Current configuration.
+ */
+export class Config {
+	constructor(readonly name: string) {}
+}
+
+export function loadConfig(): Config {
+	return new Config('default');
+}
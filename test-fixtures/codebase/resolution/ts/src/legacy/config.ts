@@ -0,0 +1,6 @@
+/**
+ * Configuration of the old server; unrelated to src/config.ts.
+ */
+export class Config {
+	constructor(readonly path: string) {}
+}
@@ -0,0 +1,9 @@
+import {Config} from '@app/legacy/config';
+
+export function startServer(): Config {
+	return new Config('/etc/server.conf');
+}
+
+export function reloadServer(loader: {loadConfig(): string}): string {
+	return loader.loadConfig();
+}
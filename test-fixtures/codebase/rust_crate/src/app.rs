@@ -0,0 +1,12 @@
+use super::legacy::Client;
+use crate::net::client::Client as NetClient;
+
+/// Connects to the default endpoint.
+pub fn connect() -> NetClient {
+    NetClient::new("localhost:8080")
+}
+
+/// Returns the legacy client.
+pub fn legacy_client() -> Client {
+    Client
+}
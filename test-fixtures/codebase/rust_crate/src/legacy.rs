@@ -0,0 +1,2 @@
+/// Legacy client kept for compatibility (unrelated to `net::Client`).
+pub struct Client;
@@ -0,0 +1,5 @@
+//! Small crate used to exercise Rust module resolution.
+
+pub mod app;
+mod legacy;
+pub mod net;
@@ -0,0 +1,12 @@
+/// Client that talks to a remote endpoint.
+pub struct Client {
+    pub endpoint: String,
+}
+
+impl Client {
+    pub fn new(endpoint: &str) -> Self {
+        Client {
+            endpoint: endpoint.to_string(),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+//! Networking helpers.
+
+pub mod client;
+
+pub use client::Client;
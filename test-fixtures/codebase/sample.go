@@ -9,6 +9,11 @@ type Greeter struct {
 	name string
 }
 
+// Speaker is satisfied by any type with a Greet method.
+type Speaker interface {
+	Greet() string
+}
+
 // unexportedStruct is private to this package.
 type unexportedStruct struct {
 	value int
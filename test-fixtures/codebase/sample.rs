@@ -2,11 +2,32 @@
 //!
 //! This module demonstrates various Rust features.
 
+use std::fmt;
+
+/// How many times a greeting is retried.
+pub const MAX_RETRIES: u32 = 3;
+
+static DEFAULT_NAME: &str = "World";
+
+/// Result type used by greeting helpers.
+pub type GreetResult<T> = Result<T, String>;
+
+/// The mood a greeting is delivered in.
+#[derive(Debug, Clone)]
+pub enum Mood {
+    /// Plain and cheerful.
+    Happy,
+    Formal(String),
+    Custom { prefix: String },
+}
+
 /// A greeter struct that holds a name.
 /// Used for generating greeting messages.
 #[derive(Debug, Clone)]
 pub struct Greeter {
     name: String,
+    /// Mood used by `greet`.
+    pub mood: Option<Mood>,
 }
 
 impl Greeter {
@@ -14,10 +35,19 @@ impl Greeter {
     pub fn new(name: &str) -> Self {
         Greeter {
             name: name.to_string(),
+            mood: None,
         }
     }
 
     /// Returns a greeting message.
+    ///
+    /// Build one with [`Greeter::new`]; retries are capped by [`MAX_RETRIES`].
+    ///
+    /// ```
+    /// let greeter = Greeter::new("Ada");
+    /// let message = greeter.greet();
+    /// assert_eq!(message, "Hello, Ada!");
+    /// ```
     pub fn greet(&self) -> String {
         format!("Hello, {}!", self.name)
     }
@@ -27,6 +57,24 @@ impl Greeter {
     }
 }
 
+impl fmt::Display for Greeter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Greeter({})", self.name)
+    }
+}
+
+/// Something that can introduce itself.
+pub trait Introduce {
+    /// Returns a short introduction.
+    fn introduce(&self) -> String;
+}
+
+impl Introduce for Greeter {
+    fn introduce(&self) -> String {
+        self.greet()
+    }
+}
+
 /// A private struct for internal use.
 struct PrivateHelper {
     value: i32,
@@ -59,6 +107,31 @@ pub fn process_data(data: &str) -> Option<String> {
     }
 }
 
+/// Builds greeting messages for every name passed in.
+#[macro_export]
+macro_rules! greet_all {
+    ($($name:expr),*) => {
+        vec![$(format!("Hello, {}!", $name)),*]
+    };
+}
+
+/// Greets the default roster.
+pub fn greet_roster() -> Vec<String> {
+    greet_all!("Ada", "Grace")
+}
+
+/// Shared within the crate only.
+pub(crate) fn crate_helper() -> u32 {
+    MAX_RETRIES
+}
+
+mod internal {
+    /// Public, but only reachable through the private `internal` module.
+    pub fn reachable_from_parent() -> i32 {
+        super::private_function()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
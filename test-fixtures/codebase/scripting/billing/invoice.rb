@@ -0,0 +1,30 @@
+require 'json'
+require_relative 'record'
+
+module Billing
+  # An invoice with line items.
+  class Invoice < Record
+    def initialize(lines)
+      @lines = lines
+    end
+
+    # Total of all line items in cents.
+    def total
+      @lines.sum { |line| rounded(line) }
+    end
+
+    def self.empty
+      new([])
+    end
+
+    def to_json(*args)
+      JSON.generate({ total: total }, *args)
+    end
+
+    private
+
+    def rounded(amount)
+      amount.round
+    end
+  end
+end
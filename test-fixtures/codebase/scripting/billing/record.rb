@@ -0,0 +1,8 @@
+module Billing
+  # Base class for persisted billing records.
+  class Record
+    def save
+      true
+    end
+  end
+end
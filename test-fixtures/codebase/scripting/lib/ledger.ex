@@ -0,0 +1,30 @@
+defmodule Scripting.Ledger.Entry do
+  @moduledoc false
+  defstruct amount: 0
+end
+
+defmodule Scripting.Ledger do
+  @moduledoc """
+  Running balance of ledger entries.
+  """
+
+  alias Scripting.Ledger.Entry
+  import Enum, only: [reduce: 3]
+
+  @doc "Balance after applying every entry."
+  @spec balance([Entry.t()]) :: integer()
+  def balance(entries) when is_list(entries) do
+    reduce(entries, 0, &apply_entry/2)
+  end
+
+  defp apply_entry(%Entry{amount: amount}, acc), do: acc + amount
+end
+
+defprotocol Scripting.Describable do
+  @doc "Human-readable description."
+  def describe(value)
+end
+
+defimpl Scripting.Describable, for: Scripting.Ledger do
+  def describe(_ledger), do: "ledger"
+end
@@ -0,0 +1,21 @@
+local util = require("scripting.util")
+
+local Meter = {}
+Meter.__index = Meter
+
+local function clamp(value)
+  return math.max(0, value)
+end
+
+--- Create a meter starting at zero.
+function Meter.new()
+  return setmetatable({ reading = 0 }, Meter)
+end
+
+--- Add a reading, ignoring negative values.
+function Meter:record(value)
+  self.reading = self.reading + clamp(value)
+  return util.round(self.reading)
+end
+
+return Meter
@@ -0,0 +1,26 @@
+package plans
+
+import scala.collection.mutable.{ListBuffer, Map => MutableMap}
+
+/** A pricing plan. */
+trait Plan {
+  def monthly: BigDecimal
+}
+
+/** A plan with a fixed monthly price. */
+case class FlatPlan(price: BigDecimal) extends Plan with Serializable {
+  def monthly: BigDecimal = price
+
+  private def discounted(rate: BigDecimal): BigDecimal = price * (1 - rate)
+}
+
+object Plans {
+  private val registry = MutableMap.empty[String, Plan]
+  private val history = ListBuffer.empty[String]
+
+  /** Register a plan under a name. */
+  def register(name: String, plan: Plan): Unit = {
+    registry.update(name, plan)
+    history += name
+  }
+}
@@ -0,0 +1,11 @@
+/**
+ * Shared counter state for the counter components.
+ */
+export function useCounter(initial = 0) {
+	let count = initial;
+	const increment = () => {
+		count += 1;
+		return count;
+	};
+	return {increment, current: () => count};
+}
@@ -34,6 +34,10 @@ export function deepClone<T>(obj: T): T {
 
 /**
  * Check if value is empty (null, undefined, empty string, empty array).
+ * Strings are trimmed first; see {@link capitalize} for formatting.
+ *
+ * @example
+ * isEmpty('  '); // true
  */
 export function isEmpty(value: unknown): boolean {
 	if (value == null) return true;